@@ -1,5 +1,6 @@
 use std::cmp::Ordering;
 use std::fmt;
+use std::str::FromStr;
 
 use strum::EnumIter;
 
@@ -23,6 +24,7 @@ macro_rules! card {
 ///
 /// Ranks contain Two through Ace (Ace-high by default).
 #[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Rank {
     Two = 2,
@@ -44,6 +46,45 @@ impl Rank {
     pub fn value(&self) -> u8 {
         *self as u8
     }
+
+    /// Returns the single character used for the rank in text notation, such as 'T' for a Ten.
+    pub fn to_char(&self) -> char {
+        match self {
+            Rank::Two => '2',
+            Rank::Three => '3',
+            Rank::Four => '4',
+            Rank::Five => '5',
+            Rank::Six => '6',
+            Rank::Seven => '7',
+            Rank::Eight => '8',
+            Rank::Nine => '9',
+            Rank::Ten => 'T',
+            Rank::Jack => 'J',
+            Rank::Queen => 'Q',
+            Rank::King => 'K',
+            Rank::Ace => 'A',
+        }
+    }
+
+    /// Returns the rank for a character in text notation, if any.
+    pub fn from_char(character: char) -> Option<Rank> {
+        match character.to_ascii_uppercase() {
+            '2' => Some(Rank::Two),
+            '3' => Some(Rank::Three),
+            '4' => Some(Rank::Four),
+            '5' => Some(Rank::Five),
+            '6' => Some(Rank::Six),
+            '7' => Some(Rank::Seven),
+            '8' => Some(Rank::Eight),
+            '9' => Some(Rank::Nine),
+            'T' => Some(Rank::Ten),
+            'J' => Some(Rank::Jack),
+            'Q' => Some(Rank::Queen),
+            'K' => Some(Rank::King),
+            'A' => Some(Rank::Ace),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Rank {
@@ -72,6 +113,7 @@ impl fmt::Display for Rank {
 ///
 /// Suit values are based on the values for the game Bridge.
 #[derive(Clone, Copy, Debug, EnumIter, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Suit {
     Club = 0,
     Diamond = 1,
@@ -83,6 +125,27 @@ impl Suit {
     pub fn value(&self) -> u8 {
         *self as u8
     }
+
+    /// Returns the single character used for the suit in text notation, such as 'h' for a Heart.
+    pub fn to_char(&self) -> char {
+        match self {
+            Suit::Club => 'c',
+            Suit::Diamond => 'd',
+            Suit::Heart => 'h',
+            Suit::Spade => 's',
+        }
+    }
+
+    /// Returns the suit for a character in text notation, if any.
+    pub fn from_char(character: char) -> Option<Suit> {
+        match character.to_ascii_lowercase() {
+            'c' => Some(Suit::Club),
+            'd' => Some(Suit::Diamond),
+            'h' => Some(Suit::Heart),
+            's' => Some(Suit::Spade),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Suit {
@@ -98,7 +161,33 @@ impl fmt::Display for Suit {
     }
 }
 
+/// The reasons text may be unable to be parsed into a card.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseCardError {
+    /// The text is not made up of exactly a rank and a suit, such as "Kh".
+    InvalidLength,
+    /// The first character is not a valid rank.
+    InvalidRank,
+    /// The second character is not a valid suit.
+    InvalidSuit,
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ParseCardError::InvalidLength => {
+                "A card must be a rank followed by a suit, such as Kh."
+            }
+            ParseCardError::InvalidRank => "The rank must be one of 2-9, T, J, Q, K, or A.",
+            ParseCardError::InvalidSuit => "The suit must be one of c, d, h, or s.",
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Card {
     pub rank: Rank,
     pub suit: Suit,
@@ -114,6 +203,56 @@ impl Card {
         }
     }
 
+    /// Returns a copy of the card turned over so that a face up card is face down and vice versa.
+    pub fn flip(&self) -> Card {
+        Card {
+            face_up: !self.face_up,
+            ..*self
+        }
+    }
+
+    pub fn is_ace(&self) -> bool {
+        self.rank == Rank::Ace
+    }
+
+    pub fn is_king(&self) -> bool {
+        self.rank == Rank::King
+    }
+
+    pub fn is_queen(&self) -> bool {
+        self.rank == Rank::Queen
+    }
+
+    pub fn is_jack(&self) -> bool {
+        self.rank == Rank::Jack
+    }
+
+    pub fn is_ten(&self) -> bool {
+        self.rank == Rank::Ten
+    }
+
+    /// Returns whether or not the card is a Jack, Queen, King, or Ace.
+    pub fn is_face_card(&self) -> bool {
+        self.rank >= Rank::Jack
+    }
+
+    /// Returns whether or not the card is a Ten, Jack, Queen, King, or Ace.
+    pub fn is_broadway_card(&self) -> bool {
+        self.rank >= Rank::Ten
+    }
+
+    /// Returns the card in text notation, such as "Kh" for the King of Hearts.
+    pub fn to_notation(&self) -> String {
+        format!("{}{}", self.rank.to_char(), self.suit.to_char())
+    }
+
+    /// Returns the face up card for text notation, such as "Td" for the Ten of Diamonds, if any.
+    ///
+    /// Use parse() instead to find out why the text is not a valid card.
+    pub fn from_notation(notation: &str) -> Option<Card> {
+        notation.parse().ok()
+    }
+
     pub fn value(&self) -> u8 {
         match self.rank {
             Rank::Ace => 1,
@@ -145,11 +284,26 @@ impl Ord for Card {
 
 impl PartialOrd for Card {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let rank_ordering = self.rank.partial_cmp(&other.rank);
-        match rank_ordering {
-            Some(Ordering::Equal) => self.suit.partial_cmp(&other.suit),
-            _ => rank_ordering,
-        }
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    /// Parses a face up card from text notation, such as "Kh" for the King of Hearts.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut characters = s.trim().chars();
+        let (Some(rank), Some(suit), None) =
+            (characters.next(), characters.next(), characters.next())
+        else {
+            return Err(ParseCardError::InvalidLength);
+        };
+
+        let rank = Rank::from_char(rank).ok_or(ParseCardError::InvalidRank)?;
+        let suit = Suit::from_char(suit).ok_or(ParseCardError::InvalidSuit)?;
+
+        Ok(Card::new(rank, suit))
     }
 }
 
@@ -222,6 +376,8 @@ impl fmt::Display for Card {
 mod tests {
     use super::*;
 
+    use std::collections::HashSet;
+
     use strum::IntoEnumIterator;
 
     #[test]
@@ -264,6 +420,17 @@ mod tests {
         assert_eq!(Suit::iter().len(), 4);
     }
 
+    #[test]
+    fn iterating_ranks_and_suits_produces_every_card() {
+        assert_eq!(Rank::iter().count(), 13);
+        assert_eq!(Suit::iter().count(), 4);
+
+        let cards: HashSet<Card> = Suit::iter()
+            .flat_map(|suit| Rank::iter().map(move |rank| Card::new(rank, suit)))
+            .collect();
+        assert_eq!(cards.len(), 52);
+    }
+
     #[test]
     fn suit_values_are_correct() {
         assert_eq!(Suit::Club.value(), 0);
@@ -307,4 +474,72 @@ mod tests {
         let ace_of_spades_card = card!(Ace, Spade);
         assert_eq!(ace_of_spades_card.to_string(), "🂡");
     }
+
+    #[test]
+    fn rank_predicates_work() {
+        assert!(card!(Ace, Spade).is_ace());
+        assert!(card!(King, Heart).is_king());
+        assert!(card!(Queen, Diamond).is_queen());
+        assert!(card!(Jack, Club).is_jack());
+        assert!(card!(Ten, Spade).is_ten());
+        assert!(!card!(Nine, Spade).is_ten());
+
+        assert!(card!(Jack, Club).is_face_card());
+        assert!(card!(Ace, Club).is_face_card());
+        assert!(!card!(Ten, Club).is_face_card());
+
+        assert!(card!(Ten, Club).is_broadway_card());
+        assert!(!card!(Nine, Club).is_broadway_card());
+    }
+
+    #[test]
+    fn face_down_cards_are_hidden() {
+        let face_down_card = card!(Ace, Spade).flip();
+        assert!(!face_down_card.face_up);
+        assert_eq!(face_down_card.to_string(), "🂠");
+
+        let face_up_card = face_down_card.flip();
+        assert!(face_up_card.face_up);
+        assert_eq!(face_up_card.to_string(), "🂡");
+    }
+
+    #[test]
+    fn parsing_card_notation_round_trips() {
+        for suit in Suit::iter() {
+            for rank in Rank::iter() {
+                let card = Card::new(rank, suit);
+                assert_eq!(card.to_notation().parse::<Card>(), Ok(card));
+            }
+        }
+
+        assert_eq!(card!(King, Heart).to_notation(), "Kh");
+        assert_eq!("Ts".parse::<Card>(), Ok(card!(Ten, Spade)));
+        assert_eq!("K".parse::<Card>(), Err(ParseCardError::InvalidLength));
+        assert_eq!("Khs".parse::<Card>(), Err(ParseCardError::InvalidLength));
+        assert_eq!("1h".parse::<Card>(), Err(ParseCardError::InvalidRank));
+        assert_eq!("Kx".parse::<Card>(), Err(ParseCardError::InvalidSuit));
+    }
+
+    #[test]
+    fn from_notation_parses_every_card() {
+        let mut cards_count = 0;
+        for suit in ['c', 'd', 'h', 's'] {
+            for rank in [
+                '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+            ] {
+                let card = Card::from_notation(&format!("{rank}{suit}")).unwrap();
+                assert_eq!(card.rank.to_char(), rank);
+                assert_eq!(card.suit.to_char(), suit);
+                assert!(card.face_up);
+                cards_count += 1;
+            }
+        }
+        assert_eq!(cards_count, 52);
+
+        assert_eq!(Card::from_notation("Ks"), Some(card!(King, Spade)));
+        assert_eq!(Card::from_notation("Td"), Some(card!(Ten, Diamond)));
+        for notation in ["", "K", "10h", "1c", "Kx", "sK", "Ksd"] {
+            assert_eq!(Card::from_notation(notation), None);
+        }
+    }
 }
@@ -1,9 +1,38 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng, SeedableRng};
 use strum::IntoEnumIterator;
 
 use crate::card::{Card, Rank, Suit};
 
+/// The reasons a deck may be unable to be created from a given set of cards or to be cut.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeckError {
+    /// The same card was provided more than once.
+    DuplicateCard,
+    /// More than 52 cards were provided.
+    InvalidCardCount,
+    /// The position is not within the cards remaining in the deck.
+    InvalidPosition,
+}
+
+impl fmt::Display for DeckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            DeckError::DuplicateCard => "The same card cannot be in the deck more than once.",
+            DeckError::InvalidCardCount => "A deck cannot have more than 52 cards.",
+            DeckError::InvalidPosition => {
+                "The position must be within the cards remaining in the deck."
+            }
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Deck {
     cards: Vec<Card>,
@@ -24,9 +53,47 @@ impl Deck {
         Self { cards }
     }
 
+    /// Create a new short deck with 36 cards (Six through Ace for each of the 4 suits).
+    ///
+    /// This is used for short-deck (6+) poker, which removes the Twos through Fives.
+    pub fn new_short_deck() -> Self {
+        let mut cards = Vec::<Card>::new();
+
+        for suit in Suit::iter() {
+            for rank in Rank::iter().filter(|rank| *rank >= Rank::Six) {
+                let card = Card::new(rank, suit);
+                cards.push(card);
+            }
+        }
+
+        Self { cards }
+    }
+
+    /// Creates a new standard deck shuffled with a random number generator seeded from the given seed.
+    ///
+    /// The same seed always produces the same order of cards.
+    pub fn new_with_seed(seed: u64) -> Self {
+        let mut deck = Self::new();
+        deck.shuffle_with_rng(&mut StdRng::seed_from_u64(seed));
+        deck
+    }
+
     /// Creates a new deck from a given set of cards.
-    pub fn from_cards(cards: Vec<Card>) -> Self {
-        Deck { cards }
+    ///
+    /// The last card provided is the first card dealt, which is useful for creating predictable deals in tests.
+    pub fn from_cards(cards: Vec<Card>) -> Result<Self, DeckError> {
+        if cards.len() > 52 {
+            return Err(DeckError::InvalidCardCount);
+        }
+
+        let mut seen_cards = HashSet::new();
+        for card in &cards {
+            if !seen_cards.insert((card.rank, card.suit)) {
+                return Err(DeckError::DuplicateCard);
+            }
+        }
+
+        Ok(Deck { cards })
     }
 
     /// Checks if a given card is in the deck.
@@ -34,6 +101,20 @@ impl Deck {
         self.cards.contains(card)
     }
 
+    /// Cuts the deck by moving the given number of cards from the top of the deck to the bottom.
+    ///
+    /// The card at the given position from the top, counting from 0, becomes the new top card.
+    ///
+    /// Returns an error if the position is not within the cards remaining in the deck.
+    pub fn cut(&mut self, position: usize) -> Result<(), DeckError> {
+        if position >= self.cards.len() {
+            return Err(DeckError::InvalidPosition);
+        }
+
+        self.cards.rotate_right(position);
+        Ok(())
+    }
+
     /// Deals a card with the default face_up value.
     pub fn deal(&mut self) -> Option<Card> {
         if let Some(card) = self.cards.pop() {
@@ -44,6 +125,19 @@ impl Deck {
         }
     }
 
+    /// Deals exactly the given number of cards with the default face_up value, such as 4 cards for an Omaha hand.
+    ///
+    /// Returns None without dealing any cards if the deck has fewer cards remaining.
+    pub fn deal_n_cards(&mut self, n: usize) -> Option<Vec<Card>> {
+        if n > self.cards.len() {
+            return None;
+        }
+
+        let mut cards = self.cards.split_off(self.cards.len() - n);
+        cards.reverse();
+        Some(cards)
+    }
+
     /// Deals a card face up with the Rank and Suit visible.
     pub fn deal_face_up(&mut self) -> Option<Card> {
         if let Some(mut card) = self.cards.pop() {
@@ -106,6 +200,11 @@ impl Deck {
         self.cards.len()
     }
 
+    /// Returns the number of cards remaining to be dealt.
+    pub fn remaining(&self) -> usize {
+        self.cards.len()
+    }
+
     /// Removes a given card from the deck.
     ///
     /// The deal() function should normally be used instead of this.
@@ -121,11 +220,24 @@ impl Deck {
     /// Shuffles the cards in the deck.
     pub fn shuffle(&mut self) -> &mut Self {
         let mut rng = thread_rng();
-        self.cards.shuffle(&mut rng);
+        self.shuffle_with_rng(&mut rng)
+    }
+
+    /// Shuffles the cards in the deck using the provided random number generator.
+    ///
+    /// A seeded generator produces the same order every time, which is useful for replaying games.
+    pub fn shuffle_with_rng<R: Rng + ?Sized>(&mut self, rng: &mut R) -> &mut Self {
+        self.cards.shuffle(rng);
         self
     }
 }
 
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,6 +262,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn new_short_deck_contains_six_through_ace() {
+        let deck = Deck::new_short_deck();
+        assert_eq!(deck.len(), 36);
+
+        for suit in Suit::iter() {
+            for rank in Rank::iter() {
+                let card = Card::new(rank, suit);
+                assert_eq!(deck.contains(&card), rank >= Rank::Six);
+            }
+        }
+    }
+
     #[test]
     fn shuffling_cards_works() {
         let unshuffled_deck = Deck::new();
@@ -159,6 +284,18 @@ mod tests {
         assert_ne!(unshuffled_deck.cards, shuffled_deck.cards);
     }
 
+    #[test]
+    fn shuffling_cards_with_seeded_rng_is_repeatable() {
+        let mut first_deck = Deck::new();
+        let mut second_deck = Deck::new();
+        first_deck.shuffle_with_rng(&mut StdRng::seed_from_u64(7));
+        second_deck.shuffle_with_rng(&mut StdRng::seed_from_u64(7));
+
+        assert_eq!(first_deck.cards, second_deck.cards);
+        assert_ne!(first_deck.cards, Deck::new().cards);
+        assert_eq!(Deck::new_with_seed(7).cards, first_deck.cards);
+    }
+
     #[test]
     fn dealing_cards_works() {
         let mut deck = Deck::new();
@@ -167,4 +304,84 @@ mod tests {
             assert_eq!(deck.cards.len(), 51);
         }
     }
+
+    #[test]
+    fn cutting_deck_works() {
+        let mut deck = Deck::new();
+        assert!(deck.cut(26).is_ok());
+        assert_eq!(deck.len(), 52);
+
+        // The 27th card from the top of a new deck is the Ace of Diamonds.
+        assert_eq!(deck.deal(), Some(Card::new(Rank::Ace, Suit::Diamond)));
+        assert_eq!(deck.deal(), Some(Card::new(Rank::King, Suit::Diamond)));
+
+        assert!(deck.cut(49).is_ok());
+        assert_eq!(deck.cut(50), Err(DeckError::InvalidPosition));
+        assert_eq!(
+            Deck::from_cards(Vec::new()).unwrap().cut(0),
+            Err(DeckError::InvalidPosition)
+        );
+    }
+
+    #[test]
+    fn remaining_decreases_as_cards_are_dealt() {
+        let mut deck = Deck::new();
+        assert_eq!(deck.remaining(), 52);
+
+        deck.deal_n_cards(5);
+        assert_eq!(deck.remaining(), 47);
+    }
+
+    #[test]
+    fn from_cards_deals_in_given_order() {
+        let mut deck = Deck::from_cards(vec![
+            Card::new(Rank::Two, Suit::Club),
+            Card::new(Rank::Ace, Suit::Spade),
+        ])
+        .unwrap();
+
+        assert_eq!(deck.deal(), Some(Card::new(Rank::Ace, Suit::Spade)));
+        assert_eq!(deck.deal(), Some(Card::new(Rank::Two, Suit::Club)));
+    }
+
+    #[test]
+    fn from_cards_rejects_duplicate_cards() {
+        let result = Deck::from_cards(vec![
+            Card::new(Rank::Two, Suit::Club),
+            Card::new(Rank::Two, Suit::Club),
+        ]);
+
+        assert_eq!(result, Err(DeckError::DuplicateCard));
+    }
+
+    #[test]
+    fn from_cards_rejects_more_than_52_cards() {
+        let mut cards = Deck::new().cards;
+        cards.push(Card::new(Rank::Two, Suit::Club));
+
+        assert_eq!(Deck::from_cards(cards), Err(DeckError::InvalidCardCount));
+    }
+
+    #[test]
+    fn dealing_n_cards_works() {
+        let mut deck = Deck::from_cards(vec![
+            Card::new(Rank::Two, Suit::Club),
+            Card::new(Rank::Three, Suit::Club),
+            Card::new(Rank::Four, Suit::Club),
+        ])
+        .unwrap();
+
+        assert_eq!(deck.deal_n_cards(4), None);
+        assert_eq!(deck.len(), 3);
+
+        assert_eq!(
+            deck.deal_n_cards(2),
+            Some(vec![
+                Card::new(Rank::Four, Suit::Club),
+                Card::new(Rank::Three, Suit::Club)
+            ])
+        );
+        assert_eq!(deck.len(), 1);
+        assert_eq!(deck.deal_n_cards(0), Some(Vec::new()));
+    }
 }
@@ -1,6 +1,35 @@
-use crate::card::Card;
+use std::fmt;
+use std::str::FromStr;
 
-#[derive(Clone, Debug)]
+use crate::card::{Card, ParseCardError, Rank};
+use crate::deck::Deck;
+
+/// The reasons cards may be unable to be discarded from a hand.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DiscardError {
+    /// More than 3 cards, or 4 cards while keeping an Ace, were requested to be discarded.
+    TooManyDiscards,
+    /// An index does not match a card in the hand.
+    InvalidIndex,
+    /// The deck does not have enough cards to replace the discarded cards.
+    NotEnoughCards,
+}
+
+impl fmt::Display for DiscardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            DiscardError::TooManyDiscards => "Too many cards were requested to be discarded.",
+            DiscardError::InvalidIndex => "The index does not match a card in the hand.",
+            DiscardError::NotEnoughCards => {
+                "The deck does not have enough cards to replace the discards."
+            }
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Hand {
     pub cards: Vec<Card>,
 }
@@ -35,18 +64,60 @@ impl Hand {
         None
     }
 
-    pub fn to_string(&self) -> String {
-        let mut cards_string = String::new();
+    /// Discards the cards at the given indices and replaces them with cards dealt from the deck.
+    ///
+    /// Up to 3 cards may be discarded, or 4 if an Ace is kept in the hand.
+    /// The discarded cards are returned to the bottom of the deck after the replacements are dealt.
+    pub fn discard_and_replace(
+        &mut self,
+        indices: &[usize],
+        deck: &mut Deck,
+    ) -> Result<(), DiscardError> {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
 
-        for (i, &card) in self.cards.iter().enumerate() {
-            cards_string.push_str(&card.to_string());
+        if indices.iter().any(|&index| index >= self.cards.len()) {
+            return Err(DiscardError::InvalidIndex);
+        }
 
-            if i < self.cards.len() - 1 {
-                cards_string.push_str(" ");
+        let keeps_ace = self
+            .cards
+            .iter()
+            .enumerate()
+            .any(|(index, card)| card.rank == Rank::Ace && !indices.contains(&index));
+        let maximum_discards = if keeps_ace { 4 } else { 3 };
+        if indices.len() > maximum_discards {
+            return Err(DiscardError::TooManyDiscards);
+        }
+
+        if deck.len() < indices.len() {
+            return Err(DiscardError::NotEnoughCards);
+        }
+
+        let mut discarded_cards: Vec<Card> = Vec::new();
+        for &index in indices.iter().rev() {
+            discarded_cards.push(self.cards.remove(index));
+        }
+
+        for _ in 0..discarded_cards.len() {
+            if let Some(card) = deck.deal() {
+                self.cards.push(card);
             }
         }
 
-        cards_string
+        for card in discarded_cards {
+            deck.insert_at_bottom(card).unwrap();
+        }
+
+        Ok(())
+    }
+
+    /// Turns every card in the Hand face up, such as when the Hand is shown at showdown.
+    pub fn reveal(&mut self) {
+        for card in self.cards.iter_mut() {
+            card.face_up = true;
+        }
     }
 
     pub fn to_symbols(&self) -> String {
@@ -56,10 +127,151 @@ impl Hand {
             card_symbols.push_str(&card.to_string());
 
             if i < self.cards.len() - 1 {
-                card_symbols.push_str(" ");
+                card_symbols.push(' ');
             }
         }
 
         card_symbols
     }
+
+    /// Returns the Hand in text notation separated by spaces, such as "Ks Qh 9c".
+    ///
+    /// Face down cards are hidden as "??". A Hand of face up cards can be parsed back with parse().
+    pub fn to_notation(&self) -> String {
+        self.cards
+            .iter()
+            .map(|card| {
+                if card.face_up {
+                    card.to_notation()
+                } else {
+                    String::from("??")
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+impl Default for Hand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_symbols())
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseCardError;
+
+    /// Parses a hand from cards in text notation separated by whitespace, such as "Kh Qh".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = s
+            .split_whitespace()
+            .map(Card::from_str)
+            .collect::<Result<Vec<Card>, ParseCardError>>()?;
+
+        Ok(Hand::new_from_cards(cards))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::card;
+    use crate::card::Suit;
+
+    #[test]
+    fn discard_and_replace_works() {
+        let mut hand = Hand::new_from_cards(vec![
+            card!(Two, Club),
+            card!(Five, Heart),
+            card!(Nine, Spade),
+            card!(Jack, Diamond),
+            card!(King, Club),
+        ]);
+        let mut deck = Deck::new();
+        deck.remove(&card!(Two, Club)).unwrap();
+        let deck_size = deck.len();
+
+        hand.discard_and_replace(&[0, 2], &mut deck).unwrap();
+
+        assert_eq!(hand.cards.len(), 5);
+        assert!(!hand.cards.contains(&card!(Two, Club)));
+        assert!(!hand.cards.contains(&card!(Nine, Spade)));
+        assert_eq!(deck.len(), deck_size);
+        assert!(deck.contains(&card!(Two, Club)));
+    }
+
+    #[test]
+    fn discard_and_replace_limits_discards() {
+        let mut deck = Deck::new();
+        let mut hand = Hand::new_from_cards(vec![
+            card!(Two, Club),
+            card!(Five, Heart),
+            card!(Nine, Spade),
+            card!(Jack, Diamond),
+            card!(King, Club),
+        ]);
+        assert_eq!(
+            hand.discard_and_replace(&[0, 1, 2, 3], &mut deck),
+            Err(DiscardError::TooManyDiscards)
+        );
+        assert_eq!(
+            hand.discard_and_replace(&[5], &mut deck),
+            Err(DiscardError::InvalidIndex)
+        );
+
+        let mut hand_with_ace = Hand::new_from_cards(vec![
+            card!(Two, Club),
+            card!(Five, Heart),
+            card!(Nine, Spade),
+            card!(Jack, Diamond),
+            card!(Ace, Club),
+        ]);
+        assert!(hand_with_ace
+            .discard_and_replace(&[0, 1, 2, 3], &mut deck)
+            .is_ok());
+        assert!(hand_with_ace.cards.contains(&card!(Ace, Club)));
+    }
+
+    #[test]
+    fn parsing_hand_notation_works() {
+        let hand: Hand = "Kh Qh".parse().unwrap();
+        assert_eq!(hand.cards, vec![card!(King, Heart), card!(Queen, Heart)]);
+
+        assert_eq!("".parse::<Hand>(), Ok(Hand::new()));
+        assert_eq!("Kh Qx".parse::<Hand>(), Err(ParseCardError::InvalidSuit));
+    }
+
+    #[test]
+    fn to_notation_hides_face_down_cards() {
+        let hand: Hand = "Ks Qh 9c 8c 2c".parse().unwrap();
+        assert_eq!(hand.to_notation(), "Ks Qh 9c 8c 2c");
+        assert_eq!(hand.to_notation().parse::<Hand>(), Ok(hand));
+
+        let hand = Hand::new_from_cards(vec![card!(Ace, Spade), card!(Ten, Diamond).flip()]);
+        assert_eq!(hand.to_notation(), "As ??");
+
+        assert_eq!(Hand::new().to_notation(), "");
+    }
+
+    #[test]
+    fn reveal_turns_cards_face_up() {
+        let mut deck = Deck::new();
+        let mut hand = Hand::new_from_cards(vec![
+            deck.deal_face_down().unwrap(),
+            deck.deal_face_down().unwrap(),
+        ]);
+        assert_eq!(hand.to_symbols(), "🂠 🂠");
+
+        hand.reveal();
+
+        assert!(hand.cards.iter().all(|card| card.face_up));
+        assert!(!hand.to_symbols().contains('🂠'));
+    }
 }
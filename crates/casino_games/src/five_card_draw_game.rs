@@ -0,0 +1,344 @@
+use std::cmp::Ordering;
+use std::io::{self, Write};
+use std::process;
+
+use casino_poker::casino_cards::card::{Card, Rank};
+use casino_poker::casino_cards::deck::Deck;
+use casino_poker::casino_cards::hand::Hand;
+use casino_poker::hand_rankings::{compare_kickers, rank_hand, HandRank};
+use casino_poker::player::Player;
+use casino_poker::uuid::Uuid;
+
+use crate::texas_hold_em_game::get_player_name_prompt;
+
+const STARTING_CHIPS_AMOUNT: u32 = 100;
+const ANTE_AMOUNT: u32 = 5;
+const CPU_PLAYERS_COUNT: usize = 3;
+const CARDS_PER_HAND: usize = 5;
+const MAXIMUM_DISCARD_COUNT: usize = 3;
+// A player keeping an Ace may discard one more card.
+const MAXIMUM_DISCARD_COUNT_WITH_ACE: usize = 4;
+
+pub struct FiveCardDrawGame {
+    deck: Deck,
+    players: Vec<Player>,
+    user_identifier: Uuid,
+    pot: u32,
+}
+
+impl FiveCardDrawGame {
+    fn new(user: Player) -> Self {
+        let user_identifier = user.identifier;
+        let mut players = vec![user];
+        for number in 1..=CPU_PLAYERS_COUNT {
+            players.push(Player::new_with_chips(
+                &format!("CPU {number}"),
+                STARTING_CHIPS_AMOUNT,
+                0,
+            ));
+        }
+
+        Self {
+            deck: Deck::new(),
+            players,
+            user_identifier,
+            pot: 0,
+        }
+    }
+
+    fn play_tournament(&mut self) {
+        loop {
+            self.play_round();
+            self.players.retain(|player| player.chips >= ANTE_AMOUNT);
+
+            if !self.is_user_playing() {
+                println!("You are out of chips. Game over.\n");
+                break;
+            }
+            if self.players.len() < 2 {
+                println!("You won the game!\n");
+                break;
+            }
+
+            if !play_another_hand_prompt() {
+                println!("Game ended.\n");
+                break;
+            }
+        }
+    }
+
+    /// Play a single round of antes, one draw, and a showdown.
+    fn play_round(&mut self) {
+        self.deck = Deck::new();
+        self.deck.shuffle();
+        self.collect_antes();
+
+        let mut player_hands: Vec<(Uuid, Hand)> = Vec::new();
+        for player in self.players.iter() {
+            match self.deck.deal_n_cards(CARDS_PER_HAND) {
+                Some(cards) => player_hands.push((player.identifier, Hand::new_from_cards(cards))),
+                None => eprintln!("Error: Unable to deal a hand to {}.", player.name),
+            }
+        }
+
+        // Draw
+        for (player_identifier, hand) in player_hands.iter_mut() {
+            let discard_positions = if *player_identifier == self.user_identifier {
+                println!("Your hand: {}", to_face_up_symbols(hand));
+                discard_prompt(hand)
+            } else {
+                choose_cpu_discards(hand)
+            };
+
+            let discard_count = discard_hand_cards(hand, &discard_positions);
+            if let Some(cards) = self.deck.deal_n_cards(discard_count) {
+                for card in cards {
+                    hand.push(card);
+                }
+            }
+
+            if let Some(player) = self.get_player(player_identifier) {
+                println!(
+                    "{} draws {} card{}.",
+                    player.name,
+                    discard_count,
+                    if discard_count == 1 { "" } else { "s" }
+                );
+            }
+        }
+
+        println!();
+        self.showdown(&player_hands);
+    }
+
+    /// Every player puts the ante into the pot before the cards are dealt.
+    fn collect_antes(&mut self) {
+        for player in self.players.iter_mut() {
+            player.subtract_chips(ANTE_AMOUNT);
+            self.pot += ANTE_AMOUNT;
+        }
+
+        println!(
+            "Each player antes {ANTE_AMOUNT} chips. The pot is {} chips.\n",
+            self.pot
+        );
+    }
+
+    /// Rank every hand and split the pot between the players with the best hand.
+    fn showdown(&mut self, player_hands: &[(Uuid, Hand)]) {
+        let mut winners: Vec<(Uuid, HandRank, Vec<Card>)> = Vec::new();
+
+        for (player_identifier, hand) in player_hands {
+            let cards: Vec<Card> = hand.get_cards().clone();
+            let hand_rank = rank_hand(cards.clone());
+            if let Some(player) = self.get_player(player_identifier) {
+                println!(
+                    "{} shows {} ({})",
+                    player.name,
+                    to_face_up_symbols(hand),
+                    hand_rank
+                );
+            }
+
+            let ordering = match winners.first() {
+                None => Ordering::Greater,
+                Some((_, best_hand_rank, best_cards)) => hand_rank
+                    .cmp(best_hand_rank)
+                    .then_with(|| compare_kickers(&hand_rank, &cards, best_cards)),
+            };
+            match ordering {
+                Ordering::Greater => winners = vec![(*player_identifier, hand_rank, cards)],
+                Ordering::Equal => winners.push((*player_identifier, hand_rank, cards)),
+                Ordering::Less => {}
+            }
+        }
+
+        if winners.is_empty() {
+            return;
+        }
+
+        // Any chips that do not divide evenly go to the first winner.
+        let share = self.pot / winners.len() as u32;
+        let remainder = self.pot % winners.len() as u32;
+        self.pot = 0;
+
+        println!();
+        for (index, (player_identifier, hand_rank, _)) in winners.iter().enumerate() {
+            let chips_won = if index == 0 { share + remainder } else { share };
+            if let Some(player) = self
+                .players
+                .iter_mut()
+                .find(|player| player.identifier == *player_identifier)
+            {
+                player.add_chips(chips_won);
+                println!(
+                    "{} wins {} chips with {}. ({} chips)",
+                    player.name, chips_won, hand_rank, player.chips
+                );
+            }
+        }
+    }
+
+    fn get_player(&self, player_identifier: &Uuid) -> Option<&Player> {
+        self.players
+            .iter()
+            .find(|player| player.identifier == *player_identifier)
+    }
+
+    fn is_user_playing(&self) -> bool {
+        self.get_player(&self.user_identifier).is_some()
+    }
+}
+
+pub fn play_game() {
+    println!("**********************");
+    println!("* ♠ Five-card draw ♠ *");
+    println!("**********************");
+
+    let user_name = get_player_name_prompt();
+    let user = Player::new_with_chips(&user_name, STARTING_CHIPS_AMOUNT, 0);
+
+    let mut five_card_draw = FiveCardDrawGame::new(user);
+    five_card_draw.play_tournament();
+}
+
+/// Ask the user which cards to discard by their positions in the hand.
+///
+/// Returns the zero-based positions of the cards to discard.
+fn discard_prompt(hand: &Hand) -> Vec<usize> {
+    loop {
+        println!(
+            "Enter the positions (1-{CARDS_PER_HAND}) of up to {MAXIMUM_DISCARD_COUNT} cards to discard, or {MAXIMUM_DISCARD_COUNT_WITH_ACE} if you keep an Ace."
+        );
+        print!("Discard: ");
+        io::stdout().flush().expect("Failed to flush stdout.");
+
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)
+            .expect("Failed to read line");
+        let trimmed_input = input.trim().to_lowercase();
+
+        if trimmed_input == "q" || trimmed_input == "quit" {
+            println!("Quitting game.");
+            process::exit(0);
+        }
+
+        let positions: Result<Vec<usize>, _> = trimmed_input
+            .split_whitespace()
+            .map(|position| position.parse::<usize>())
+            .collect();
+
+        match positions {
+            Ok(positions)
+                if positions
+                    .iter()
+                    .all(|position| (1..=CARDS_PER_HAND).contains(position)) =>
+            {
+                let mut positions: Vec<usize> =
+                    positions.iter().map(|position| position - 1).collect();
+                positions.sort();
+                positions.dedup();
+
+                if is_valid_discard(hand, &positions) {
+                    return positions;
+                }
+                println!("You are unable to discard that many cards.");
+            }
+            _ => println!("Error: Please enter the card positions separated by spaces."),
+        }
+    }
+}
+
+/// Returns whether or not the discard is within the limit, which is higher when the player keeps an Ace.
+fn is_valid_discard(hand: &Hand, discard_positions: &[usize]) -> bool {
+    let keeps_ace =
+        hand.get_cards().iter().enumerate().any(|(position, card)| {
+            card.rank == Rank::Ace && !discard_positions.contains(&position)
+        });
+
+    discard_positions.len() <= MAXIMUM_DISCARD_COUNT
+        || (keeps_ace && discard_positions.len() <= MAXIMUM_DISCARD_COUNT_WITH_ACE)
+}
+
+/// Keep the cards that make up the hand rank and discard the rest, up to the allowed limit.
+///
+/// A CPU player with only a high card keeps it and discards the lowest cards.
+fn choose_cpu_discards(hand: &Hand) -> Vec<usize> {
+    let hand_rank = rank_hand(hand.get_cards().clone());
+    if matches!(
+        hand_rank,
+        HandRank::Straight(_)
+            | HandRank::Flush(_)
+            | HandRank::FullHouse(_)
+            | HandRank::StraightFlush(_)
+    ) {
+        return Vec::new();
+    }
+
+    let kept_ranks = hand_rank.component_ranks();
+    let mut discard_positions: Vec<usize> = hand
+        .get_cards()
+        .iter()
+        .enumerate()
+        .filter(|(_, card)| !kept_ranks.contains(&card.rank))
+        .map(|(position, _)| position)
+        .collect();
+
+    // Discard the lowest cards first.
+    discard_positions.sort_by_key(|&position| hand.get_cards()[position].rank);
+    while !is_valid_discard(hand, &discard_positions) {
+        discard_positions.pop();
+    }
+    discard_positions.sort();
+
+    discard_positions
+}
+
+/// Remove the cards at the provided positions from the hand and return how many were removed.
+fn discard_hand_cards(hand: &mut Hand, discard_positions: &[usize]) -> usize {
+    let kept_cards: Vec<Card> = hand
+        .get_cards()
+        .iter()
+        .enumerate()
+        .filter(|(position, _)| !discard_positions.contains(position))
+        .map(|(_, card)| *card)
+        .collect();
+    let discard_count = hand.get_cards().len() - kept_cards.len();
+    *hand = Hand::new_from_cards(kept_cards);
+
+    discard_count
+}
+
+/// Get the symbols of the hand's cards as if they were all face up.
+fn to_face_up_symbols(hand: &Hand) -> String {
+    let mut revealed_hand = hand.clone();
+    revealed_hand.reveal();
+    revealed_hand.to_symbols()
+}
+
+fn play_another_hand_prompt() -> bool {
+    loop {
+        println!("\nPlay another hand?");
+        print!("yes/no [Y/n]: ");
+        io::stdout().flush().expect("Failed to flush stdout.");
+
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)
+            .expect("Failed to read line");
+
+        match input.trim().to_lowercase().as_str() {
+            "q" | "quit" => {
+                println!("Quitting game.");
+                process::exit(0);
+            }
+            "n" | "no" => return false,
+            "y" | "yes" | "" => {
+                println!();
+                return true;
+            }
+            _ => println!("Invalid input. Please enter 'y' or 'n' or enter 'q' to quit the game."),
+        }
+    }
+}
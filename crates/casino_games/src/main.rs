@@ -1,6 +1,7 @@
 use std::io::{self, Write};
 use std::process;
 
+mod five_card_draw_game;
 mod texas_hold_em_game;
 
 fn main() {
@@ -15,6 +16,7 @@ fn main() {
 fn select_game() {
     println!("Games");
     println!("Texas hold 'em");
+    println!("Five-card draw");
     println!("Which game would you like to play?");
     print!("Game: ");
     io::stdout().flush().expect("Failed to flush stdout.");
@@ -34,6 +36,10 @@ fn select_game() {
             println!();
             texas_hold_em_game::play_game();
         }
+        "fivecarddraw" | "draw" => {
+            println!();
+            five_card_draw_game::play_game();
+        }
         _ => println!(
             "Invalid input. Please enter the name of a game listed above or enter 'q' to quit.\n"
         ),
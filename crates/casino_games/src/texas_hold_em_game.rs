@@ -65,7 +65,7 @@ impl TexasHoldEmGame {
 
             loop {
                 println!("\nPlay another hand?");
-                print!("yes/no [Y/n]: ");
+                print!("yes/no/help [Y/n/h]: ");
                 io::stdout().flush().expect("Failed to flush stdout.");
 
                 let mut input = String::new();
@@ -79,6 +79,10 @@ impl TexasHoldEmGame {
                         println!("Quitting game.");
                         process::exit(0);
                     }
+                    "h" | "help" => {
+                        println!();
+                        TexasHoldEm::display_hand_rankings_table();
+                    }
                     "n" | "no" => {
                         self.game.end_game();
                         println!("Game ended.\n");
@@ -100,17 +104,19 @@ impl TexasHoldEmGame {
 
     // todo: implement betting system
     // todo: implement folding
-    // todo: implement side pot correctly
     // todo: implement hand timer
     /// Play a single round.
     pub fn play_round(&mut self) {
         // Pre-round
-        self.game.rotate_dealer();
+        self.game.reset_player_round_state();
+        if let Err(error) = self.game.rotate_dealer() {
+            eprintln!("Error: {}", error);
+            return;
+        }
         self.game.shuffle_deck();
         self.game.add_players_to_main_pot();
         self.game.print_dealer();
-        self.game.post_blind(true);
-        self.game.post_blind(false);
+        self.game.create_main_pot_from_blinds();
 
         println!();
 
@@ -121,7 +127,9 @@ impl TexasHoldEmGame {
 
         // Print the user's hand
         if let Some(user_hand) = player_hands.get(&self.user.identifier) {
-            println!("Your hand: {}\n", user_hand.to_symbols());
+            let mut revealed_hand = user_hand.clone();
+            revealed_hand.reveal();
+            println!("Your hand: {}\n", revealed_hand.to_symbols());
         } else {
             eprintln!(
                 "Unable to get user's hand with the identifier: {}",
@@ -148,18 +156,12 @@ impl TexasHoldEmGame {
             }
 
             // These remain the same for the following betting rounds
-            starting_better_seat_index = self.game.get_small_blind_seat_index();
+            starting_better_seat_index = self.game.get_first_to_act_after_the_flop_seat_index();
             starting_bet_amount = 0;
 
             // Flop
-            if let Some(card) = self.game.deal_card() {
-                burned_cards.push(card);
-            }
-
-            for _ in 0..3 {
-                if let Some(card) = self.game.deal_card() {
-                    table_cards.push(card);
-                }
+            for card in self.game.deal_flop() {
+                table_cards.push(card);
             }
 
             println!("** FLOP **");
@@ -181,11 +183,7 @@ impl TexasHoldEmGame {
             }
 
             // Turn
-            if let Some(card) = self.game.deal_card() {
-                burned_cards.push(card);
-            }
-
-            if let Some(card) = self.game.deal_card() {
+            for card in self.game.deal_turn() {
                 table_cards.push(card);
             }
 
@@ -208,11 +206,7 @@ impl TexasHoldEmGame {
             }
 
             // River
-            if let Some(card) = self.game.deal_card() {
-                burned_cards.push(card);
-            }
-
-            if let Some(card) = self.game.deal_card() {
+            for card in self.game.deal_river() {
                 table_cards.push(card);
             }
 
@@ -239,7 +233,10 @@ impl TexasHoldEmGame {
 
         // Determine winners
         let winning_players = self.game.rank_all_hands(&player_hands, &table_cards);
-        self.game.determine_round_result(&winning_players);
+        let round_result = self
+            .game
+            .determine_round_result(&player_hands, &table_cards, &winning_players);
+        self.game.print_round_summary(&round_result);
 
     // Post-round
         self.game
@@ -345,7 +342,9 @@ impl TexasHoldEmGame {
                                 current_player.name, current_table_bet
                             );
                             current_player.subtract_chips(current_table_bet);
-                            self.game.add_chips_to_main_pot(current_table_bet);
+                            if let Err(error) = self.game.add_chips_to_main_pot(current_table_bet) {
+                                eprintln!("Error: {}", error);
+                            }
                         }
                         PlayerAction::Check() => {
                             println!("{} checks.", current_player.name);
@@ -376,7 +375,9 @@ impl TexasHoldEmGame {
 
                             last_player_to_raise_identifier = Some(current_player.identifier);
                             current_player.subtract_chips(total_bet);
-                            self.game.add_chips_to_main_pot(total_bet);
+                            if let Err(error) = self.game.add_chips_to_main_pot(total_bet) {
+                                eprintln!("Error: {}", error);
+                            }
                             current_table_bet += bet;
 
                             println!("The current bet is now {current_table_bet}.");
@@ -405,14 +406,19 @@ pub fn play_game() {
     println!("* ♠ Texas hold 'em ♠ *");
     println!("**********************");
 
-    let (small_blind_amount, big_blind_amount) = choose_table();
-
-    let mut texas_hold_em_1_3_no_limit = TexasHoldEm::new(
-        MINIMUM_CHIPS_BUY_IN_AMOUNT,
-        MAXIMUM_PLAYERS_COUNT,
-        small_blind_amount,
-        big_blind_amount,
-    );
+    let mut texas_hold_em_1_3_no_limit = loop {
+        let (small_blind_amount, big_blind_amount) = choose_table();
+
+        match TexasHoldEm::new(
+            MINIMUM_CHIPS_BUY_IN_AMOUNT,
+            MAXIMUM_PLAYERS_COUNT,
+            small_blind_amount,
+            big_blind_amount,
+        ) {
+            Ok(texas_hold_em) => break texas_hold_em,
+            Err(error) => println!("{}\n", error),
+        }
+    };
 
     let user_name = get_player_name_prompt();
     let mut player1 = texas_hold_em_1_3_no_limit.new_player(&user_name);
@@ -436,7 +442,7 @@ pub fn play_game() {
     texas_hold_em.play_tournament();
 }
 
-fn get_player_name_prompt() -> String {
+pub(crate) fn get_player_name_prompt() -> String {
     loop {
         print!("Enter your name: ");
         io::stdout().flush().expect("Failed to flush stdout.");
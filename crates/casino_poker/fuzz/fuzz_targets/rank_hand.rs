@@ -0,0 +1,75 @@
+#![no_main]
+
+use std::cmp::Ordering;
+
+use libfuzzer_sys::fuzz_target;
+use strum::IntoEnumIterator;
+
+use casino_cards::card::{Card, Rank, Suit};
+use casino_poker::hand_rankings::rank_hand;
+
+/// The number of cards rank_hand() accepts, from the hole cards alone up to a complete board.
+const HAND_SIZES: [usize; 6] = [2, 3, 4, 5, 6, 7];
+
+/// Build two hands without duplicate cards by drawing from a standard 52-card deck.
+///
+/// The first byte chooses the size of each hand and every following byte chooses the next card to draw.
+fn hands_from_bytes(data: &[u8]) -> Option<(Vec<Card>, Vec<Card>)> {
+    let (&sizes, indices) = data.split_first()?;
+    let first_size = HAND_SIZES[usize::from(sizes & 0b1111) % HAND_SIZES.len()];
+    let second_size = HAND_SIZES[usize::from(sizes >> 4) % HAND_SIZES.len()];
+
+    let mut deck: Vec<Card> = Suit::iter()
+        .flat_map(|suit| Rank::iter().map(move |rank| Card::new(rank, suit)))
+        .collect();
+
+    let mut draw = |size: usize, indices: &[u8]| -> Option<Vec<Card>> {
+        let indices = indices.get(..size)?;
+        Some(
+            indices
+                .iter()
+                .map(|&index| deck.swap_remove(usize::from(index) % deck.len()))
+                .collect(),
+        )
+    };
+
+    let first_hand = draw(first_size, indices)?;
+    let second_hand = draw(second_size, &indices[first_size..])?;
+
+    Some((first_hand, second_hand))
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((first_hand, second_hand)) = hands_from_bytes(data) else {
+        return;
+    };
+
+    let first_rank = rank_hand(first_hand.clone());
+    let second_rank = rank_hand(second_hand);
+
+    // Every card in the hand rank must come from the cards provided.
+    for card in first_rank.dominant_cards() {
+        assert!(
+            first_hand.contains(card),
+            "{first_rank} uses a card that was not provided"
+        );
+    }
+
+    // The order the cards are provided in must not change the result.
+    let mut reversed_hand = first_hand;
+    reversed_hand.reverse();
+    let reversed_rank = rank_hand(reversed_hand);
+    assert_eq!(first_rank.cmp(&reversed_rank), Ordering::Equal);
+    assert_eq!(first_rank, reversed_rank);
+
+    // Ord and PartialEq must agree with each other.
+    assert_eq!(
+        first_rank.cmp(&second_rank) == Ordering::Equal,
+        first_rank == second_rank,
+        "{first_rank:?} and {second_rank:?} disagree between cmp() and eq()"
+    );
+    assert_eq!(
+        first_rank.cmp(&second_rank),
+        second_rank.cmp(&first_rank).reverse()
+    );
+});
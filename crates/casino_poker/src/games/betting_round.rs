@@ -0,0 +1,227 @@
+use std::collections::{HashMap, HashSet};
+
+use uuid::Uuid;
+
+/// The betting state of a single street.
+///
+/// Tracks the current bet, how much each player has bet, and which players still need to act on it.
+#[derive(Clone, Debug, Default)]
+pub struct BettingRound {
+    current_bet: u32,
+    last_raiser: Option<Uuid>,
+    active_players: Vec<Uuid>,
+    acted: HashSet<Uuid>,
+    bets: HashMap<Uuid, u32>,
+    betting_open: bool,
+    betting_closed: bool,
+}
+
+impl BettingRound {
+    /// Create a betting round where the provided players act in the order given.
+    pub fn new(active_players: Vec<Uuid>) -> Self {
+        Self {
+            active_players,
+            ..Self::default()
+        }
+    }
+
+    /// Get the highest total bet that players must match.
+    pub fn current_bet(&self) -> u32 {
+        self.current_bet
+    }
+
+    /// Get the player who made the last raise during the street, if any.
+    pub fn last_raiser(&self) -> Option<Uuid> {
+        self.last_raiser
+    }
+
+    /// Get the total amount the player has bet during the street.
+    pub fn get_bet(&self, player_identifier: &Uuid) -> u32 {
+        self.bets.get(player_identifier).copied().unwrap_or(0)
+    }
+
+    /// Get the amount of chips the player needs to add to match the current bet.
+    pub fn get_amount_to_call(&self, player_identifier: &Uuid) -> u32 {
+        self.current_bet
+            .saturating_sub(self.get_bet(player_identifier))
+    }
+
+    /// Returns whether or not a bet has been placed during the street.
+    ///
+    /// Players are able to check until betting is open, after which they must call the bet instead.
+    pub fn is_betting_open(&self) -> bool {
+        self.betting_open
+    }
+
+    /// Returns whether or not raises are still allowed during the street.
+    pub fn is_raising_allowed(&self) -> bool {
+        !self.betting_closed
+    }
+
+    /// Disallow any further raises during the street.
+    pub fn close_betting(&mut self) {
+        self.betting_closed = true;
+    }
+
+    /// Replace the players who act during the street without clearing the bets already made.
+    ///
+    /// This is used to carry forced bets, such as the blinds, into the betting round.
+    pub fn set_active_players(&mut self, active_players: Vec<Uuid>) {
+        self.active_players = active_players;
+        self.acted.clear();
+    }
+
+    /// Record a forced bet that does not count as the player acting.
+    pub fn post_blind(&mut self, player_identifier: Uuid, amount: u32) {
+        let bet = self.bets.entry(player_identifier).or_default();
+        *bet += amount;
+        self.current_bet = self.current_bet.max(*bet);
+        if amount > 0 {
+            self.betting_open = true;
+        }
+    }
+
+    /// Record the player acting by adding the amount to their bet.
+    ///
+    /// An amount of zero is a check. If the player's total bet exceeds the current bet it is a raise,
+    /// and every other active player must act again starting with the player after the raiser.
+    pub fn place_bet(&mut self, player_identifier: Uuid, amount: u32) {
+        let bet = self.bets.entry(player_identifier).or_default();
+        *bet += amount;
+        if amount > 0 {
+            self.betting_open = true;
+        }
+
+        if *bet > self.current_bet {
+            self.current_bet = *bet;
+            self.last_raiser = Some(player_identifier);
+            self.acted.clear();
+
+            if let Some(position) = self
+                .active_players
+                .iter()
+                .position(|identifier| *identifier == player_identifier)
+            {
+                self.active_players.rotate_left(position + 1);
+            }
+        }
+
+        self.acted.insert(player_identifier);
+    }
+
+    /// Remove a player who is no longer able to act during the street, such as after folding.
+    pub fn remove_player(&mut self, player_identifier: &Uuid) {
+        self.active_players
+            .retain(|identifier| identifier != player_identifier);
+        self.acted.remove(player_identifier);
+    }
+
+    /// Get the identifier of the next player who needs to act.
+    pub fn next_to_act(&self) -> Option<Uuid> {
+        self.active_players
+            .iter()
+            .find(|identifier| !self.acted.contains(identifier))
+            .copied()
+    }
+
+    /// Returns whether or not every active player has acted on the current bet.
+    pub fn is_complete(&self) -> bool {
+        self.next_to_act().is_none()
+    }
+
+    /// Clear all bets and prepare for the next street with the provided players acting in order.
+    pub fn reset_for_new_street(&mut self, active_players: Vec<Uuid>) {
+        self.current_bet = 0;
+        self.last_raiser = None;
+        self.active_players = active_players;
+        self.acted.clear();
+        self.bets.clear();
+        self.betting_open = false;
+        self.betting_closed = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests place_bet().
+    ///
+    /// Tests that a raise requires every other active player to act again.
+    #[test]
+    fn place_bet_raise_reopens_action() {
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let third = Uuid::new_v4();
+        let mut betting_round = BettingRound::new(vec![first, second, third]);
+
+        assert_eq!(betting_round.next_to_act(), Some(first));
+        betting_round.place_bet(first, 0);
+        betting_round.place_bet(second, 10);
+
+        assert_eq!(betting_round.current_bet(), 10);
+        assert_eq!(betting_round.last_raiser(), Some(second));
+        assert_eq!(betting_round.next_to_act(), Some(third));
+        assert_eq!(betting_round.get_amount_to_call(&first), 10);
+
+        betting_round.place_bet(third, 10);
+        assert_eq!(betting_round.next_to_act(), Some(first));
+        assert!(!betting_round.is_complete());
+
+        betting_round.place_bet(first, 10);
+        assert!(betting_round.is_complete());
+
+        betting_round.reset_for_new_street(vec![first, second]);
+        assert_eq!(betting_round.current_bet(), 0);
+        assert_eq!(betting_round.get_bet(&second), 0);
+        assert_eq!(betting_round.next_to_act(), Some(first));
+    }
+
+    /// Tests post_blind().
+    ///
+    /// Tests that blinds set the current bet without counting as the player acting.
+    #[test]
+    fn post_blind_does_not_count_as_acting() {
+        let small_blind = Uuid::new_v4();
+        let big_blind = Uuid::new_v4();
+        let mut betting_round = BettingRound::default();
+
+        betting_round.post_blind(small_blind, 2);
+        betting_round.post_blind(big_blind, 5);
+        betting_round.set_active_players(vec![small_blind, big_blind]);
+
+        assert_eq!(betting_round.current_bet(), 5);
+        assert_eq!(betting_round.get_amount_to_call(&small_blind), 3);
+
+        betting_round.place_bet(small_blind, 3);
+        assert_eq!(betting_round.next_to_act(), Some(big_blind));
+
+        betting_round.place_bet(big_blind, 0);
+        assert!(betting_round.is_complete());
+    }
+
+    /// Tests is_betting_open() and close_betting().
+    ///
+    /// Tests that betting opens with the first bet and that both states reset with the next street.
+    #[test]
+    fn betting_open_and_closed_states_transition() {
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let mut betting_round = BettingRound::new(vec![first, second]);
+        assert!(!betting_round.is_betting_open());
+        assert!(betting_round.is_raising_allowed());
+
+        betting_round.place_bet(first, 0);
+        assert!(!betting_round.is_betting_open());
+
+        betting_round.place_bet(second, 10);
+        assert!(betting_round.is_betting_open());
+
+        betting_round.close_betting();
+        assert!(!betting_round.is_raising_allowed());
+
+        betting_round.reset_for_new_street(vec![first, second]);
+        assert!(!betting_round.is_betting_open());
+        assert!(betting_round.is_raising_allowed());
+    }
+}
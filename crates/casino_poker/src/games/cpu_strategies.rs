@@ -0,0 +1,179 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use casino_cards::card;
+use casino_cards::card::{Card, Rank, Suit};
+use casino_cards::hand::Hand;
+
+use crate::games::texas_hold_em::PlayerAction;
+use crate::hand_rankings::HandRank;
+
+/// Chooses the actions of a computer-controlled player.
+pub trait CpuStrategy {
+    /// Choose an action for the player's turn.
+    ///
+    /// The amount to call is the chips the player must add to match the current bet, and the hand rank
+    /// is the best hand the player can make from their hand and the face up table cards.
+    fn choose_action(
+        &mut self,
+        hand: &Hand,
+        table_cards: &Hand,
+        amount_to_call: u32,
+        chips: u32,
+        hand_rank: &HandRank,
+    ) -> PlayerAction;
+}
+
+/// Chooses a random action out of the actions the player is able to take.
+///
+/// The strategy never folds when it is able to check, and a raise is for a random amount up to all of the player's chips.
+#[derive(Clone, Debug)]
+pub struct RandomStrategy {
+    rng: StdRng,
+}
+
+impl RandomStrategy {
+    pub fn new() -> Self {
+        Self {
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Create a strategy that always chooses the same actions for the same seed.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Default for RandomStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CpuStrategy for RandomStrategy {
+    fn choose_action(
+        &mut self,
+        _hand: &Hand,
+        _table_cards: &Hand,
+        amount_to_call: u32,
+        chips: u32,
+        _hand_rank: &HandRank,
+    ) -> PlayerAction {
+        let mut actions: Vec<PlayerAction> = Vec::new();
+        if amount_to_call == 0 {
+            actions.push(PlayerAction::Check());
+        } else {
+            actions.push(PlayerAction::Fold());
+            if chips >= amount_to_call {
+                actions.push(PlayerAction::Call());
+            }
+        }
+        if chips > amount_to_call {
+            let raise_amount = self.rng.gen_range(1..=chips - amount_to_call);
+            actions.push(PlayerAction::Raise(raise_amount));
+        }
+
+        let index = self.rng.gen_range(0..actions.len());
+        actions.swap_remove(index)
+    }
+}
+
+/// Only stays in the hand with a hand rank of at least the minimum hand rank.
+///
+/// The strategy checks when possible, calls when the hand is at least the minimum hand rank, and folds otherwise.
+#[derive(Clone, Debug)]
+pub struct ConservativeStrategy {
+    minimum_hand_rank: HandRank,
+}
+
+impl ConservativeStrategy {
+    pub fn new(minimum_hand_rank: HandRank) -> Self {
+        Self { minimum_hand_rank }
+    }
+}
+
+impl Default for ConservativeStrategy {
+    /// Calls with a Jack high or better.
+    fn default() -> Self {
+        Self::new(HandRank::HighCard(card!(Jack, Spade)))
+    }
+}
+
+impl CpuStrategy for ConservativeStrategy {
+    fn choose_action(
+        &mut self,
+        _hand: &Hand,
+        _table_cards: &Hand,
+        amount_to_call: u32,
+        chips: u32,
+        hand_rank: &HandRank,
+    ) -> PlayerAction {
+        if amount_to_call == 0 {
+            PlayerAction::Check()
+        } else if *hand_rank >= self.minimum_hand_rank && chips >= amount_to_call {
+            PlayerAction::Call()
+        } else {
+            PlayerAction::Fold()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests RandomStrategy::choose_action().
+    ///
+    /// Tests that only actions the player is able to take are chosen.
+    #[test]
+    fn random_strategy_chooses_legal_actions() {
+        let mut strategy = RandomStrategy::new_with_seed(7);
+        let hand = Hand::new_from_cards(vec![card!(Two, Club), card!(Seven, Diamond)]);
+        let hand_rank = HandRank::HighCard(card!(Seven, Diamond));
+
+        for _ in 0..100 {
+            match strategy.choose_action(&hand, &Hand::new(), 0, 50, &hand_rank) {
+                PlayerAction::Check() => {}
+                PlayerAction::Raise(amount) => assert!((1..=50).contains(&amount)),
+                action => panic!(
+                    "{:?} is not expected when there is nothing to call.",
+                    action
+                ),
+            }
+
+            match strategy.choose_action(&hand, &Hand::new(), 20, 10, &hand_rank) {
+                PlayerAction::Fold() => {}
+                action => panic!("{:?} is not possible without enough chips to call.", action),
+            }
+        }
+    }
+
+    /// Tests ConservativeStrategy::choose_action().
+    ///
+    /// Tests that the strategy folds below the minimum hand rank and calls otherwise.
+    #[test]
+    fn conservative_strategy_folds_below_minimum_hand_rank() {
+        let mut strategy = ConservativeStrategy::default();
+        let table_cards = Hand::new();
+        let weak_hand = Hand::new_from_cards(vec![card!(Two, Club), card!(Seven, Diamond)]);
+        let weak_hand_rank = HandRank::HighCard(card!(Seven, Diamond));
+        let strong_hand = Hand::new_from_cards(vec![card!(Four, Club), card!(Four, Diamond)]);
+        let strong_hand_rank = HandRank::Pair([card!(Four, Club), card!(Four, Diamond)]);
+
+        assert_eq!(
+            strategy.choose_action(&weak_hand, &table_cards, 0, 100, &weak_hand_rank),
+            PlayerAction::Check()
+        );
+        assert_eq!(
+            strategy.choose_action(&weak_hand, &table_cards, 10, 100, &weak_hand_rank),
+            PlayerAction::Fold()
+        );
+        assert_eq!(
+            strategy.choose_action(&strong_hand, &table_cards, 10, 100, &strong_hand_rank),
+            PlayerAction::Call()
+        );
+    }
+}
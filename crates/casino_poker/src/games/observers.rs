@@ -0,0 +1,167 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use uuid::Uuid;
+
+use casino_cards::hand::Hand;
+
+use crate::games::texas_hold_em::PlayerAction;
+use crate::hand_rankings::HandRank;
+
+/// Receives events from a game as it is played.
+///
+/// Every method has an empty default implementation, so an observer only needs to implement
+/// the events it is interested in.
+pub trait GameObserver {
+    /// Called at the start of a round once the dealer has been chosen.
+    fn on_round_start(&mut self, _dealer_identifier: Uuid) {}
+
+    /// Called when a player is dealt their hand.
+    fn on_cards_dealt(&mut self, _player_identifier: Uuid, _hand: &Hand) {}
+
+    /// Called when a player performs an action during a betting round.
+    fn on_player_action(&mut self, _player_identifier: Uuid, _action: &PlayerAction) {}
+
+    /// Called when community cards are dealt to the table.
+    fn on_community_cards(&mut self, _table_cards: &Hand) {}
+
+    /// Called at the end of a round with the winning players and their hand ranks.
+    fn on_round_end(&mut self, _winning_players: &HashMap<Uuid, Vec<HandRank>>) {}
+}
+
+/// Prints every game event to stdout.
+#[derive(Clone, Debug, Default)]
+pub struct LoggingObserver;
+
+impl LoggingObserver {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl GameObserver for LoggingObserver {
+    fn on_round_start(&mut self, dealer_identifier: Uuid) {
+        println!("[log] Round started with dealer {}.", dealer_identifier);
+    }
+
+    fn on_cards_dealt(&mut self, player_identifier: Uuid, hand: &Hand) {
+        println!(
+            "[log] {} card{} dealt to {}.",
+            hand.cards.len(),
+            if hand.cards.len() == 1 { "" } else { "s" },
+            player_identifier
+        );
+    }
+
+    fn on_player_action(&mut self, player_identifier: Uuid, action: &PlayerAction) {
+        println!("[log] {} performed {:?}.", player_identifier, action);
+    }
+
+    fn on_community_cards(&mut self, table_cards: &Hand) {
+        println!("[log] Table cards: {}", table_cards.to_symbols());
+    }
+
+    fn on_round_end(&mut self, winning_players: &HashMap<Uuid, Vec<HandRank>>) {
+        for player_identifier in winning_players.keys() {
+            println!("[log] Round won by {}.", player_identifier);
+        }
+    }
+}
+
+/// Tallies game events so they can be inspected after play.
+///
+/// Clones share the same tallies, which allows a clone to be given to the game
+/// while the original is kept to read the results.
+#[derive(Clone, Debug, Default)]
+pub struct StatisticsObserver {
+    statistics: Rc<RefCell<ObservedStatistics>>,
+}
+
+#[derive(Debug, Default)]
+struct ObservedStatistics {
+    rounds_started: usize,
+    rounds_ended: usize,
+    hands_dealt: HashMap<Uuid, usize>,
+    actions: HashMap<Uuid, usize>,
+    rounds_won: HashMap<Uuid, usize>,
+}
+
+impl StatisticsObserver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of rounds that have started.
+    pub fn rounds_started(&self) -> usize {
+        self.statistics.borrow().rounds_started
+    }
+
+    /// Returns the number of rounds that have ended.
+    pub fn rounds_ended(&self) -> usize {
+        self.statistics.borrow().rounds_ended
+    }
+
+    /// Returns the number of hands dealt to the player.
+    pub fn hands_dealt(&self, player_identifier: &Uuid) -> usize {
+        self.statistics
+            .borrow()
+            .hands_dealt
+            .get(player_identifier)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of actions the player has performed.
+    pub fn actions(&self, player_identifier: &Uuid) -> usize {
+        self.statistics
+            .borrow()
+            .actions
+            .get(player_identifier)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of rounds the player has won or pushed.
+    pub fn rounds_won(&self, player_identifier: &Uuid) -> usize {
+        self.statistics
+            .borrow()
+            .rounds_won
+            .get(player_identifier)
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl GameObserver for StatisticsObserver {
+    fn on_round_start(&mut self, _dealer_identifier: Uuid) {
+        self.statistics.borrow_mut().rounds_started += 1;
+    }
+
+    fn on_cards_dealt(&mut self, player_identifier: Uuid, _hand: &Hand) {
+        *self
+            .statistics
+            .borrow_mut()
+            .hands_dealt
+            .entry(player_identifier)
+            .or_default() += 1;
+    }
+
+    fn on_player_action(&mut self, player_identifier: Uuid, _action: &PlayerAction) {
+        *self
+            .statistics
+            .borrow_mut()
+            .actions
+            .entry(player_identifier)
+            .or_default() += 1;
+    }
+
+    fn on_round_end(&mut self, winning_players: &HashMap<Uuid, Vec<HandRank>>) {
+        let mut statistics = self.statistics.borrow_mut();
+        statistics.rounds_ended += 1;
+
+        for player_identifier in winning_players.keys() {
+            *statistics.rounds_won.entry(*player_identifier).or_default() += 1;
+        }
+    }
+}
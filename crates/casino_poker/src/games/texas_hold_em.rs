@@ -1,12 +1,21 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io::{self, BufRead, Write};
 
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{thread_rng, Rng, SeedableRng};
 use uuid::Uuid;
 
-use casino_cards::card::Card;
+use casino_cards::card;
+use casino_cards::card::{Card, Rank, Suit};
 use casino_cards::deck::Deck;
 use casino_cards::hand::Hand;
 
-use crate::hand_rankings::{get_high_card_value, rank_hand, HandRank};
+use crate::games::betting_round::BettingRound;
+use crate::games::cpu_strategies::{ConservativeStrategy, CpuStrategy};
+use crate::games::observers::GameObserver;
+use crate::hand_rankings::{best_five_cards, compare_kickers, rank_hand, BestFive, HandRank};
 use crate::player::Player;
 
 /// The actions a Player can choose from on their turn.
@@ -23,44 +32,429 @@ pub enum PlayerAction {
     Raise(u32),
 }
 
+/// How the betting rounds are run while a round is played.
+enum RoundBetting<'a> {
+    /// Skip betting entirely.
+    None,
+    /// Every player checks, or calls when checking is not possible.
+    CheckEveryStreet,
+    /// Every player chooses their action from lines read from the input.
+    FromInput(&'a mut dyn BufRead),
+}
+
+/// The betting rounds of a hand.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Street {
+    /// Betting after the hands are dealt and before any table cards are shown.
+    PreFlop,
+    /// Betting after the first three table cards are dealt.
+    Flop,
+    /// Betting after the fourth table card is dealt.
+    Turn,
+    /// Betting after the fifth and final table card is dealt.
+    River,
+}
+
+impl Street {
+    /// Get the street that follows this one.
+    /// The River is the final street.
+    pub fn next(&self) -> Street {
+        match self {
+            Street::PreFlop => Street::Flop,
+            Street::Flop => Street::Turn,
+            Street::Turn => Street::River,
+            Street::River => Street::River,
+        }
+    }
+}
+
+impl fmt::Display for Street {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let street = match self {
+            Street::PreFlop => "Pre-flop",
+            Street::Flop => "Flop",
+            Street::Turn => "Turn",
+            Street::River => "River",
+        };
+
+        write!(f, "{}", street)
+    }
+}
+
+/// The rules that limit how much a player is able to raise.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BettingMode {
+    /// A raise may be any amount up to all of the player's chips.
+    #[default]
+    NoLimit,
+    /// A raise must be exactly the big blind before the turn, and double the big blind on the turn and river.
+    Limit,
+    /// A raise may be no more than the size of the pot after the player calls.
+    PotLimit,
+}
+
+impl fmt::Display for BettingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let betting_mode = match self {
+            BettingMode::NoLimit => "No-limit",
+            BettingMode::Limit => "Limit",
+            BettingMode::PotLimit => "Pot-limit",
+        };
+
+        write!(f, "{}", betting_mode)
+    }
+}
+
+/// A single action taken by a player during a hand.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActionRecord {
+    pub player_identifier: Uuid,
+    pub street: Street,
+    pub action: PlayerAction,
+    pub pot_before: u32,
+    pub pot_after: u32,
+}
+
+/// The outcome of a completed hand.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HandResult {
+    pub pot_amount: u32,
+    pub winner_identifiers: Vec<Uuid>,
+    pub winning_hand_rank: Option<HandRank>,
+    pub profit_loss: HashMap<Uuid, i64>,
+    pub actions: Vec<ActionRecord>,
+    /// The players in seat order with the chips they had at the start of the hand.
+    pub starting_stacks: Vec<(Uuid, u32)>,
+}
+
+/// The round number, the winners, the pot, and the hand rank of every player at the showdown of a round.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundSummary {
+    pub round_number: u32,
+    pub winner_identifiers: Vec<Uuid>,
+    pub pot_amount: u32,
+    pub hand_ranks: HashMap<Uuid, HandRank>,
+}
+
+/// The cards dealt, the winners, and the chips awarded in a round.
+///
+/// The default is the result of a round that could not be played, with no cards dealt and no winners.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RoundResult {
+    pub player_hands: HashMap<Uuid, Hand>,
+    pub table_cards: Hand,
+    pub winning_players: HashMap<Uuid, Vec<HandRank>>,
+    pub pot_amount: u32,
+    pub chips_won: HashMap<Uuid, u32>,
+}
+
+/// The results of every hand a player has played and their chips after each one.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Statistics {
+    pub name: String,
+    pub wins: u32,
+    pub losses: u32,
+    pub pushes: u32,
+    pub hands_played: u32,
+    pub chip_history: Vec<u32>,
+}
+
+/// Statistics aggregated over every hand played in a game.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GameStatistics {
+    pub total_hands: usize,
+    pub total_chips_in_play: u32,
+    pub average_pot: f64,
+    pub hand_type_frequencies: HashMap<String, usize>,
+    pub biggest_pot: u32,
+    pub biggest_pot_winner: Option<String>,
+    pub average_profit_loss_per_player: HashMap<String, f64>,
+}
+
+/// The reasons a player may be unable to fold.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FoldError {
+    /// The player is not seated at the table.
+    PlayerNotFound,
+    /// The player has already folded in the current hand.
+    AlreadyFolded,
+    /// There is no hand in progress to fold from.
+    NotInBettingRound,
+}
+
+impl fmt::Display for FoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            FoldError::PlayerNotFound => "The player is not at the table.",
+            FoldError::AlreadyFolded => "The player has already folded.",
+            FoldError::NotInBettingRound => "There is no hand in progress.",
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+/// The minimum buy-in must be at least this many big blinds.
+pub const MINIMUM_BUY_IN_BIG_BLINDS: u32 = 20;
+
+/// The reasons the buy-in and blind amounts may be invalid for a table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConfigError {
+    /// The small blind amount is zero.
+    ZeroSmallBlind,
+    /// The big blind amount is less than the small blind amount.
+    BigBlindBelowSmallBlind,
+    /// The minimum buy-in is less than MINIMUM_BUY_IN_BIG_BLINDS times the big blind amount.
+    BuyInTooSmall,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ConfigError::ZeroSmallBlind => "The small blind amount must be greater than zero.",
+            ConfigError::BigBlindBelowSmallBlind => {
+                "The big blind amount must be at least the small blind amount."
+            }
+            ConfigError::BuyInTooSmall => {
+                return write!(
+                    f,
+                    "The minimum buy-in must be at least {} times the big blind amount.",
+                    MINIMUM_BUY_IN_BIG_BLINDS
+                );
+            }
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+/// The reasons a player may be unable to join the table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AddPlayerError {
+    /// The table already has the maximum number of players.
+    TableFull,
+    /// The player does not have the minimum amount of chips required to buy in.
+    NotEnoughChips,
+    /// The player is already at the table or waiting to join it.
+    AlreadyJoined,
+}
+
+impl fmt::Display for AddPlayerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            AddPlayerError::TableFull => "Unable to join the table. It is already at max capacity.",
+            AddPlayerError::NotEnoughChips => {
+                "The player does not have enough chips to play at this table."
+            }
+            AddPlayerError::AlreadyJoined => "The player has already joined the table.",
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+/// The reasons a player may be unable to be ejected from the table.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EjectError {
+    /// The player is not seated at the table.
+    PlayerNotFound,
+}
+
+impl fmt::Display for EjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            EjectError::PlayerNotFound => "The player is not at the table.",
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+/// The reasons chips may be unable to be awarded from a pot.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AwardError {
+    /// The player is not seated at the table.
+    PlayerNotFound,
+    /// There are no players to award the chips to.
+    NoPlayers,
+    /// The pot has no chips to award.
+    EmptyPot,
+}
+
+impl fmt::Display for AwardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            AwardError::PlayerNotFound => "The player is not at the table.",
+            AwardError::NoPlayers => "There are no players to award the chips to.",
+            AwardError::EmptyPot => "The pot has no chips to award.",
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
+/// The ways the seats and the players at the table may not match.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IntegrityError {
+    /// A seat is occupied by a player who is not at the table.
+    SeatWithoutPlayer(Uuid),
+    /// A player at the table does not have a seat.
+    PlayerWithoutSeat(Uuid),
+    /// A player occupies more than one seat.
+    DuplicateSeat(Uuid),
+}
+
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityError::SeatWithoutPlayer(identifier) => write!(
+                f,
+                "The seat for {} does not belong to a player at the table.",
+                identifier
+            ),
+            IntegrityError::PlayerWithoutSeat(identifier) => {
+                write!(f, "The player {} does not have a seat.", identifier)
+            }
+            IntegrityError::DuplicateSeat(identifier) => {
+                write!(f, "The player {} has more than one seat.", identifier)
+            }
+        }
+    }
+}
+
+/// The reasons a bounty may be unable to be awarded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BountyError {
+    /// The eliminated player or the bounty hunter is not seated at the table.
+    PlayerNotFound,
+    /// The player still has chips, so they have not been eliminated.
+    PlayerNotEliminated,
+    /// A player cannot collect the bounty for eliminating themselves.
+    SamePlayer,
+}
+
+impl fmt::Display for BountyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            BountyError::PlayerNotFound => "The player is not at the table.",
+            BountyError::PlayerNotEliminated => "The player has not been eliminated.",
+            BountyError::SamePlayer => "A player cannot collect their own bounty.",
+        };
+
+        write!(f, "{}", message)
+    }
+}
+
 /// The core of the Texas hold 'em game.
 ///
-/// The game currently defaults to no-limit.
+/// The game defaults to no-limit, which can be changed with set_betting_mode().
 pub struct TexasHoldEm {
     game_over: bool,
     deck: Deck,
     players: HashMap<Uuid, Player>,
     seats: Vec<Uuid>,
     dealer_seat_index: usize,
+    big_blind_identifier: Option<Uuid>,
     main_pot: Pot,
     side_pots: Vec<Pot>,
     minimum_chips_buy_in_amount: u32,
     maximum_players_count: usize,
     small_blind_amount: u32,
     big_blind_amount: u32,
+    betting_mode: BettingMode,
+    hand_in_progress: bool,
+    current_betting_round: Option<BettingRound>,
+    observers: Vec<Box<dyn GameObserver>>,
+    pending_removal: HashSet<Uuid>,
+    pending_joins: Vec<Player>,
+    street: Street,
+    player_action_history: Vec<ActionRecord>,
+    player_hand_ranks: HashMap<Uuid, HandRank>,
+    dealt_hands: HashMap<Uuid, Hand>,
+    hand_history: Vec<HandResult>,
+    rounds_played: u32,
+    round_history: Vec<RoundSummary>,
+    player_statistics: HashMap<Uuid, Statistics>,
+    eliminated_players: Vec<Player>,
+    burned_cards: Hand,
+    randomize_seats: bool,
+    auto_remove_broke: bool,
+    cut_deck: bool,
+    community_cards: Hand,
+    cpu_strategies: HashMap<Uuid, Box<dyn CpuStrategy>>,
+    rng: Option<StdRng>,
+    verbose: bool,
 }
 
 impl TexasHoldEm {
     /// Create a new game that internally contains a deck and players.
+    ///
+    /// Returns an error if the small blind is zero, the big blind is less than the small blind,
+    /// or the minimum buy-in is less than MINIMUM_BUY_IN_BIG_BLINDS times the big blind.
     pub fn new(
         minimum_chips_buy_in_amount: u32,
         maximum_players_count: usize,
         small_blind_amount: u32,
         big_blind_amount: u32,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, ConfigError> {
+        validate_config(
+            minimum_chips_buy_in_amount,
+            small_blind_amount,
+            big_blind_amount,
+        )?;
+
+        Ok(Self {
             game_over: false,
             deck: Deck::new(),
             players: HashMap::new(),
             seats: Vec::new(),
             dealer_seat_index: 0,
-            main_pot: Pot::new(0, HashMap::new()),
+            big_blind_identifier: None,
+            main_pot: Pot::new(0, HashSet::new()),
             side_pots: Vec::new(),
             minimum_chips_buy_in_amount,
             maximum_players_count,
             small_blind_amount,
             big_blind_amount,
-        }
+            betting_mode: BettingMode::NoLimit,
+            hand_in_progress: false,
+            current_betting_round: None,
+            observers: Vec::new(),
+            pending_removal: HashSet::new(),
+            pending_joins: Vec::new(),
+            street: Street::PreFlop,
+            player_action_history: Vec::new(),
+            player_hand_ranks: HashMap::new(),
+            dealt_hands: HashMap::new(),
+            hand_history: Vec::new(),
+            rounds_played: 0,
+            round_history: Vec::new(),
+            player_statistics: HashMap::new(),
+            eliminated_players: Vec::new(),
+            burned_cards: Hand::new(),
+            randomize_seats: false,
+            auto_remove_broke: false,
+            cut_deck: false,
+            community_cards: Hand::new(),
+            cpu_strategies: HashMap::new(),
+            rng: None,
+            verbose: false,
+        })
+    }
+
+    /// Set whether or not information only specific players should know, such as the cards dealt to each
+    /// player, is printed while the game is played.
+    ///
+    /// This is off by default, so a game can be watched without revealing any player's hand.
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Register an observer to be notified of game events.
+    pub fn add_observer(&mut self, observer: Box<dyn GameObserver>) {
+        self.observers.push(observer);
     }
 
     // Create a new player with zero chips.
@@ -70,12 +464,12 @@ impl TexasHoldEm {
 
     // Create a new player with a defined amount of chips.
     pub fn new_player_with_chips(&mut self, name: &str, chips: u32) -> Player {
-        Player::new_with_chips(name, chips)
+        Player::new_with_chips(name, chips, 0)
     }
 
     /// Add a player into the game.
     pub fn add_player(&mut self, player: Player) -> Result<(), &'static str> {
-        if self.players.len() > self.maximum_players_count {
+        if self.players.len() >= self.maximum_players_count {
             return Err("Unable to join the table. It is already at max capacity.");
         }
 
@@ -100,17 +494,90 @@ impl TexasHoldEm {
 
         self.seats.push(player.identifier);
         self.players.insert(player.identifier, player);
+        debug_assert!(self.validate_seat_integrity().is_ok());
+        Ok(())
+    }
+
+    /// Add a computer-controlled player into the game.
+    ///
+    /// CPU players choose their own actions with the provided strategy through execute_cpu_action() during betting rounds.
+    pub fn add_cpu_player(
+        &mut self,
+        player: Player,
+        strategy: Box<dyn CpuStrategy>,
+    ) -> Result<(), &'static str> {
+        let player_identifier = player.identifier;
+        self.add_player(player)?;
+        self.cpu_strategies.insert(player_identifier, strategy);
+        Ok(())
+    }
+
+    /// Returns whether or not the player is computer-controlled.
+    pub fn is_cpu_player(&self, player_identifier: &Uuid) -> bool {
+        self.cpu_strategies.contains_key(player_identifier)
+    }
+
+    /// Add a player into the game once the current round is over.
+    ///
+    /// If a hand is in progress, the player is seated at the start of the next round so that they are not dealt into it.
+    /// Otherwise the player is seated immediately.
+    pub fn add_player_for_next_round(&mut self, player: Player) -> Result<(), AddPlayerError> {
+        if self.players.len() + self.pending_joins.len() >= self.maximum_players_count {
+            return Err(AddPlayerError::TableFull);
+        }
+
+        if player.chips < self.minimum_chips_buy_in_amount {
+            return Err(AddPlayerError::NotEnoughChips);
+        }
+
+        if self.players.contains_key(&player.identifier)
+            || self
+                .pending_joins
+                .iter()
+                .any(|pending_player| pending_player.identifier == player.identifier)
+        {
+            return Err(AddPlayerError::AlreadyJoined);
+        }
+
+        if !self.hand_in_progress {
+            // add_player() only rejects a player for the reasons checked above, using the same messages.
+            return self.add_player(player).map_err(|error| {
+                if error == AddPlayerError::NotEnoughChips.to_string() {
+                    AddPlayerError::NotEnoughChips
+                } else {
+                    AddPlayerError::TableFull
+                }
+            });
+        }
+
+        println!(
+            "{} will join the table at the start of the next round.",
+            player.name
+        );
+        self.pending_joins.push(player);
         Ok(())
     }
 
+    /// Seat all players that are waiting to join the table.
+    pub fn add_pending_players(&mut self) {
+        for player in std::mem::take(&mut self.pending_joins) {
+            if let Err(error) = self.add_player(player) {
+                eprintln!("Error: {}", error);
+            }
+        }
+    }
+
     /// Remove a player from the game.
+    ///
+    /// A CPU player keeps their strategy so they play the same way if they are seated again, such as in the next
+    /// tournament of play_n_tournaments().
     pub fn remove_player(&mut self, player_identifier: &Uuid) -> Option<Player> {
         if self.players.is_empty() {
             eprintln!("Unable to remove player. The table is empty.");
             return None;
         }
 
-        if self.players.get(&player_identifier).is_none() {
+        if !self.players.contains_key(player_identifier) {
             eprintln!(
                 "Unable to remove player. The identifier {} is not at the table.",
                 player_identifier
@@ -122,1459 +589,6658 @@ impl TexasHoldEm {
         }
 
         // Remove and return player
-        self.players.remove(&player_identifier)
+        let player = self.players.remove(player_identifier);
+        debug_assert!(self.validate_seat_integrity().is_ok());
+        player
     }
 
-    /// Simulates a tournament consisting of multiple rounds without betting or folding.
-    pub fn play_tournament(&mut self) {
-        while !self.game_over {
-            self.print_leaderboard();
-            self.simulate_round();
-            self.remove_losers();
-            self.check_for_game_over();
-        }
-    }
+    /// Check that every seat belongs to a player at the table and every player at the table has exactly one seat.
+    pub fn validate_seat_integrity(&self) -> Result<(), IntegrityError> {
+        let mut seated_players: HashSet<Uuid> = HashSet::new();
 
-    pub fn remove_losers(&mut self) {
-        for (identifier, player) in self.players.clone() {
-            if player.chips == 0 {
-                println!(
-                    "{} is out of chips and was removed from the game.",
-                    player.name
-                );
-                self.remove_player(&identifier);
+        for player_identifier in self.seats.iter() {
+            if !self.players.contains_key(player_identifier) {
+                return Err(IntegrityError::SeatWithoutPlayer(*player_identifier));
             }
-        }
-    }
 
-    pub fn check_for_game_over(&mut self) -> bool {
-        if self.players.is_empty() {
-            println!("No players remaining. Game over!");
-            self.end_game();
+            if !seated_players.insert(*player_identifier) {
+                return Err(IntegrityError::DuplicateSeat(*player_identifier));
+            }
         }
 
-        if self.players.len() == 1 {
-            println!("One player remaining. Game over!");
-            self.end_game();
+        for player_identifier in self.players.keys() {
+            if !seated_players.contains(player_identifier) {
+                return Err(IntegrityError::PlayerWithoutSeat(*player_identifier));
+            }
         }
 
-        self.game_over
+        Ok(())
     }
 
-    /// End the game.
-    pub fn end_game(&mut self) {
-        self.game_over = true;
-    }
+    /// Forcibly remove a player from the game immediately, such as for unsportsmanlike conduct.
+    ///
+    /// Any chips the player has already bet remain in the pot.
+    /// Returns the ejected player with the chips they had remaining.
+    pub fn eject_player_for_unsportsmanlike_conduct(
+        &mut self,
+        player_identifier: Uuid,
+    ) -> Result<Player, EjectError> {
+        if !self.players.contains_key(&player_identifier) {
+            return Err(EjectError::PlayerNotFound);
+        }
 
-    /// Print statistics about the players currently seated at the table.
-    /// Players are printed from the highest to lowest amount of chips.
-    pub fn print_leaderboard(&self) {
-        // Step 1: Create a vector of (player_identifier, chips) tuples
-        let mut player_stats: Vec<(&Uuid, &Player)> = self
-            .seats
-            .iter()
-            .filter_map(|player_identifier| {
-                self.players
-                    .get(player_identifier)
-                    .map(|player| (player_identifier, player))
-            })
-            .collect();
+        if let Some(betting_round) = self.current_betting_round.as_mut() {
+            betting_round.remove_player(&player_identifier);
+        }
+        self.pending_removal.remove(&player_identifier);
 
-        // Step 2: Sort players by the number of chips (descending order)
-        player_stats.sort_by(|(_, player1), (_, player2)| player2.chips.cmp(&player1.chips));
+        let player = self
+            .remove_player(&player_identifier)
+            .ok_or(EjectError::PlayerNotFound)?;
+        println!(
+            "{} was ejected from the game for unsportsmanlike conduct.",
+            player.name
+        );
 
-        println!("***************");
-        println!("* LEADERBOARD *");
-        println!("***************");
+        Ok(player)
+    }
 
-        // Step 3: Print the sorted list
-        for &(_player_identifier, player) in &player_stats {
-            println!(
-                "{}: {} chip{}",
-                player.name,
-                player.chips,
-                if player.chips == 1 { "" } else { "s" }
+    /// Schedule a player to be removed from the game once the current round is over.
+    pub fn remove_player_after_round(&mut self, player_identifier: Uuid) {
+        if !self.players.contains_key(&player_identifier) {
+            eprintln!(
+                "Unable to schedule the removal of the player. The identifier {} is not at the table.",
+                player_identifier
             );
+            return;
         }
-        println!();
-    }
-
-    /// Simulates a single round with no betting or folding.
-    pub fn simulate_round(&mut self) {
-        // Pre-round
-        self.rotate_dealer();
-        self.shuffle_deck();
-        self.add_players_to_main_pot();
-        self.print_dealer();
-        self.post_blind(true);
-        self.post_blind(false);
-
-        println!();
-
-        // Initializing these as Hand because it is a Vec<Card> that can print as symbols if needed
-        let mut table_cards = Hand::new();
-        let mut burned_cards = Hand::new();
-        let player_hands = self.deal_hands_to_all_players();
 
-        // Flop
-        if let Some(card) = self.deal_card() {
-            burned_cards.push(card);
-        }
+        self.pending_removal.insert(player_identifier);
+    }
 
-        for _ in 0..3 {
-            if let Some(card) = self.deal_card() {
-                table_cards.push(card);
+    /// Remove all players that were scheduled to be removed after the round.
+    pub fn remove_pending_players(&mut self) {
+        for player_identifier in std::mem::take(&mut self.pending_removal) {
+            if let Some(player) = self.remove_player(&player_identifier) {
+                println!("{} left the table.", player.name);
             }
         }
+    }
 
-        // Turn
-        if let Some(card) = self.deal_card() {
-            burned_cards.push(card);
+    /// Fold a player's hand for the rest of the current hand.
+    ///
+    /// The player keeps their seat and any chips they have already bet remain in the pot.
+    /// Folded players are not considered when ranking hands.
+    pub fn fold_player(&mut self, player_identifier: Uuid) -> Result<(), FoldError> {
+        if !self.hand_in_progress {
+            return Err(FoldError::NotInBettingRound);
         }
 
-        if let Some(card) = self.deal_card() {
-            table_cards.push(card);
-        }
+        let Some(player) = self.players.get_mut(&player_identifier) else {
+            return Err(FoldError::PlayerNotFound);
+        };
 
-        // River
-        if let Some(card) = self.deal_card() {
-            burned_cards.push(card);
+        if player.is_folded() {
+            return Err(FoldError::AlreadyFolded);
         }
 
-        if let Some(card) = self.deal_card() {
-            table_cards.push(card);
+        println!("{} folds.", player.name);
+        player.fold();
+        if let Some(betting_round) = self.current_betting_round.as_mut() {
+            betting_round.remove_player(&player_identifier);
         }
+        Ok(())
+    }
 
-        println!("Table cards:");
-        println!("{}", table_cards.to_symbols());
-        println!();
+    /// Set the rules that limit how much a player is able to raise.
+    pub fn set_betting_mode(&mut self, betting_mode: BettingMode) {
+        self.betting_mode = betting_mode;
+    }
 
-        // Determine winners
-        let winning_players = self.rank_all_hands(&player_hands, &table_cards);
-        self.determine_round_result(&winning_players);
+    /// Get the rules that limit how much a player is able to raise.
+    pub fn get_betting_mode(&self) -> BettingMode {
+        self.betting_mode
+    }
 
-        // Post-round
-        self.reset_deck(player_hands, table_cards, burned_cards);
-        self.reset_pots();
+    /// Set whether or not players without chips are removed from the table after each round in simulate_n_rounds().
+    pub fn set_auto_remove_broke(&mut self, auto_remove_broke: bool) {
+        self.auto_remove_broke = auto_remove_broke;
     }
 
-    /// Shuffle the game's deck.
-    /// This is required at the start of every round.
-    pub fn shuffle_deck(&mut self) {
-        self.deck.shuffle();
+    /// Set whether or not the seats are randomly assigned at the start of a tournament.
+    pub fn set_randomize_seats(&mut self, randomize_seats: bool) {
+        self.randomize_seats = randomize_seats;
     }
 
-    /// Rotate the dealer button clockwise to the next player.
-    /// This must happen before the start of the next round.
-    /// This will also update the small blind and big blind players.
-    pub fn rotate_dealer(&mut self) {
-        self.dealer_seat_index = (self.dealer_seat_index + 1) % self.seats.len();
+    /// Set whether or not the deck is cut at a random position near the middle after it is shuffled.
+    pub fn set_cut_deck(&mut self, cut_deck: bool) {
+        self.cut_deck = cut_deck;
     }
 
-    /// Print the name of the player that has the dealer button for the round.
-    pub fn print_dealer(&self) {
-        if let Some(dealer_identifier) = self.seats.get(self.dealer_seat_index) {
-            if let Some(dealer) = self.players.get(dealer_identifier) {
-                println!("{} is the dealer.", dealer.name);
-            } else {
-                eprintln!(
-                    "Error: Unable to find the dealer with the id {}",
-                    dealer_identifier
-                );
-            }
-        } else {
-            eprintln!(
-                "Error: Unable to find the dealer at seat {}",
-                self.dealer_seat_index
-            );
+    /// Randomly assign every player at the table to a new seat.
+    pub fn assign_random_seats(&mut self) {
+        match self.rng.as_mut() {
+            Some(rng) => self.seats.shuffle(rng),
+            None => self.seats.shuffle(&mut thread_rng()),
         }
     }
 
-    /// Add all players at the table to the main betting pot.
-    pub fn add_players_to_main_pot(&mut self) {
-        for (identifier, player) in self.players.clone() {
-            self.main_pot.add_player(identifier, player);
+    /// Reset the per-hand state of every player, such as whether or not they have folded.
+    ///
+    /// This must be called before each new hand and is the single place where players are reset between hands.
+    /// The bets made by each player are tracked by the betting round and are reset with every street.
+    pub fn reset_player_round_state(&mut self) {
+        for player in self.players.values_mut() {
+            player.reset_for_new_round();
         }
     }
 
-    /// Get the seat index of the small blind player.
-    /// This must happen before the start of the next round.
-    /// This must happen after rotate_dealer() is executed.
-    pub fn get_small_blind_seat_index(&self) -> usize {
-        (self.dealer_seat_index + 1) % self.seats.len()
+    /// Get the minimum amount of chips a player needs to play a hand without going all in.
+    pub fn minimum_chips_to_play(&self) -> u32 {
+        self.big_blind_amount
     }
 
-    /// Get the seat index of the small blind player.
-    /// This must happen before the start of the next round.
-    /// This must happen after rotate_dealer() is executed.
-    pub fn get_big_blind_seat_index(&self) -> usize {
-        (self.dealer_seat_index + 2) % self.seats.len()
-    }
+    /// Check that every player has enough chips to play the next hand before it is dealt.
+    ///
+    /// Players without any chips are folded so they are not dealt a hand,
+    /// and players with fewer chips than the minimum are flagged as all in.
+    pub fn flag_short_stacked_players(&mut self) {
+        let minimum_chips_to_play = self.minimum_chips_to_play();
 
-    /// Get the seat index of the player to the left of the big blind.
-    /// Aka under the gun.
-    pub fn get_under_the_gun_seat_index(&self) -> usize {
-        self.rotate_current_player(self.get_big_blind_seat_index())
+        for player in self.players.values_mut() {
+            if player.chips == 0 {
+                eprintln!(
+                    "Error: {} has no chips and will not be dealt a hand.",
+                    player.name
+                );
+                player.fold();
+            } else if player.chips < minimum_chips_to_play {
+                println!(
+                    "{} has fewer chips than the big blind and is all in.",
+                    player.name
+                );
+                player.all_in = true;
+            }
+        }
     }
 
-    pub fn subtract_chips_from_player(&mut self, player_identifier: &Uuid, amount: u32) {
-        if let Some(player) = self.players.get_mut(player_identifier) {
-            player.subtract_chips(amount);
+    /// Simulates a tournament consisting of multiple rounds without betting or folding.
+    ///
+    /// The seats are randomly assigned before the first round if randomize_seats is set.
+    pub fn play_tournament(&mut self) {
+        if self.randomize_seats {
+            self.assign_random_seats();
+        }
+
+        while !self.game_over {
+            self.print_leaderboard();
+            self.simulate_round();
+            self.remove_losers();
+            self.check_for_game_over();
         }
+
+        self.print_statistics();
+        self.print_chip_history();
     }
 
-    pub fn add_chips_to_main_pot(&mut self, amount: u32) {
-        self.main_pot.add_chips(amount);
+    /// Play a game between a human player and CPU players that fill the rest of the table, reading from stdin.
+    pub fn play_interactive_game(&mut self) {
+        self.play_interactive_game_from_input(&mut io::stdin().lock());
     }
 
-    /// Post the blind amount for either the small blind or the big blind.
-    /// Create a side pot if the player could not post the full blind amount.
-    pub fn post_blind(&mut self, is_small_blind: bool) {
-        let seat_index = if is_small_blind {
-            self.get_small_blind_seat_index()
-        } else {
-            self.get_big_blind_seat_index()
+    /// Play a game between a human player and CPU players that fill the rest of the table.
+    ///
+    /// The human player's name and every one of their actions are read from the input, as described in
+    /// betting_round_from_input(). Every player starts with the minimum buy-in. The game continues until
+    /// the human player is out of chips or is the only player remaining, and the leaderboard is printed after each round.
+    pub fn play_interactive_game_from_input(&mut self, input: &mut dyn BufRead) {
+        print!("Enter your name: ");
+        if let Err(error) = io::stdout().flush() {
+            eprintln!("Error: {}", error);
+        }
+        let mut name = String::new();
+        if let Err(error) = input.read_line(&mut name) {
+            eprintln!("Error: {}", error);
+        }
+        let name = match name.trim() {
+            "" => "Player",
+            name => name,
         };
 
-        if let Some(player_identifier) = self.seats.get(seat_index) {
-            if let Some(player) = self.players.get_mut(player_identifier) {
-                let blind_amount = if is_small_blind {
-                    self.small_blind_amount
-                } else {
-                    self.big_blind_amount
-                };
+        let chips = self.minimum_chips_buy_in_amount;
+        let human_player = self.new_player_with_chips(name, chips);
+        let human_identifier = human_player.identifier;
+        if let Err(error) = self.add_player(human_player) {
+            eprintln!("Error: {}", error);
+            return;
+        }
 
-                if player.chips >= blind_amount {
-                    println!(
-                        "{} posted the {} blind with {} chip{}.",
-                        player.name,
-                        if is_small_blind { "small" } else { "big" },
-                        blind_amount,
-                        if blind_amount == 1 { "" } else { "s" }
-                    );
+        let mut cpu_number = 1;
+        while self.seats.len() < self.maximum_players_count {
+            let cpu_player = self.new_player_with_chips(&format!("CPU {}", cpu_number), chips);
+            if let Err(error) =
+                self.add_cpu_player(cpu_player, Box::new(ConservativeStrategy::default()))
+            {
+                eprintln!("Error: {}", error);
+                break;
+            }
+            cpu_number += 1;
+        }
 
-                    player.subtract_chips(blind_amount);
-                    self.main_pot.add_chips(blind_amount);
-                } else if player.chips > 0 {
-                    let partial_blind_amount = player.chips;
-                    player.subtract_chips(partial_blind_amount);
-                    self.main_pot.add_chips(partial_blind_amount);
-
-                    // todo: Should this be cloning the main pot's players?
-                    // What if the small blind didn't have enough chips to cover.
-                    // They probably shouldn't be included if this were triggered again for the big blind.
-                    // Handling side pot creation
-                    let side_pot_players: HashMap<Uuid, Player> = self
-                        .main_pot
-                        .players
-                        .clone()
-                        .iter()
-                        .filter(|(&id, _)| id != *player_identifier)
-                        .map(|(&id, player)| (id, player.clone()))
-                        .collect();
-                    let side_pot_amount = blind_amount - partial_blind_amount;
-                    let side_pot = Pot::new(side_pot_amount, side_pot_players);
-                    self.side_pots.push(side_pot);
+        loop {
+            if !self.players.contains_key(&human_identifier) {
+                println!("You are out of chips. Game over!");
+                break;
+            }
 
-                    println!(
-                        "{} posted {} to cover part of the {} blind. The remaining {} has gone into a side pot.",
-                        player.name,
-                        partial_blind_amount,
-                        if is_small_blind { "small" } else { "big" },
-                        side_pot_amount
-                    );
-                } else {
-                    eprintln!(
-                        "Error: The player has no chips and should not be playing this hand."
-                    );
-                }
-            } else {
-                eprintln!(
-                    "Error: Unable to find player with the id {}",
-                    player_identifier
-                );
+            if self.players.len() == 1 {
+                println!("You won the game!");
+                break;
             }
+
+            self.play_round_with_betting(input);
+            self.remove_losers();
+            self.print_leaderboard();
+        }
+
+        self.end_game();
+    }
+
+    /// Play a heads-up final between the two provided players until one of them has all of their combined chips.
+    ///
+    /// The other players are set aside for the final and return to their seats with their chips afterwards.
+    /// Heads-up blind rules apply, so the dealer posts the small blind and acts first before the flop.
+    ///
+    /// Returns the identifier of the winner, or None if either player is not at the table or they are the same player.
+    pub fn run_heads_up_final(&mut self, player1: Uuid, player2: Uuid) -> Option<Uuid> {
+        if player1 == player2
+            || !self.players.contains_key(&player1)
+            || !self.players.contains_key(&player2)
+        {
+            return None;
+        }
+
+        let original_seats = self.seats.clone();
+        let other_players: Vec<Player> = original_seats
+            .iter()
+            .filter(|player_identifier| {
+                **player_identifier != player1 && **player_identifier != player2
+            })
+            .filter_map(|player_identifier| self.players.remove(player_identifier))
+            .collect();
+        self.seats.retain(|player_identifier| {
+            *player_identifier == player1 || *player_identifier == player2
+        });
+        self.dealer_seat_index %= self.seats.len();
+
+        println!("Heads-up final!");
+        while self.players.values().all(|player| player.chips > 0) {
+            self.simulate_round();
+        }
+
+        let winner = if self.players[&player1].chips > 0 {
+            player1
         } else {
-            eprintln!("Error: Unable to find player at seat {}", seat_index);
+            player2
+        };
+
+        // Return the other players to their original seats with the dealer button where the final ended.
+        let dealer_identifier = self.seats[self.dealer_seat_index];
+        for player in other_players {
+            self.players.insert(player.identifier, player);
         }
+        self.seats = original_seats;
+        self.dealer_seat_index = self
+            .seats
+            .iter()
+            .position(|player_identifier| *player_identifier == dealer_identifier)
+            .unwrap_or(0);
+
+        Some(winner)
     }
 
-    pub fn get_small_blind_amount(&self) -> u32 {
-        self.small_blind_amount
+    /// Play the provided number of independent tournaments and collect the statistics for each one.
+    ///
+    /// The game is reset to the players and chips it started with before each tournament,
+    /// and each tournament is shuffled with a random seed derived from its index.
+    pub fn play_n_tournaments(&mut self, n: usize) -> Vec<GameStatistics> {
+        let starting_players: Vec<Player> = self
+            .seats
+            .iter()
+            .filter_map(|player_identifier| self.players.get(player_identifier))
+            .cloned()
+            .collect();
+
+        let mut all_statistics: Vec<GameStatistics> = Vec::new();
+        for index in 0..n {
+            self.reset_for_new_session(starting_players.clone());
+            self.rng = Some(StdRng::seed_from_u64(index as u64));
+            self.play_tournament();
+            all_statistics.push(self.statistics());
+        }
+        self.rng = None;
+
+        all_statistics
     }
 
-    pub fn get_big_blind_amount(&self) -> u32 {
-        self.big_blind_amount
+    /// Reset the game to its initial state with the provided players seated in order.
+    ///
+    /// The table settings, such as the blind amounts and observers, are kept.
+    /// The CPU strategies of the provided players are also kept, because strategies are stored on the table by
+    /// player identifier rather than on Player, which derives Clone, Eq, and Hash.
+    pub fn reset_for_new_session(&mut self, players: Vec<Player>) {
+        self.game_over = false;
+        self.deck = Deck::new();
+        self.players = HashMap::new();
+        self.seats = Vec::new();
+        self.dealer_seat_index = 0;
+        self.big_blind_identifier = None;
+        self.main_pot = Pot::new(0, HashSet::new());
+        self.side_pots = Vec::new();
+        self.hand_in_progress = false;
+        self.current_betting_round = None;
+        self.pending_removal = HashSet::new();
+        self.pending_joins = Vec::new();
+        self.street = Street::PreFlop;
+        self.player_action_history = Vec::new();
+        self.player_hand_ranks = HashMap::new();
+        self.dealt_hands = HashMap::new();
+        self.hand_history = Vec::new();
+        self.rounds_played = 0;
+        self.round_history = Vec::new();
+        self.player_statistics = HashMap::new();
+        self.eliminated_players = Vec::new();
+        self.burned_cards = Hand::new();
+        self.community_cards = Hand::new();
+        self.cpu_strategies.retain(|player_identifier, _| {
+            players
+                .iter()
+                .any(|player| player.identifier == *player_identifier)
+        });
+
+        for mut player in players {
+            player.reset_for_new_round();
+            self.seats.push(player.identifier);
+            self.players.insert(player.identifier, player);
+        }
     }
 
-    /// Deal hands of two cards to every player starting with the player to the left of the dealer.
-    pub fn deal_hands_to_all_players(&mut self) -> HashMap<Uuid, Hand> {
-        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
-        let mut current_player_seat_index = self.get_small_blind_seat_index();
-
-        // Deal cards to player starting to the left of the dealer
-        while current_player_seat_index != self.dealer_seat_index {
-            if let Some(current_player_identifier) = self.seats.get(current_player_seat_index) {
-                if let Some(current_player) = self.players.get(current_player_identifier).cloned() {
-                    if let Some(hand) = self.deal_hand() {
-                        println!("Hand dealt to {}.", current_player.name);
-                        player_hands.insert(current_player.identifier, hand);
-                    } else {
-                        eprintln!("Error: Unable to deal hand.");
+    pub fn remove_losers(&mut self) {
+        // The bounty for each eliminated player goes to the player who won the hand that eliminated them.
+        let bounty_hunter = self
+            .hand_history
+            .last()
+            .and_then(|hand_result| hand_result.winner_identifiers.first())
+            .copied();
+
+        for (identifier, player) in self.players.clone() {
+            if player.chips == 0 {
+                if let Some(bounty_hunter) = bounty_hunter {
+                    if player.bounty > 0 {
+                        if let Err(error) = self.award_bounty(identifier, bounty_hunter) {
+                            eprintln!("Error: {}", error);
+                        }
                     }
-                } else {
-                    eprintln!(
-                        "Error: Unable to find player with the id {}",
-                        current_player_identifier
-                    )
                 }
-            } else {
-                eprintln!(
-                    "Error: Unable to find player at the seat {}",
-                    current_player_seat_index
-                )
+
+                println!(
+                    "{} is out of chips and was removed from the game.",
+                    player.name
+                );
+                if let Some(eliminated_player) = self.remove_player(&identifier) {
+                    self.eliminated_players.push(eliminated_player);
+                }
             }
+        }
+    }
 
-            // Move to the next player
-            current_player_seat_index = (current_player_seat_index + 1) % self.seats.len();
+    /// Move the bounty on an eliminated player to the player who eliminated them, such as in a knockout tournament.
+    ///
+    /// Returns the amount of chips awarded.
+    pub fn award_bounty(
+        &mut self,
+        eliminated_player: Uuid,
+        bounty_hunter: Uuid,
+    ) -> Result<u32, BountyError> {
+        if eliminated_player == bounty_hunter {
+            return Err(BountyError::SamePlayer);
         }
 
-        // Deal cards to the dealer
-        if let Some(dealer_identifier) = self.seats.get(self.dealer_seat_index) {
-            if let Some(dealer) = self.players.get(dealer_identifier).cloned() {
-                if let Some(hand) = self.deal_hand() {
-                    println!("Hand dealt to {}.", dealer.name);
-                    player_hands.insert(dealer.identifier, hand);
-                } else {
-                    eprintln!("Error: Unable to deal hand.")
-                }
-            } else {
-                eprintln!(
-                    "Error: Unable to find player with the id {}",
-                    dealer_identifier
-                )
-            }
-        } else {
-            eprintln!(
-                "Error: Unable to find player at the seat {}",
-                self.dealer_seat_index
-            )
+        if !self.players.contains_key(&bounty_hunter) {
+            return Err(BountyError::PlayerNotFound);
         }
 
-        println!();
+        let Some(eliminated) = self.players.get_mut(&eliminated_player) else {
+            return Err(BountyError::PlayerNotFound);
+        };
 
-        player_hands
+        if eliminated.chips > 0 {
+            return Err(BountyError::PlayerNotEliminated);
+        }
+
+        let bounty = std::mem::take(&mut eliminated.bounty);
+        let eliminated_name = eliminated.name.clone();
+
+        if let Some(hunter) = self.players.get_mut(&bounty_hunter) {
+            hunter.add_chips(bounty);
+            println!(
+                "{} collected the bounty of {} chip{} for eliminating {}.",
+                hunter.name,
+                bounty,
+                if bounty == 1 { "" } else { "s" },
+                eliminated_name
+            );
+        }
+
+        Ok(bounty)
     }
 
-    /// Get a mutable reference to a Player via their seat index.
-    pub fn get_player_at_seat(&mut self, seat_index: usize) -> Option<&mut Player> {
-        if let Some(player_identifier) = self.seats.get(seat_index) {
-            if let Some(player) = self.players.get_mut(player_identifier) {
-                return Some(player);
-            }
+    pub fn check_for_game_over(&mut self) -> bool {
+        if self.players.is_empty() {
+            println!("No players remaining. Game over!");
+            self.end_game();
         }
 
-        None
+        if self.players.len() == 1 {
+            println!("One player remaining. Game over!");
+            self.end_game();
+        }
+
+        self.game_over
     }
 
-    /// Rotate the current player's seat index.
-    pub fn rotate_current_player(&self, current_player_seat_index: usize) -> usize {
-        (current_player_seat_index + 1) % self.seats.len()
+    /// End the game.
+    pub fn end_game(&mut self) {
+        self.game_over = true;
     }
 
-    /// Deal a hand of two cards.
-    fn deal_hand(&mut self) -> Option<Hand> {
-        let mut hand = Hand::new();
+    /// Print statistics about the players currently seated at the table.
+    /// Players are printed from the highest to lowest amount of chips.
+    pub fn print_leaderboard(&self) {
+        // Step 1: Get the seated players
+        let mut player_stats: Vec<&Player> = self.players_in_seat_order();
 
-        if let Some(card1) = self.deal_card() {
-            hand.push(card1);
-        } else {
-            return None;
-        }
+        // Step 2: Sort players by the number of chips (descending order)
+        player_stats.sort_by_key(|player| std::cmp::Reverse(player.chips));
 
-        if let Some(card2) = self.deal_card() {
-            hand.push(card2);
-        } else {
-            return None;
+        println!("***************");
+        println!("* LEADERBOARD *");
+        println!("***************");
+
+        // Step 3: Print the sorted list
+        for player in player_stats {
+            println!(
+                "{}: {} chip{}",
+                player.name,
+                player.chips,
+                if player.chips == 1 { "" } else { "s" }
+            );
         }
+        println!();
+    }
 
-        Some(hand)
+    /// Simulates a single round with no betting or folding.
+    ///
+    /// If the deck does not have enough cards to deal two to every player and run out the table,
+    /// the round is not played and an empty RoundResult is returned.
+    pub fn simulate_round(&mut self) -> RoundResult {
+        self.shuffle_deck();
+        self.simulate_round_with_current_deck(RoundBetting::None)
     }
 
-    /// Deals a single card.
-    pub fn deal_card(&mut self) -> Option<Card> {
-        // todo: change to deck.deal_face_down for all other players after testing is completed.
-        if let Some(card) = self.deck.deal_face_up() {
-            return Some(card);
+    /// Simulates the provided number of rounds and collects the result of each one.
+    ///
+    /// Stops early if the game is over. Unlike play_tournament(), players without chips stay at the table
+    /// unless set_auto_remove_broke() is enabled.
+    pub fn simulate_n_rounds(&mut self, n: usize) -> Vec<RoundResult> {
+        let mut round_results: Vec<RoundResult> = Vec::new();
+
+        for _ in 0..n {
+            if self.check_for_game_over() {
+                break;
+            }
+
+            round_results.push(self.simulate_round());
+
+            if self.auto_remove_broke {
+                self.remove_losers();
+            }
         }
 
-        None
+        round_results
     }
 
-    /// Rank the provided hands to determine which hands are the best.
-    pub fn rank_all_hands(
-        &self,
-        player_hands: &HashMap<Uuid, Hand>,
-        table_cards: &Hand,
-    ) -> HashMap<Uuid, Vec<HandRank>> {
-        let mut winning_players: HashMap<Uuid, Vec<HandRank>> = HashMap::new();
-        let mut best_hand: Vec<(HandRank, &Hand)> = Vec::new();
+    /// Simulates a single round with no betting or folding using a deck shuffled from the given seed.
+    ///
+    /// The same seed always deals the same cards, so the same players produce the same result.
+    pub fn simulate_round_with_seed(&mut self, seed: u64) -> RoundResult {
+        self.deck = Deck::new_with_seed(seed);
+        self.simulate_round_with_current_deck(RoundBetting::None)
+    }
 
-        for (player_identifier, hand) in player_hands.iter() {
-            if let Some(player) = self.players.get(player_identifier) {
-                let mut cards_to_rank: Vec<Card> = table_cards.get_cards().clone();
-                cards_to_rank.push(hand.cards[0]);
-                cards_to_rank.push(hand.cards[1]);
+    /// Plays a single hand where every player checks on every street and the best hand at showdown wins.
+    ///
+    /// The blinds are still posted, so the other players call the big blind before the flop instead of checking.
+    /// This is useful for testing the payouts independently from the betting decisions.
+    pub fn play_hand_with_all_checks(&mut self) -> RoundResult {
+        self.shuffle_deck();
+        self.simulate_round_with_current_deck(RoundBetting::CheckEveryStreet)
+    }
 
-                let hand_rank = rank_hand(cards_to_rank);
-                // todo: remove after testing
-                println!("{} has {}", player.name, hand_rank);
+    /// Plays a single hand where every player chooses their action on every street from the provided input.
+    ///
+    /// Each line of input is one action, as described in betting_round_from_input().
+    /// Players who fold are not able to act on later streets and are not ranked at showdown.
+    pub fn play_round_with_betting(&mut self, input: &mut dyn BufRead) -> RoundResult {
+        self.shuffle_deck();
+        self.simulate_round_with_current_deck(RoundBetting::FromInput(input))
+    }
 
-                let mut hand_rank_vec = Vec::new();
-                hand_rank_vec.push(hand_rank);
+    /// Run a betting round where every player is prompted for their action on stdin.
+    pub fn betting_round(&mut self, starting_seat_index: usize) {
+        self.betting_round_from_input(starting_seat_index, &mut io::stdin().lock());
+    }
 
-                if best_hand.is_empty() {
-                    best_hand.push((hand_rank, hand));
-                    winning_players.insert(player.identifier, hand_rank_vec);
-                    continue;
-                }
+    /// Run a betting round where every player's action is read from the input, one line per action.
+    ///
+    /// The accepted actions are `fold`, `check`, `call`, `raise <total chips to bet>`, and `all-in`.
+    /// An action that is not allowed is rejected and the player is prompted again.
+    /// The player folds if the input runs out.
+    pub fn betting_round_from_input(
+        &mut self,
+        starting_seat_index: usize,
+        input: &mut dyn BufRead,
+    ) {
+        self.start_betting_round(starting_seat_index);
 
-                if let Some((best_hand_rank, best_hand_cards)) = best_hand.last() {
-                    match hand_rank.cmp(best_hand_rank) {
-                        std::cmp::Ordering::Equal => {
-                            // If hand ranks are equal and are made up of less than 5 cards then check for a kicker (high card).
-                            if hand_rank.len() < 5 {
-                                let mut current_cards_and_table_cards =
-                                    table_cards.get_cards().clone();
-                                current_cards_and_table_cards.push(hand.cards[0]);
-                                current_cards_and_table_cards.push(hand.cards[1]);
-
-                                // Get the kicker for current hand rank
-                                let mut cards_not_used_in_current_hand_rank = Vec::new();
-                                for card in current_cards_and_table_cards {
-                                    // Check to see that the kicker is not part of the current hand rank.
-                                    if !hand_rank.contains(&card) {
-                                        cards_not_used_in_current_hand_rank.push(card);
-                                    }
-                                }
-                                let current_hand_kicker =
-                                    get_high_card_value(&cards_not_used_in_current_hand_rank)
-                                        .unwrap();
-
-                                // Get the kicker for the best hand rank
-                                let mut best_hand_cards_and_table_cards =
-                                    table_cards.get_cards().clone();
-                                best_hand_cards_and_table_cards.push(best_hand_cards.cards[0]);
-                                best_hand_cards_and_table_cards.push(best_hand_cards.cards[1]);
-
-                                let mut cards_not_used_in_best_hand_rank = Vec::new();
-                                for card in best_hand_cards_and_table_cards {
-                                    // Check to see that the kicker is not part of the best hand rank.
-                                    if !best_hand_rank.contains(&card) {
-                                        cards_not_used_in_best_hand_rank.push(card);
-                                    }
-                                }
-                                let best_hand_kicker =
-                                    get_high_card_value(&cards_not_used_in_best_hand_rank).unwrap();
+        while !self.betting_round_complete() {
+            let Some(player_identifier) = self.next_to_act() else {
+                break;
+            };
 
-                                // If there is a tie, but the best hand has a higher kicker, add that kicker to the best hand.
-                                if let Some((leading_player, leading_hand_vec)) =
-                                    winning_players.iter().next()
-                                {
-                                    if leading_hand_vec.len() < 2 {
-                                        winning_players
-                                            .entry(*leading_player)
-                                            .or_default()
-                                            .push(HandRank::HighCard(best_hand_kicker));
-                                    }
-                                }
+            if self.is_cpu_player(&player_identifier) {
+                if self.execute_cpu_action(player_identifier).is_none() {
+                    break;
+                }
+                continue;
+            }
 
-                                // Compare the kickers to determine the best hand.
-                                match current_hand_kicker.rank.cmp(&best_hand_kicker.rank) {
-                                    std::cmp::Ordering::Equal => {
-                                        best_hand.push((hand_rank, hand));
-                                        hand_rank_vec.push(HandRank::HighCard(current_hand_kicker));
-                                        winning_players.insert(player.identifier, hand_rank_vec);
-                                    }
-                                    std::cmp::Ordering::Greater => {
-                                        best_hand.clear();
-                                        best_hand.push((hand_rank, hand));
-                                        winning_players.clear();
-                                        hand_rank_vec.push(HandRank::HighCard(current_hand_kicker));
-                                        winning_players.insert(player.identifier, hand_rank_vec);
-                                    }
-                                    std::cmp::Ordering::Less => {
-                                        // Do nothing, as the best hand remains unchanged.
-                                    }
-                                }
-                            } else {
-                                // If the hand uses too many cards to consider a kicker, push the new hand.
-                                best_hand.push((hand_rank, hand));
-                                winning_players.insert(player.identifier, hand_rank_vec);
-                            }
-                        }
-                        std::cmp::Ordering::Greater => {
-                            best_hand.clear();
-                            best_hand.push((hand_rank, hand));
-                            winning_players.clear();
-                            winning_players.insert(player.identifier, hand_rank_vec);
-                        }
-                        std::cmp::Ordering::Less => {
-                            // Do nothing, as the best hand remains unchanged.
-                        }
+            self.print_action_prompt(player_identifier);
+            let mut line = String::new();
+            let action = match input.read_line(&mut line) {
+                Ok(0) | Err(_) => PlayerAction::Fold(),
+                Ok(_) => match self.parse_player_action(player_identifier, &line) {
+                    Some(action) => action,
+                    None => {
+                        println!("Invalid action. Please enter one of the listed actions.");
+                        continue;
                     }
-                }
-            } else {
-                eprintln!(
-                    "Error: Unable to find player with the id {}",
-                    player_identifier
-                )
+                },
+            };
+
+            if let Err(error) = self.apply_player_action(player_identifier, &action) {
+                println!("{}", error);
             }
         }
 
-        winning_players
+        self.end_betting_round();
     }
 
-    // todo: implement side pot logic
-    /// Determine which player or players won the round and how the pot(s) should be divided.
-    pub fn determine_round_result(&mut self, winning_players: &HashMap<Uuid, Vec<HandRank>>) {
-        match winning_players.len() {
-            1 => {
-                if let Some((player_identifier, winning_hand_rank_vec)) =
-                    winning_players.iter().next()
+    /// Parse an action entered by the player, such as `call` or `raise 20`.
+    ///
+    /// A raise is given as the total chips to bet, including the amount to call, and must be at least
+    /// the amount to call plus the big blind unless it puts the player all in.
+    fn parse_player_action(&self, player_identifier: Uuid, input: &str) -> Option<PlayerAction> {
+        let player = self.players.get(&player_identifier)?;
+        let amount_to_call = self.get_amount_to_call(&player_identifier);
+        let input = input.trim().to_lowercase();
+        let mut words = input.split_whitespace();
+
+        let action = match (words.next()?, words.next()) {
+            ("f" | "fold", None) => PlayerAction::Fold(),
+            ("k" | "check", None) => PlayerAction::Check(),
+            ("c" | "call", None) => PlayerAction::Call(),
+            ("a" | "all-in", None) if player.chips > amount_to_call => {
+                PlayerAction::Raise(player.chips - amount_to_call)
+            }
+            // Going all in for no more than the amount to call is a call for the player's remaining chips.
+            ("a" | "all-in", None) if player.chips > 0 => PlayerAction::Call(),
+            ("r" | "raise", Some(amount)) => {
+                let total_bet: u32 = amount.parse().ok()?;
+                let minimum_raise_amount = amount_to_call + self.big_blind_amount;
+                if total_bet <= amount_to_call
+                    || (total_bet < minimum_raise_amount && total_bet != player.chips)
                 {
-                    if let Some(player) = self.players.get_mut(player_identifier) {
-                        if winning_hand_rank_vec.len() > 1 {
-                            println!(
-                                "\n{} wins with {} and {}",
-                                player.name, winning_hand_rank_vec[0], winning_hand_rank_vec[1]
-                            );
-                        } else {
-                            println!(
-                                "\n{} wins with {}",
-                                player.name,
-                                winning_hand_rank_vec.last().unwrap()
-                            );
-                        }
-
-                        // Allocate winnings from the main pot to the winner.
-                        let main_pot_chips: u32 = self.main_pot.distribute_all_chips();
-                        player.add_chips(main_pot_chips);
-
-                        println!(
-                            "{} wins {} chip{}.",
-                            player.name,
-                            main_pot_chips,
-                            if main_pot_chips == 1 { "" } else { "s" }
-                        );
-                    } else {
-                        eprintln!(
-                            "Error: Unable to get player with the id {}.",
-                            player_identifier
-                        );
-                    }
+                    return None;
                 }
+                PlayerAction::Raise(total_bet - amount_to_call)
             }
-            n if n > 1 => {
-                // Divide the main pot equally for the multiple winners.
-                // In the event of a pot that cannot be split equally, the additional chips are allocated
-                // to each player starting with the first winning player to the left of the dealer.
-                // The winning players are already ordered starting from the left of the dealer,
-                // which helps when allocating uneven winnings.
-
-                let player_count = match u32::try_from(winning_players.len()) {
-                    Ok(number) => number,
-                    Err(error) => {
-                        panic!("Couldn't convert {} to u32: {error}", winning_players.len())
-                    }
-                };
-
-                let main_pot_chips: u32 = self.main_pot.distribute_all_chips();
-                let divided_chips_amount = main_pot_chips / player_count;
-                let remainder_chips_amount = main_pot_chips % player_count;
-                // Create a vector to store the total chips each player will receive.
-                let mut total_chips = vec![divided_chips_amount; winning_players.len()];
+            _ => return None,
+        };
 
-                // Distribute the remainder starting from the first winning player to the left of the dealer.
-                for i in 0..remainder_chips_amount {
-                    total_chips[i as usize] += 1;
-                }
+        if words.next().is_some() {
+            return None;
+        }
 
-                // Create a map to store the position of each player
-                let mut player_positions = HashMap::new();
-                for (index, &seat) in self.seats.iter().enumerate() {
-                    player_positions.insert(seat, index);
-                }
+        Some(action)
+    }
 
-                // Sort the winning players based on their positions relative to the dealer
-                let mut sorted_winning_players: Vec<_> = winning_players.iter().collect();
-                sorted_winning_players.sort_by_key(|(player_id, _)| {
-                    let pos = player_positions.get(player_id).unwrap();
-                    (*pos + self.seats.len() - (self.dealer_seat_index + 1)) % self.seats.len()
-                });
+    /// Run a betting round where every player checks, or calls when checking is not possible.
+    fn check_through_betting_round(&mut self, starting_seat_index: usize) {
+        self.start_betting_round(starting_seat_index);
 
-                // Allocate the calculated total amount of chips to each player and print the result.
-                for (i, (player_identifier, tied_hand_rank)) in
-                    sorted_winning_players.iter().enumerate()
-                {
-                    if let Some(player) = self.players.get_mut(player_identifier) {
-                        if tied_hand_rank.len() > 1 {
-                            println!(
-                                "\n{} pushes with {} and {}",
-                                player.name, tied_hand_rank[0], tied_hand_rank[1]
-                            );
-                        } else {
-                            println!(
-                                "\n{} pushes with {}",
-                                player.name,
-                                tied_hand_rank.last().unwrap()
-                            );
-                        }
+        while !self.betting_round_complete() {
+            let Some(player_identifier) = self.next_to_act() else {
+                break;
+            };
 
-                        // Allocate winnings from the main pot to the winner.
-                        let chips_won = total_chips[i];
-                        player.add_chips(chips_won);
-                        println!(
-                            "{} wins {} chip{}.",
-                            player.name,
-                            chips_won,
-                            if chips_won == 1 { "" } else { "s" }
-                        );
-                    } else {
-                        eprintln!(
-                            "Error: Unable to get player with the id {}.",
-                            player_identifier
-                        );
-                    }
-                }
-            }
-            _ => {
-                panic!("Error: No winning player was determined.");
+            if self.execute_bot_action(player_identifier).is_none() {
+                break;
             }
         }
+
+        self.end_betting_round();
     }
 
-    /// Returns all the cards to the deck.
-    pub fn reset_deck(
+    /// Run the betting for the current street in the way chosen for the round.
+    fn run_betting_for_street(
         &mut self,
-        player_hands: HashMap<Uuid, Hand>,
-        table_cards: Hand,
-        burned_cards: Hand,
+        betting: &mut RoundBetting<'_>,
+        starting_seat_index: usize,
     ) {
-        // Return cards from the players' hands to the deck
-        for (_player, hand) in player_hands.iter() {
-            if let (Some(card1), Some(card2)) = (hand.cards.first(), hand.cards.last()) {
-                self.deck.insert_at_top(*card1).unwrap();
-                self.deck.insert_at_top(*card2).unwrap();
+        match betting {
+            RoundBetting::None => {}
+            RoundBetting::CheckEveryStreet => self.check_through_betting_round(starting_seat_index),
+            RoundBetting::FromInput(input) => {
+                self.betting_round_from_input(starting_seat_index, &mut **input)
             }
         }
+    }
 
-        // Return cards from the table to the deck
-        for card in table_cards.get_cards() {
-            self.deck.insert_at_top(*card).unwrap();
+    fn simulate_round_with_current_deck(&mut self, mut betting: RoundBetting<'_>) -> RoundResult {
+        // Pre-round
+        self.hand_in_progress = true;
+        self.reset_player_round_state();
+        self.add_pending_players();
+
+        if self.seats.is_empty() {
+            eprintln!("Error: There are no players seated to play a round.");
+            self.hand_in_progress = false;
+            return RoundResult::default();
         }
 
-        // Return cards from the burned pile to the deck
-        for card in burned_cards.get_cards() {
-            self.deck.insert_at_top(*card).unwrap();
+        // Two cards for every player, five table cards, and a burned card before the flop, turn, and river.
+        let cards_needed = 2 * self.seats.len() + 8;
+        if self.deck.remaining() < cards_needed {
+            eprintln!(
+                "Error: The deck has {} cards remaining, but {} are needed to deal a round to {} players.",
+                self.deck.remaining(),
+                cards_needed,
+                self.seats.len()
+            );
+            self.hand_in_progress = false;
+            return RoundResult::default();
         }
-    }
+        self.flag_short_stacked_players();
+        if let Err(error) = self.rotate_dealer() {
+            eprintln!("Error: {}", error);
+        }
+        self.add_players_to_main_pot();
+        self.print_dealer();
+        self.auto_post_blinds_for_round();
 
-    /// Resets the main pot and all side pots to be empty.
-    pub fn reset_pots(&mut self) {
-        self.main_pot = Pot::new(0, HashMap::new());
-        self.side_pots = Vec::new();
-    }
-}
+        println!();
 
-impl Default for TexasHoldEm {
-    fn default() -> Self {
-        Self {
-            game_over: false,
-            deck: Deck::new(),
-            players: HashMap::new(),
-            seats: Vec::new(),
-            dealer_seat_index: 0,
-            main_pot: Pot::new(0, HashMap::new()),
-            side_pots: Vec::new(),
-            minimum_chips_buy_in_amount: 100,
-            maximum_players_count: 10,
-            small_blind_amount: 2,
-            big_blind_amount: 5,
+        // Initializing these as Hand because it is a Vec<Card> that can print as symbols if needed
+        let mut table_cards = Hand::new();
+        let player_hands = self.deal_hands_to_all_players();
+        self.run_betting_for_street(
+            &mut betting,
+            self.get_first_to_act_before_the_flop_seat_index(),
+        );
+
+        // Flop
+        for card in self.deal_flop() {
+            table_cards.push(card);
         }
-    }
-}
 
-/// The Pot manages how many chips have been bet and who the winnings should be allocated to.
-#[derive(Clone)]
-struct Pot {
-    amount: u32,
-    players: HashMap<Uuid, Player>,
-}
+        self.notify_community_cards(&table_cards);
+        self.run_betting_for_street(
+            &mut betting,
+            self.get_first_to_act_after_the_flop_seat_index(),
+        );
 
-impl Pot {
-    fn new(amount: u32, players: HashMap<Uuid, Player>) -> Self {
-        Self { amount, players }
-    }
+        // Turn
+        for card in self.deal_turn() {
+            table_cards.push(card);
+        }
 
-    fn add_player(&mut self, identifier: Uuid, player: Player) {
-        self.players.insert(identifier, player);
-    }
+        self.notify_community_cards(&table_cards);
+        self.run_betting_for_street(
+            &mut betting,
+            self.get_first_to_act_after_the_flop_seat_index(),
+        );
 
-    fn add_chips(&mut self, chips: u32) {
-        self.amount += chips;
-    }
+        // River
+        for card in self.deal_river() {
+            table_cards.push(card);
+        }
 
-    fn distribute_all_chips(&mut self) -> u32 {
-        let chips = self.amount;
-        self.amount = 0;
-        chips
+        self.notify_community_cards(&table_cards);
+        self.run_betting_for_street(
+            &mut betting,
+            self.get_first_to_act_after_the_flop_seat_index(),
+        );
+
+        self.print_table_state(&table_cards);
+
+        // Determine winners
+        let winning_players = self.rank_all_hands(&player_hands, &table_cards);
+        let round_result =
+            self.determine_round_result(&player_hands, &table_cards, &winning_players);
+        self.print_round_summary(&round_result);
+        self.record_round_summary(&round_result);
+
+        // Post-round
+        self.remove_pending_players();
+        self.reset_deck(player_hands, table_cards, Hand::new());
+        self.reset_pots();
+        for player in self.players.values_mut() {
+            player.folded = false;
+        }
+        self.hand_in_progress = false;
+        debug_assert!(self.validate_seat_integrity().is_ok());
+
+        round_result
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Count the round as played and add a summary of it to the round history.
+    fn record_round_summary(&mut self, round_result: &RoundResult) {
+        self.rounds_played += 1;
+
+        let mut winner_identifiers: Vec<Uuid> =
+            round_result.winning_players.keys().copied().collect();
+        self.sort_by_position_after_dealer(&mut winner_identifiers);
+        self.round_history.push(RoundSummary {
+            round_number: self.rounds_played,
+            winner_identifiers,
+            pot_amount: round_result.pot_amount,
+            hand_ranks: self.player_hand_ranks.clone(),
+        });
+    }
 
-    use casino_cards::card;
-    use casino_cards::card::{Card, Rank, Suit};
+    /// Notify all observers of the cards currently on the table.
+    fn notify_community_cards(&mut self, table_cards: &Hand) {
+        for observer in self.observers.iter_mut() {
+            observer.on_community_cards(table_cards);
+        }
+    }
 
-    /// Tests rank_all_hands().
+    /// Shuffle the game's deck.
+    /// This is required at the start of every round.
     ///
-    /// Tests that a single winner is correctly chosen.
-    #[test]
-    fn rank_all_hands_identifies_winner() {
-        let mut game = TexasHoldEm::new(100, 10, 1, 3);
+    /// If set_cut_deck() is enabled, the deck is then cut somewhere in the middle half of the deck.
+    pub fn shuffle_deck(&mut self) {
+        match self.rng.as_mut() {
+            Some(rng) => self.deck.shuffle_with_rng(rng),
+            None => self.deck.shuffle(),
+        };
 
-        let two_of_diamonds = card!(Two, Diamond);
-        let two_of_hearts = card!(Two, Heart);
-        let three_of_clubs = card!(Three, Club);
-        let four_of_diamonds = card!(Four, Diamond);
-        let five_of_clubs = card!(Five, Club);
-        let six_of_diamonds = card!(Six, Diamond);
-        let eight_of_spades = card!(Eight, Spade);
-        let nine_of_clubs = card!(Nine, Club);
-        let nine_of_hearts = card!(Nine, Heart);
-        let ten_of_spades = card!(Ten, Spade);
-        let jack_of_clubs = card!(Jack, Club);
-        let queen_of_hearts = card!(Queen, Heart);
-        let king_of_clubs = card!(King, Club);
-        let ace_of_hearts = card!(Ace, Heart);
-        let ace_of_spades = card!(Ace, Spade);
+        if self.cut_deck && !self.deck.is_empty() {
+            let cards_count = self.deck.len();
+            let positions = cards_count / 4..=cards_count * 3 / 4;
+            let position = match self.rng.as_mut() {
+                Some(rng) => rng.gen_range(positions),
+                None => thread_rng().gen_range(positions),
+            };
+            self.deck
+                .cut(position)
+                .expect("The middle half of the deck is always within the deck.");
+        }
+    }
 
-        let flush = HandRank::Flush([
-            three_of_clubs,
-            five_of_clubs,
-            nine_of_clubs,
-            jack_of_clubs,
-            king_of_clubs,
-        ]);
+    /// Rotate the dealer button clockwise to the next player.
+    /// This must happen before the start of the next round.
+    /// This will also update the small blind and big blind players.
+    ///
+    /// The big blind moves forward exactly one player each hand, so once a big blind has been posted, the button is
+    /// placed relative to the player who posted it. This keeps a player from skipping the big blind after a dead
+    /// small blind. If that player has left the table, the button moves forward one seat.
+    ///
+    /// Returns an error if there are no players seated to pass the dealer button to.
+    pub fn rotate_dealer(&mut self) -> Result<(), &'static str> {
+        if self.seats.is_empty() {
+            return Err("There are no players seated to pass the dealer button to.");
+        }
 
-        let table_cards: Vec<Card> = vec![
-            two_of_diamonds,
-            three_of_clubs,
-            eight_of_spades,
-            jack_of_clubs,
-            king_of_clubs,
-        ];
+        let previous_big_blind_seat_index =
+            self.big_blind_identifier.and_then(|big_blind_identifier| {
+                self.seats
+                    .iter()
+                    .position(|player_identifier| *player_identifier == big_blind_identifier)
+            });
+        self.dealer_seat_index = match previous_big_blind_seat_index {
+            // When playing heads-up, the dealer posts the small blind.
+            Some(seat_index) if self.seats.len() == 2 => seat_index,
+            Some(seat_index) => (seat_index + self.seats.len() - 1) % self.seats.len(),
+            None => (self.dealer_seat_index + 1) % self.seats.len(),
+        };
 
-        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
-        let table_cards = Hand::new_from_cards(table_cards);
+        if let Some(&dealer_identifier) = self.seats.get(self.dealer_seat_index) {
+            for observer in self.observers.iter_mut() {
+                observer.on_round_start(dealer_identifier);
+            }
+        }
 
-        let player1 = game.new_player_with_chips("Player 1", 100);
-        game.add_player(player1.clone()).unwrap();
-        let player1_cards: Vec<Card> = vec![five_of_clubs, nine_of_clubs];
-        let player1_hand = Hand::new_from_cards(player1_cards);
-        player_hands.insert(player1.identifier, player1_hand);
+        Ok(())
+    }
 
-        let player2 = game.new_player_with_chips("Player 2", 100);
-        game.add_player(player2.clone()).unwrap();
-        let player2_cards: Vec<Card> = vec![two_of_hearts, ten_of_spades];
-        let player2_hand = Hand::new_from_cards(player2_cards);
-        player_hands.insert(player2.identifier, player2_hand);
+    /// Rotate the dealer button clockwise by the provided number of seats.
+    ///
+    /// This is used to start a game with a specific player as the dealer, such as the winner of the previous tournament.
+    pub fn rotate_dealer_by(&mut self, n: usize) {
+        if self.seats.is_empty() {
+            return;
+        }
 
-        let player3 = game.new_player_with_chips("Player 3", 100);
-        game.add_player(player3.clone()).unwrap();
-        let player3_cards: Vec<Card> = vec![four_of_diamonds, queen_of_hearts];
-        let player3_hand = Hand::new_from_cards(player3_cards);
-        player_hands.insert(player3.identifier, player3_hand);
+        self.dealer_seat_index = (self.dealer_seat_index + n) % self.seats.len();
+    }
 
-        let player4 = game.new_player_with_chips("Player 4", 100);
-        game.add_player(player4.clone()).unwrap();
-        let player4_cards: Vec<Card> = vec![ace_of_hearts, ace_of_spades];
-        let player4_hand = Hand::new_from_cards(player4_cards);
+    /// Print the name of the player that has the dealer button for the round.
+    pub fn print_dealer(&self) {
+        if let Some(dealer_identifier) = self.seats.get(self.dealer_seat_index) {
+            if let Some(dealer) = self.players.get(dealer_identifier) {
+                println!("{} is the dealer.", dealer.name);
+            } else {
+                eprintln!(
+                    "Error: Unable to find the dealer with the id {}",
+                    dealer_identifier
+                );
+            }
+        } else {
+            eprintln!(
+                "Error: Unable to find the dealer at seat {}",
+                self.dealer_seat_index
+            );
+        }
+    }
+
+    /// Add all players at the table to the main betting pot.
+    pub fn add_players_to_main_pot(&mut self) {
+        for identifier in self.players.keys() {
+            self.main_pot.add_eligible_player(*identifier);
+        }
+    }
+
+    /// Get the seat index of the small blind player.
+    /// This must happen before the start of the next round.
+    /// This must happen after rotate_dealer() is executed.
+    /// When playing heads-up, the dealer posts the small blind.
+    pub fn get_small_blind_seat_index(&self) -> usize {
+        if self.seats.len() == 2 {
+            return self.dealer_seat_index % self.seats.len();
+        }
+
+        (self.dealer_seat_index + 1) % self.seats.len()
+    }
+
+    /// Get the seat index of the small blind player.
+    /// This must happen before the start of the next round.
+    /// This must happen after rotate_dealer() is executed.
+    /// When playing heads-up, the player who is not the dealer posts the big blind.
+    pub fn get_big_blind_seat_index(&self) -> usize {
+        if self.seats.len() == 2 {
+            return (self.dealer_seat_index + 1) % self.seats.len();
+        }
+
+        (self.dealer_seat_index + 2) % self.seats.len()
+    }
+
+    /// Get the seat index of the player who acts first before the flop, which is the first player to the left of
+    /// the player who posted the big blind.
+    ///
+    /// This is the player under the gun unless the big blind moved up a seat because the small blind was dead.
+    pub fn get_first_to_act_before_the_flop_seat_index(&self) -> usize {
+        match self.big_blind_identifier.and_then(|big_blind_identifier| {
+            self.seats
+                .iter()
+                .position(|player_identifier| *player_identifier == big_blind_identifier)
+        }) {
+            Some(big_blind_seat_index) => self.rotate_current_player(big_blind_seat_index),
+            None => self.get_under_the_gun_seat_index(),
+        }
+    }
+
+    /// Get the seat index of the player who acts first after the flop, which is the first player to the left of the dealer.
+    pub fn get_first_to_act_after_the_flop_seat_index(&self) -> usize {
+        (self.dealer_seat_index + 1) % self.seats.len()
+    }
+
+    /// Get the identifiers of the seated players in the order cards are dealt.
+    ///
+    /// The order starts with the player to the left of the dealer and wraps around to end with the dealer.
+    pub fn seats_in_deal_order(&self) -> Vec<Uuid> {
+        if self.seats.is_empty() {
+            return Vec::new();
+        }
+
+        let first_seat_index = (self.dealer_seat_index + 1) % self.seats.len();
+        self.seats[first_seat_index..]
+            .iter()
+            .chain(self.seats[..first_seat_index].iter())
+            .copied()
+            .collect()
+    }
+
+    /// Get the seat index of the player to the left of the big blind.
+    /// Aka under the gun.
+    pub fn get_under_the_gun_seat_index(&self) -> usize {
+        self.rotate_current_player(self.get_big_blind_seat_index())
+    }
+
+    /// Returns whether or not the player acts last after the flop among the players still in the hand.
+    ///
+    /// Betting after the flop ends with the dealer, so the dealer is in position unless they have folded.
+    pub fn is_in_position(&self, player_identifier: Uuid) -> bool {
+        if self.seats.is_empty() {
+            return false;
+        }
+
+        for offset in 0..self.seats.len() {
+            let seat_index =
+                (self.dealer_seat_index + self.seats.len() - offset) % self.seats.len();
+            if let Some(seated_player_identifier) = self.seats.get(seat_index) {
+                if let Some(player) = self.players.get(seated_player_identifier) {
+                    if !player.is_folded() {
+                        return *seated_player_identifier == player_identifier;
+                    }
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Get the short name of the player's position at the table relative to the dealer.
+    ///
+    /// The positions are "BTN" (dealer), "SB" (small blind), "BB" (big blind), "UTG" (under the gun),
+    /// "MP" (middle position), and "CO" (cutoff).
+    pub fn position_name(&self, player_identifier: Uuid) -> Option<&'static str> {
+        let seat_index = self
+            .seats
+            .iter()
+            .position(|identifier| *identifier == player_identifier)?;
+
+        let seats_count = self.seats.len();
+        let offset_from_dealer = (seat_index + seats_count - self.dealer_seat_index) % seats_count;
+
+        let position_name = if seat_index == self.dealer_seat_index {
+            "BTN"
+        } else if seat_index == self.get_small_blind_seat_index() {
+            "SB"
+        } else if seat_index == self.get_big_blind_seat_index() {
+            "BB"
+        } else if offset_from_dealer == 3 {
+            "UTG"
+        } else if offset_from_dealer == seats_count - 1 {
+            "CO"
+        } else {
+            "MP"
+        };
+
+        Some(position_name)
+    }
+
+    /// Get a snapshot of the amount of chips each seated player has.
+    pub fn get_all_player_chips(&self) -> HashMap<Uuid, u32> {
+        self.seats
+            .iter()
+            .filter_map(|player_identifier| {
+                self.players
+                    .get(player_identifier)
+                    .map(|player| (*player_identifier, player.chips))
+            })
+            .collect()
+    }
+
+    pub fn subtract_chips_from_player(&mut self, player_identifier: &Uuid, amount: u32) {
+        if let Some(player) = self.players.get_mut(player_identifier) {
+            player.subtract_chips(amount);
+        }
+    }
+
+    pub fn add_chips_to_main_pot(&mut self, amount: u32) -> Result<(), &'static str> {
+        self.main_pot.add_chips(amount)
+    }
+
+    /// Get the amount of chips in the main pot.
+    pub fn get_main_pot_amount(&self) -> u32 {
+        self.main_pot.amount
+    }
+
+    /// Post both the small blind and the big blind and return the amount of chips they added to the main pot.
+    pub fn create_main_pot_from_blinds(&mut self) -> u32 {
+        let pot_before = self.main_pot.amount;
+        self.post_blind(true);
+        self.post_blind(false);
+
+        self.main_pot.amount - pot_before
+    }
+
+    /// Get the ratio of the main pot to the given player's chips.
+    ///
+    /// Returns None if the player is not at the table.
+    /// A player with no chips has a ratio of infinity.
+    pub fn pot_to_stack_ratio(&self, player_identifier: Uuid) -> Option<f64> {
+        let player = self.players.get(&player_identifier)?;
+
+        Some(f64::from(self.main_pot.amount) / f64::from(player.chips))
+    }
+
+    /// Post the blind amount for either the small blind or the big blind.
+    /// Create a side pot if the player could not post the full blind amount.
+    pub fn post_blind(&mut self, is_small_blind: bool) {
+        let seat_index = if is_small_blind {
+            self.get_small_blind_seat_index()
+        } else {
+            self.get_big_blind_seat_index()
+        };
+
+        self.post_blind_from_seat(seat_index, is_small_blind);
+    }
+
+    /// Post the blinds for the round, following the dead small blind rule when the previous big blind has left the table.
+    ///
+    /// In a casino, the button always moves forward one seat and no player may skip the big blind.
+    /// The player who posted the big blind in the previous hand would post the small blind in this hand, so if they
+    /// were eliminated or left the table, the small blind is dead: no small blind is posted for the round, and the
+    /// next player with chips, who would otherwise have posted the small blind, posts the big blind instead.
+    /// Action before the flop then starts to the left of that player.
+    /// When only two players remain, both blinds are posted as usual.
+    /// Posting a blind does not count as the player acting, so they still receive action during the betting round.
+    ///
+    /// Returns the amount of chips the blinds added to the main pot.
+    pub fn auto_post_blinds_for_round(&mut self) -> u32 {
+        if self.seats.is_empty() {
+            return 0;
+        }
+
+        let previous_big_blind_left = self
+            .big_blind_identifier
+            .is_some_and(|big_blind_identifier| !self.players.contains_key(&big_blind_identifier));
+
+        if !previous_big_blind_left || self.seats.len() <= 2 {
+            return self.create_main_pot_from_blinds();
+        }
+
+        println!("The small blind is dead for this round.");
+        let pot_before = self.main_pot.amount;
+        let small_blind_seat_index = self.get_small_blind_seat_index();
+        let next_active_seat_index = (0..self.seats.len())
+            .map(|offset| (small_blind_seat_index + offset) % self.seats.len())
+            .find(|&seat_index| {
+                self.players
+                    .get(&self.seats[seat_index])
+                    .is_some_and(|player| player.chips > 0)
+            });
+
+        match next_active_seat_index {
+            Some(seat_index) => self.post_blind_from_seat(seat_index, false),
+            None => eprintln!("Error: Unable to find a player with chips to post the big blind."),
+        }
+
+        self.main_pot.amount - pot_before
+    }
+
+    /// Post the small blind or the big blind for the player at the provided seat index.
+    fn post_blind_from_seat(&mut self, seat_index: usize, is_small_blind: bool) {
+        if let Some(player_identifier) = self.seats.get(seat_index) {
+            if let Some(player) = self.players.get_mut(player_identifier) {
+                let blind_amount = if is_small_blind {
+                    self.small_blind_amount
+                } else {
+                    self.big_blind_amount
+                };
+
+                if player.chips >= blind_amount {
+                    println!(
+                        "{} posted the {} blind with {} chip{}.",
+                        player.name,
+                        if is_small_blind { "small" } else { "big" },
+                        blind_amount,
+                        if blind_amount == 1 { "" } else { "s" }
+                    );
+
+                    if let Err(error) = self.main_pot.add_chips(blind_amount) {
+                        eprintln!("Error: {}", error);
+                        return;
+                    }
+                    player.subtract_chips(blind_amount);
+                    self.current_betting_round
+                        .get_or_insert_with(BettingRound::default)
+                        .post_blind(*player_identifier, blind_amount);
+                    if !is_small_blind {
+                        self.big_blind_identifier = Some(*player_identifier);
+                    }
+                } else if player.chips > 0 {
+                    // The player is all in for part of the blind.
+                    // The side pots are created from what each player bet once the hand reaches showdown.
+                    let partial_blind_amount = player.chips;
+                    if let Err(error) = self.main_pot.add_chips(partial_blind_amount) {
+                        eprintln!("Error: {}", error);
+                        return;
+                    }
+                    player.subtract_chips(partial_blind_amount);
+                    self.current_betting_round
+                        .get_or_insert_with(BettingRound::default)
+                        .post_blind(*player_identifier, partial_blind_amount);
+                    if !is_small_blind {
+                        self.big_blind_identifier = Some(*player_identifier);
+                    }
+
+                    println!(
+                        "{} is all in after posting {} of the {} blind.",
+                        player.name,
+                        partial_blind_amount,
+                        if is_small_blind { "small" } else { "big" }
+                    );
+                } else {
+                    eprintln!(
+                        "Error: The player has no chips and should not be playing this hand."
+                    );
+                }
+            } else {
+                eprintln!(
+                    "Error: Unable to find player with the id {}",
+                    player_identifier
+                );
+            }
+        } else {
+            eprintln!("Error: Unable to find player at seat {}", seat_index);
+        }
+    }
+
+    pub fn get_small_blind_amount(&self) -> u32 {
+        self.small_blind_amount
+    }
+
+    pub fn get_big_blind_amount(&self) -> u32 {
+        self.big_blind_amount
+    }
+
+    pub fn get_minimum_buy_in(&self) -> u32 {
+        self.minimum_chips_buy_in_amount
+    }
+
+    /// Set the minimum amount of chips a player needs to join the table.
+    ///
+    /// The minimum buy-in is validated against the blinds the same way as in new().
+    pub fn set_minimum_buy_in(
+        &mut self,
+        minimum_chips_buy_in_amount: u32,
+    ) -> Result<(), ConfigError> {
+        validate_config(
+            minimum_chips_buy_in_amount,
+            self.small_blind_amount,
+            self.big_blind_amount,
+        )?;
+
+        self.minimum_chips_buy_in_amount = minimum_chips_buy_in_amount;
+        Ok(())
+    }
+
+    /// Get the small blind amount and the big blind amount.
+    pub fn get_blind_amounts(&self) -> (u32, u32) {
+        (self.small_blind_amount, self.big_blind_amount)
+    }
+
+    /// Set the small blind amount and the big blind amount, such as when the blinds increase during a tournament.
+    ///
+    /// The blinds are validated against the minimum buy-in the same way as in new().
+    pub fn set_blind_amounts(&mut self, small: u32, big: u32) -> Result<(), ConfigError> {
+        validate_config(self.minimum_chips_buy_in_amount, small, big)?;
+
+        self.small_blind_amount = small;
+        self.big_blind_amount = big;
+        Ok(())
+    }
+
+    /// Start a betting round with the player at the provided seat index acting first.
+    ///
+    /// Any bets already made during the street, such as the blinds, are carried into the betting round.
+    pub fn start_betting_round(&mut self, starting_seat_index: usize) {
+        let mut active_players: Vec<Uuid> = Vec::new();
+
+        for offset in 0..self.seats.len() {
+            let seat_index = (starting_seat_index + offset) % self.seats.len();
+            if let Some(player_identifier) = self.seats.get(seat_index) {
+                if let Some(player) = self.players.get(player_identifier) {
+                    if !player.is_folded() && player.chips > 0 {
+                        active_players.push(*player_identifier);
+                    }
+                }
+            }
+        }
+
+        match self.current_betting_round.as_mut() {
+            Some(betting_round) => betting_round.set_active_players(active_players),
+            None => self.current_betting_round = Some(BettingRound::new(active_players)),
+        }
+    }
+
+    /// Clear the bets made during the current street so that the next betting round starts at zero.
+    /// This also advances to the next street.
+    pub fn end_betting_round(&mut self) {
+        self.street = self.street.next();
+        if let Some(betting_round) = self.current_betting_round.as_mut() {
+            betting_round.reset_for_new_street(Vec::new());
+        }
+    }
+
+    /// Returns whether or not a hand is currently being played, as opposed to being between hands.
+    pub fn is_hand_in_progress(&self) -> bool {
+        self.hand_in_progress
+    }
+
+    /// Get the street of the current betting round.
+    pub fn get_street(&self) -> Street {
+        self.street
+    }
+
+    /// Get the number of streets left to bet on after the current one.
+    ///
+    /// Returns 0 on the River and when there is no hand in progress.
+    pub fn number_of_active_streets_remaining(&self) -> usize {
+        if !self.hand_in_progress {
+            return 0;
+        }
+
+        match self.street {
+            Street::PreFlop => 3,
+            Street::Flop => 2,
+            Street::Turn => 1,
+            Street::River => 0,
+        }
+    }
+
+    /// Get every action taken during the current hand in the order they were taken.
+    pub fn player_action_history(&self) -> &[ActionRecord] {
+        &self.player_action_history
+    }
+
+    /// Get the identifier of the player whose turn it is in the current betting round.
+    pub fn next_to_act(&self) -> Option<Uuid> {
+        self.current_betting_round
+            .as_ref()
+            .and_then(|betting_round| betting_round.next_to_act())
+    }
+
+    /// Returns whether or not every player has acted on the current bet.
+    ///
+    /// The betting round is also complete once only one player has not folded.
+    pub fn betting_round_complete(&self) -> bool {
+        let remaining_players_count = self
+            .seats
+            .iter()
+            .filter_map(|player_identifier| self.players.get(player_identifier))
+            .filter(|player| !player.is_folded())
+            .count();
+
+        let betting_round_complete = self
+            .current_betting_round
+            .as_ref()
+            .is_none_or(|betting_round| betting_round.is_complete());
+
+        betting_round_complete || remaining_players_count <= 1
+    }
+
+    /// Returns whether or not a bet has been placed during the current street.
+    ///
+    /// Players are able to check until betting is open, after which they must call instead.
+    pub fn is_betting_open(&self) -> bool {
+        self.current_betting_round
+            .as_ref()
+            .is_some_and(|betting_round| betting_round.is_betting_open())
+    }
+
+    /// Disallow any further raises during the current street.
+    pub fn close_betting(&mut self) {
+        if let Some(betting_round) = self.current_betting_round.as_mut() {
+            betting_round.close_betting();
+        }
+    }
+
+    /// Returns whether or not raises are still allowed during the current street.
+    fn is_raising_allowed(&self) -> bool {
+        self.current_betting_round
+            .as_ref()
+            .is_none_or(|betting_round| betting_round.is_raising_allowed())
+    }
+
+    /// Get the highest total bet that players must match during the current street.
+    pub fn get_current_bet(&self) -> u32 {
+        self.current_betting_round
+            .as_ref()
+            .map_or(0, |betting_round| betting_round.current_bet())
+    }
+
+    /// Print the player's chips, the pot, the amount to call, and the actions they are able to take.
+    ///
+    /// Example: `Player 1 [150 chips] | Pot: 20 | To call: 5 | Actions: fold, call (5), raise (min 10), all-in (150)`
+    pub fn print_action_prompt(&self, player_identifier: Uuid) {
+        match self.format_action_prompt(player_identifier) {
+            Some(action_prompt) => println!("{}", action_prompt),
+            None => eprintln!(
+                "Error: Unable to find player with the id {}",
+                player_identifier
+            ),
+        }
+    }
+
+    fn format_action_prompt(&self, player_identifier: Uuid) -> Option<String> {
+        let player = self.players.get(&player_identifier)?;
+        let amount_to_call = self.get_amount_to_call(&player_identifier);
+        let minimum_raise_amount = amount_to_call + self.big_blind_amount;
+
+        let mut actions: Vec<String> = vec![String::from("fold")];
+        if amount_to_call == 0 {
+            actions.push(String::from("check"));
+        } else if player.chips > amount_to_call {
+            actions.push(format!("call ({})", amount_to_call));
+        }
+        if self.is_raising_allowed() && player.chips > minimum_raise_amount {
+            actions.push(format!("raise (min {})", minimum_raise_amount));
+        }
+        if player.chips > 0 {
+            actions.push(format!("all-in ({})", player.chips));
+        }
+
+        Some(format!(
+            "{} [{} chip{}] | Pot: {} | To call: {} | Actions: {}",
+            player.name,
+            player.chips,
+            if player.chips == 1 { "" } else { "s" },
+            self.main_pot.amount,
+            amount_to_call,
+            actions.join(", ")
+        ))
+    }
+
+    /// Print a reference table of every hand ranking from the strongest to the weakest.
+    ///
+    /// Each row has the rank number, the hand name, an example hand, and a brief description.
+    pub fn display_hand_rankings_table() {
+        println!("{}", Self::format_hand_rankings_table());
+    }
+
+    fn format_hand_rankings_table() -> String {
+        let hand_rankings = [
+            (
+                HandRank::StraightFlush([
+                    card!(Ten, Spade),
+                    card!(Jack, Spade),
+                    card!(Queen, Spade),
+                    card!(King, Spade),
+                    card!(Ace, Spade),
+                ]),
+                "Five cards in sequence of the same suit.",
+            ),
+            (
+                HandRank::FourOfAKind([
+                    card!(Nine, Club),
+                    card!(Nine, Diamond),
+                    card!(Nine, Heart),
+                    card!(Nine, Spade),
+                ]),
+                "Four cards of the same rank.",
+            ),
+            (
+                HandRank::FullHouse([
+                    card!(King, Club),
+                    card!(King, Diamond),
+                    card!(King, Heart),
+                    card!(Seven, Club),
+                    card!(Seven, Spade),
+                ]),
+                "Three of a kind combined with a pair.",
+            ),
+            (
+                HandRank::Flush([
+                    card!(Two, Heart),
+                    card!(Six, Heart),
+                    card!(Nine, Heart),
+                    card!(Jack, Heart),
+                    card!(King, Heart),
+                ]),
+                "Five cards of the same suit, not in sequence.",
+            ),
+            (
+                HandRank::Straight([
+                    card!(Five, Club),
+                    card!(Six, Diamond),
+                    card!(Seven, Heart),
+                    card!(Eight, Spade),
+                    card!(Nine, Club),
+                ]),
+                "Five cards in sequence, not of the same suit.",
+            ),
+            (
+                HandRank::ThreeOfAKind([
+                    card!(Queen, Club),
+                    card!(Queen, Diamond),
+                    card!(Queen, Spade),
+                ]),
+                "Three cards of the same rank.",
+            ),
+            (
+                HandRank::TwoPair([
+                    card!(Jack, Club),
+                    card!(Jack, Heart),
+                    card!(Four, Diamond),
+                    card!(Four, Spade),
+                ]),
+                "Two different pairs.",
+            ),
+            (
+                HandRank::Pair([card!(Ten, Diamond), card!(Ten, Heart)]),
+                "Two cards of the same rank.",
+            ),
+            (
+                HandRank::HighCard(card!(Ace, Diamond)),
+                "The highest card when no other hand is made.",
+            ),
+        ];
+
+        let mut lines = vec![format!(
+            "{:<4} {:<13} {:<14} {}",
+            "#", "Hand", "Example", "Description"
+        )];
+        for (index, (hand_rank, description)) in hand_rankings.iter().enumerate() {
+            let example = Hand::new_from_cards(hand_rank.dominant_cards().to_vec()).to_symbols();
+            lines.push(format!(
+                "{:<4} {:<13} {:<14} {}",
+                index + 1,
+                hand_rank.variant_name(),
+                example,
+                description
+            ));
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    /// Print the current street, the face up table cards, the main pot, and every seated player's chips and status.
+    pub fn print_table_state(&self, table_cards: &Hand) {
+        println!("{}", self.format_table_state(table_cards));
+    }
+
+    fn format_table_state(&self, table_cards: &Hand) -> String {
+        let face_up_cards = Hand::new_from_cards(
+            table_cards
+                .cards
+                .iter()
+                .filter(|card| card.face_up)
+                .copied()
+                .collect(),
+        );
+
+        let mut lines = vec![
+            format!("Street: {}", self.street),
+            format!("Table cards: {}", face_up_cards.to_symbols()),
+            format!("Pot: {}", self.main_pot.amount),
+        ];
+
+        for player_identifier in self.seats.iter() {
+            if let Some(player) = self.players.get(player_identifier) {
+                let status = if player.is_folded() {
+                    "folded"
+                } else if player.is_all_in() {
+                    "all-in"
+                } else {
+                    "active"
+                };
+
+                lines.push(format!(
+                    "{} [{} chip{}] {}",
+                    player.name,
+                    player.chips,
+                    if player.chips == 1 { "" } else { "s" },
+                    status
+                ));
+            }
+        }
+
+        lines.join("\n") + "\n"
+    }
+
+    /// Get the amount of chips the player needs to add to match the current bet.
+    pub fn get_amount_to_call(&self, player_identifier: &Uuid) -> u32 {
+        self.current_betting_round
+            .as_ref()
+            .map_or(0, |betting_round| {
+                betting_round.get_amount_to_call(player_identifier)
+            })
+    }
+
+    /// Apply an action for the player whose turn it is in the current betting round.
+    pub fn apply_player_action(
+        &mut self,
+        player_identifier: Uuid,
+        action: &PlayerAction,
+    ) -> Result<(), &'static str> {
+        if self.next_to_act() != Some(player_identifier) {
+            return Err("It is not the player's turn to act.");
+        }
+
+        let amount_to_call = self.get_amount_to_call(&player_identifier);
+        let pot_before = self.main_pot.amount;
+
+        let Some(player) = self.players.get_mut(&player_identifier) else {
+            return Err("The player is not at the table.");
+        };
+        let Some(betting_round) = self.current_betting_round.as_mut() else {
+            return Err("There is no betting round in progress.");
+        };
+
+        match action {
+            PlayerAction::Call() => {
+                // A player without enough chips to call the full amount calls all in for their remaining chips.
+                let call_amount = amount_to_call.min(player.chips);
+
+                self.main_pot.add_chips(call_amount)?;
+                println!("{} calls with {} chips.", player.name, call_amount);
+                player.subtract_chips(call_amount);
+                betting_round.place_bet(player_identifier, call_amount);
+                if player.chips == 0 {
+                    println!("{} is all in.", player.name);
+                    player.all_in = true;
+                    betting_round.remove_player(&player_identifier);
+                }
+            }
+            PlayerAction::Check() => {
+                if amount_to_call > 0 {
+                    return Err("The player is unable to check while there is a bet to call.");
+                }
+
+                println!("{} checks.", player.name);
+                betting_round.place_bet(player_identifier, 0);
+            }
+            PlayerAction::Fold() => {
+                betting_round.remove_player(&player_identifier);
+                if let Err(error) = self.fold_player(player_identifier) {
+                    eprintln!("Error: {}", error);
+                }
+            }
+            PlayerAction::Raise(amount) => {
+                if !betting_round.is_raising_allowed() {
+                    return Err("Betting is closed and no further raises are allowed.");
+                }
+
+                match self.betting_mode {
+                    BettingMode::NoLimit => {}
+                    BettingMode::Limit => {
+                        let bet_size = match self.street {
+                            Street::PreFlop | Street::Flop => self.big_blind_amount,
+                            Street::Turn | Street::River => self.big_blind_amount * 2,
+                        };
+                        if *amount != bet_size {
+                            return Err(
+                                "In limit, a raise must be exactly the bet size for the street.",
+                            );
+                        }
+                    }
+                    BettingMode::PotLimit => {
+                        if *amount > self.main_pot.amount + amount_to_call {
+                            return Err("In pot-limit, a raise is unable to be more than the size of the pot.");
+                        }
+                    }
+                }
+
+                let total_bet = amount_to_call + amount;
+                if player.chips < total_bet {
+                    return Err("The player does not have enough chips to raise.");
+                }
+
+                self.main_pot.add_chips(total_bet)?;
+                println!("{} raises by {} chips.", player.name, amount);
+                player.subtract_chips(total_bet);
+                if player.chips == 0 {
+                    println!("{} is all in.", player.name);
+                    player.all_in = true;
+                }
+                // Every other player still in the hand must act again on the raise.
+                betting_round.place_bet(player_identifier, total_bet);
+                if player.chips == 0 {
+                    betting_round.remove_player(&player_identifier);
+                }
+            }
+        }
+
+        for observer in self.observers.iter_mut() {
+            observer.on_player_action(player_identifier, action);
+        }
+        self.player_action_history.push(ActionRecord {
+            player_identifier,
+            street: self.street,
+            action: action.clone(),
+            pot_before,
+            pot_after: self.main_pot.amount,
+        });
+
+        Ok(())
+    }
+
+    /// Select and apply an action on behalf of a computer-controlled player.
+    ///
+    /// The bot checks when possible, calls when it can afford to, and folds otherwise.
+    pub fn execute_bot_action(&mut self, player_identifier: Uuid) -> Option<PlayerAction> {
+        let player = self.players.get(&player_identifier)?;
+        let amount_to_call = self.get_amount_to_call(&player_identifier);
+
+        let action = if amount_to_call == 0 {
+            PlayerAction::Check()
+        } else if player.chips >= amount_to_call {
+            PlayerAction::Call()
+        } else {
+            PlayerAction::Fold()
+        };
+
+        match self.apply_player_action(player_identifier, &action) {
+            Ok(()) => Some(action),
+            Err(error) => {
+                eprintln!("Error: {}", error);
+                None
+            }
+        }
+    }
+
+    /// Select and apply an action on behalf of a CPU player using their strategy.
+    ///
+    /// The strategy is given the player's hand, the face up table cards, and the best hand the player can make.
+    /// If the chosen action is unable to be applied, the CPU player checks when possible and folds otherwise.
+    pub fn execute_cpu_action(&mut self, player_identifier: Uuid) -> Option<PlayerAction> {
+        let chips = self.players.get(&player_identifier)?.chips;
+        let amount_to_call = self.get_amount_to_call(&player_identifier);
+        let hand = self
+            .dealt_hands
+            .get(&player_identifier)
+            .cloned()
+            .unwrap_or_default();
+        let hand_rank = if hand.cards.len() >= 2 {
+            let mut cards = hand.cards.clone();
+            cards.extend(self.community_cards.cards.iter().copied());
+            rank_hand(cards)
+        } else {
+            HandRank::HighCard(*hand.cards.first()?)
+        };
+
+        let strategy = self.cpu_strategies.get_mut(&player_identifier)?;
+        let action = strategy.choose_action(
+            &hand,
+            &self.community_cards,
+            amount_to_call,
+            chips,
+            &hand_rank,
+        );
+
+        match self.apply_player_action(player_identifier, &action) {
+            Ok(()) => Some(action),
+            Err(error) => {
+                eprintln!("Error: {}", error);
+                let fallback_action = if amount_to_call == 0 {
+                    PlayerAction::Check()
+                } else {
+                    PlayerAction::Fold()
+                };
+                self.apply_player_action(player_identifier, &fallback_action)
+                    .ok()
+                    .map(|()| fallback_action)
+            }
+        }
+    }
+
+    /// Run the current betting round, acting automatically for the provided bots.
+    ///
+    /// The betting round pauses when it is a human player's turn so that their action can be applied
+    /// with apply_player_action() before this is called again.
+    ///
+    /// Returns the actions taken in order.
+    pub fn run_betting_round_with_bots(
+        &mut self,
+        bot_identifiers: &HashSet<Uuid>,
+    ) -> Vec<(Uuid, PlayerAction)> {
+        let mut actions: Vec<(Uuid, PlayerAction)> = Vec::new();
+
+        while !self.betting_round_complete() {
+            let Some(player_identifier) = self.next_to_act() else {
+                break;
+            };
+
+            if !bot_identifiers.contains(&player_identifier) {
+                if let Some(player) = self.players.get(&player_identifier) {
+                    println!("It's {}'s turn.", player.name);
+                    println!(
+                        "The amount to call is {} chips.",
+                        self.get_amount_to_call(&player_identifier)
+                    );
+                }
+                break;
+            }
+
+            match self.execute_bot_action(player_identifier) {
+                Some(action) => actions.push((player_identifier, action)),
+                None => break,
+            }
+        }
+
+        actions
+    }
+
+    /// Deal hands of two cards to every player starting with the player to the left of the dealer.
+    ///
+    /// One card is dealt to each player in order, and then a second card is dealt to each player in the same order.
+    pub fn deal_hands_to_all_players(&mut self) -> HashMap<Uuid, Hand> {
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+
+        self.player_action_history.clear();
+        self.player_hand_ranks.clear();
+        self.dealt_hands.clear();
+        self.community_cards = Hand::new();
+        self.street = Street::PreFlop;
+        self.hand_in_progress = true;
+
+        // Dealing starts with the player to the left of the dealer.
+        let mut dealing_order: Vec<(Uuid, Hand)> = self
+            .seats_in_deal_order()
+            .iter()
+            .filter_map(|player_identifier| self.players.get(player_identifier))
+            // Players that have folded before the hand, such as those without chips, are not dealt in.
+            .filter(|player| !player.is_folded())
+            .map(|player| (player.identifier, Hand::new()))
+            .collect();
+
+        for _ in 0..2 {
+            for (_, hand) in dealing_order.iter_mut() {
+                if let Some(card) = self.deal_card() {
+                    hand.push(card);
+                }
+            }
+        }
+
+        for (player_identifier, hand) in dealing_order {
+            if hand.cards.len() != 2 {
+                eprintln!("Error: Unable to deal hand.");
+                continue;
+            }
+
+            if let Some(player) = self.players.get(&player_identifier) {
+                if self.verbose {
+                    let mut revealed_hand = hand.clone();
+                    revealed_hand.reveal();
+                    println!(
+                        "Hand dealt to {}: {}",
+                        player.name,
+                        revealed_hand.to_symbols()
+                    );
+                } else {
+                    println!("Hand dealt to {}.", player.name);
+                }
+            }
+            for observer in self.observers.iter_mut() {
+                observer.on_cards_dealt(player_identifier, &hand);
+            }
+            self.dealt_hands.insert(player_identifier, hand.clone());
+            player_hands.insert(player_identifier, hand);
+        }
+
+        println!();
+
+        player_hands
+    }
+
+    /// Get the seated players in the order of their seats.
+    pub fn players_in_seat_order(&self) -> Vec<&Player> {
+        self.seats
+            .iter()
+            .filter_map(|player_identifier| self.players.get(player_identifier))
+            .collect()
+    }
+
+    /// Get mutable references to the seated players in the order of their seats.
+    pub fn players_in_seat_order_mut(&mut self) -> Vec<&mut Player> {
+        let mut players: HashMap<Uuid, &mut Player> = self
+            .players
+            .iter_mut()
+            .map(|(player_identifier, player)| (*player_identifier, player))
+            .collect();
+
+        self.seats
+            .iter()
+            .filter_map(|player_identifier| players.remove(player_identifier))
+            .collect()
+    }
+
+    /// Find a player at the table or among the eliminated players.
+    fn find_player(&self, player_identifier: &Uuid) -> Option<&Player> {
+        self.players.get(player_identifier).or_else(|| {
+            self.eliminated_players
+                .iter()
+                .find(|player| player.identifier == *player_identifier)
+        })
+    }
+
+    /// Get the name of a player at the table or of an eliminated player.
+    ///
+    /// Falls back to the player's identifier if the player is unknown.
+    #[cfg(feature = "export")]
+    fn player_name(&self, player_identifier: &Uuid) -> String {
+        self.find_player(player_identifier)
+            .map(|player| player.name.clone())
+            .unwrap_or_else(|| player_identifier.to_string())
+    }
+
+    /// Get a mutable reference to a Player via their seat index.
+    pub fn get_player_at_seat(&mut self, seat_index: usize) -> Option<&mut Player> {
+        if let Some(player_identifier) = self.seats.get(seat_index) {
+            if let Some(player) = self.players.get_mut(player_identifier) {
+                return Some(player);
+            }
+        }
+
+        None
+    }
+
+    /// Rotate the current player's seat index.
+    pub fn rotate_current_player(&self, current_player_seat_index: usize) -> usize {
+        (current_player_seat_index + 1) % self.seats.len()
+    }
+
+    /// Deals a single card face down.
+    /// This is used for the players' hands.
+    pub fn deal_card(&mut self) -> Option<Card> {
+        if let Some(card) = self.deck.deal_face_down() {
+            return Some(card);
+        }
+
+        None
+    }
+
+    /// Deals a single card face down into the burned pile.
+    /// This is done before each time the table cards are dealt.
+    pub fn burn_card(&mut self) -> Option<Card> {
+        let card = self.deck.deal_face_down()?;
+        self.burned_cards.push(card);
+        Some(card)
+    }
+
+    /// Burns one card and then deals the provided number of community cards face up.
+    ///
+    /// Returns no cards if fewer than one card is requested or the deck does not have enough cards.
+    pub fn deal_n_community_cards(&mut self, n: usize) -> Vec<Card> {
+        if n < 1 {
+            eprintln!("Error: At least one community card must be dealt.");
+            return Vec::new();
+        }
+
+        if self.deck.len() < n + 1 {
+            eprintln!(
+                "Error: The deck does not have enough cards to burn one and deal {}.",
+                n
+            );
+            return Vec::new();
+        }
+
+        self.burn_card();
+
+        let mut community_cards: Vec<Card> = Vec::new();
+        for _ in 0..n {
+            if let Some(card) = self.deal_card_face_up() {
+                community_cards.push(card);
+                self.community_cards.push(card);
+            }
+        }
+
+        community_cards
+    }
+
+    /// Burns one card and then deals the three cards of the flop.
+    pub fn deal_flop(&mut self) -> Vec<Card> {
+        self.deal_n_community_cards(3)
+    }
+
+    /// Burns one card and then deals the turn card.
+    pub fn deal_turn(&mut self) -> Vec<Card> {
+        self.deal_n_community_cards(1)
+    }
+
+    /// Burns one card and then deals the river card.
+    pub fn deal_river(&mut self) -> Vec<Card> {
+        self.deal_n_community_cards(1)
+    }
+
+    /// Deals a single card face up.
+    /// This is used for the table cards.
+    pub fn deal_card_face_up(&mut self) -> Option<Card> {
+        if let Some(card) = self.deck.deal_face_up() {
+            return Some(card);
+        }
+
+        None
+    }
+
+    /// Rank the provided hands to determine which hands are the best.
+    ///
+    /// The rank of every hand that has not folded is recorded and available from get_player_rank_in_hand().
+    /// The recorded ranks replace any that were recorded before, so a player who has since folded has no rank.
+    pub fn rank_all_hands(
+        &mut self,
+        player_hands: &HashMap<Uuid, Hand>,
+        table_cards: &Hand,
+    ) -> HashMap<Uuid, Vec<HandRank>> {
+        let (winning_players, player_hand_ranks) = self.rank_hands(player_hands, table_cards);
+        self.player_hand_ranks = player_hand_ranks;
+
+        winning_players
+    }
+
+    /// Evaluate a showdown between the provided hands on a complete board.
+    ///
+    /// The winners and their hand ranks are returned without awarding any chips or recording the ranks,
+    /// so the pot amount is zero and no chips are won. Folded players and unknown players are not ranked.
+    pub fn run_showdown_only(
+        &self,
+        player_hands: HashMap<Uuid, Hand>,
+        board: [Card; 5],
+    ) -> RoundResult {
+        let table_cards = Hand::new_from_cards(board.to_vec());
+        let (winning_players, _) = self.rank_hands(&player_hands, &table_cards);
+
+        RoundResult {
+            player_hands,
+            table_cards,
+            winning_players,
+            pot_amount: 0,
+            chips_won: HashMap::new(),
+        }
+    }
+
+    /// Determine the winning hands along with the rank of every hand that has not folded.
+    fn rank_hands(
+        &self,
+        player_hands: &HashMap<Uuid, Hand>,
+        table_cards: &Hand,
+    ) -> (HashMap<Uuid, Vec<HandRank>>, HashMap<Uuid, HandRank>) {
+        let mut winning_players: HashMap<Uuid, Vec<HandRank>> = HashMap::new();
+        let mut player_hand_ranks: HashMap<Uuid, HandRank> = HashMap::new();
+        let mut best_hand: Vec<(HandRank, Vec<Card>)> = Vec::new();
+
+        for (player_identifier, hand) in player_hands.iter() {
+            if let Some(player) = self.players.get(player_identifier) {
+                if player.is_folded() {
+                    continue;
+                }
+
+                // Once five cards are revealed, the hand is ranked by its best five cards, which also hold its kickers.
+                let revealed_cards: Vec<Card> = reveal_cards(hand, table_cards);
+                let cards_to_rank: Vec<Card> = match best_five_cards(&revealed_cards) {
+                    Some(best_five) => best_five.to_vec(),
+                    None => revealed_cards,
+                };
+
+                let hand_rank = rank_hand(cards_to_rank.clone());
+                if self.verbose {
+                    println!("{} has {}", player.name, hand_rank);
+                }
+                player_hand_ranks.insert(player.identifier, hand_rank);
+
+                let mut hand_rank_vec = Vec::new();
+                hand_rank_vec.push(hand_rank);
+
+                if best_hand.is_empty() {
+                    best_hand.push((hand_rank, cards_to_rank));
+                    winning_players.insert(player.identifier, hand_rank_vec);
+                    continue;
+                }
+
+                if let Some((best_hand_rank, best_hand_cards)) = best_hand.last() {
+                    match hand_rank.cmp(best_hand_rank) {
+                        std::cmp::Ordering::Equal => {
+                            // If hand ranks are equal and are made up of less than 5 cards then check for a kicker (high card).
+                            if hand_rank.len() < 5 {
+                                let kicker_ordering =
+                                    compare_kickers(&hand_rank, &cards_to_rank, best_hand_cards);
+
+                                // The kickers are found the same way as in compare_kickers() so the recorded kicker
+                                // always matches the one that decided the hand.
+                                let current_hand_kickers =
+                                    kicker_cards_by_rank(&hand_rank, &cards_to_rank);
+                                let best_hand_kickers =
+                                    kicker_cards_by_rank(best_hand_rank, best_hand_cards);
+
+                                // The deciding kicker is the first one that differs between the hands and is recorded with the hand rank.
+                                let kicker_index = current_hand_kickers
+                                    .iter()
+                                    .zip(best_hand_kickers.iter())
+                                    .position(|(current_kicker, best_kicker)| {
+                                        current_kicker.rank != best_kicker.rank
+                                    })
+                                    .unwrap_or(0);
+                                // There is no kicker to record when the hands do not have enough cards, such as before the flop.
+                                let current_hand_kicker =
+                                    current_hand_kickers.get(kicker_index).copied();
+                                let best_hand_kicker = best_hand_kickers.get(kicker_index).copied();
+
+                                // If there is a tie, but the best hand has a higher kicker, add that kicker to the best hand.
+                                if let (
+                                    Some((leading_player, leading_hand_vec)),
+                                    Some(best_hand_kicker),
+                                ) = (winning_players.iter().next(), best_hand_kicker)
+                                {
+                                    if leading_hand_vec.len() < 2 {
+                                        winning_players
+                                            .entry(*leading_player)
+                                            .or_default()
+                                            .push(HandRank::HighCard(best_hand_kicker));
+                                    }
+                                }
+
+                                // Compare the kickers to determine the best hand.
+                                match kicker_ordering {
+                                    std::cmp::Ordering::Equal => {
+                                        best_hand.push((hand_rank, cards_to_rank));
+                                        hand_rank_vec
+                                            .extend(current_hand_kicker.map(HandRank::HighCard));
+                                        winning_players.insert(player.identifier, hand_rank_vec);
+                                    }
+                                    std::cmp::Ordering::Greater => {
+                                        best_hand.clear();
+                                        best_hand.push((hand_rank, cards_to_rank));
+                                        winning_players.clear();
+                                        hand_rank_vec
+                                            .extend(current_hand_kicker.map(HandRank::HighCard));
+                                        winning_players.insert(player.identifier, hand_rank_vec);
+                                    }
+                                    std::cmp::Ordering::Less => {
+                                        // Do nothing, as the best hand remains unchanged.
+                                    }
+                                }
+                            } else {
+                                // If the hand uses too many cards to consider a kicker, push the new hand.
+                                best_hand.push((hand_rank, cards_to_rank));
+                                winning_players.insert(player.identifier, hand_rank_vec);
+                            }
+                        }
+                        std::cmp::Ordering::Greater => {
+                            best_hand.clear();
+                            best_hand.push((hand_rank, cards_to_rank));
+                            winning_players.clear();
+                            winning_players.insert(player.identifier, hand_rank_vec);
+                        }
+                        std::cmp::Ordering::Less => {
+                            // Do nothing, as the best hand remains unchanged.
+                        }
+                    }
+                }
+            } else {
+                eprintln!(
+                    "Error: Unable to find player with the id {}",
+                    player_identifier
+                )
+            }
+        }
+
+        (winning_players, player_hand_ranks)
+    }
+
+    /// Rank every player's hand from best to worst.
+    ///
+    /// Each inner Vec is a group of players whose hands are tied, including their kickers.
+    /// Players within a group are ordered starting from the left of the dealer.
+    /// Folded players are not ranked.
+    pub fn rank_all_players(
+        &self,
+        player_hands: &HashMap<Uuid, Hand>,
+        table_cards: &Hand,
+    ) -> Vec<Vec<Uuid>> {
+        let mut ranked_players: Vec<(Uuid, HandRank, Vec<Rank>)> = Vec::new();
+
+        for (player_identifier, hand) in player_hands.iter() {
+            let Some(player) = self.players.get(player_identifier) else {
+                eprintln!(
+                    "Error: Unable to find player with the id {}",
+                    player_identifier
+                );
+                continue;
+            };
+
+            if player.is_folded() {
+                continue;
+            }
+
+            // Rank by the best five cards, as rank_hands() does, so the order matches the winners of the round.
+            let revealed_cards: Vec<Card> = reveal_cards(hand, table_cards);
+            let cards_to_rank: Vec<Card> = match best_five_cards(&revealed_cards) {
+                Some(best_five) => best_five.to_vec(),
+                None => revealed_cards,
+            };
+
+            let hand_rank = rank_hand(cards_to_rank.clone());
+
+            // Only the cards that fill out the best five cards can be used as kickers.
+            let mut kicker_ranks: Vec<Rank> = hand_rank
+                .kicker_cards_from(&cards_to_rank)
+                .iter()
+                .map(|card| card.rank)
+                .collect();
+            kicker_ranks.truncate(5_usize.saturating_sub(hand_rank.len()));
+
+            ranked_players.push((*player_identifier, hand_rank, kicker_ranks));
+        }
+
+        let seats_count = self.seats.len().max(1);
+        let distance_from_dealer = |player_identifier: &Uuid| {
+            let seat_index = self
+                .seats
+                .iter()
+                .position(|identifier| identifier == player_identifier)
+                .unwrap_or(0);
+            (seat_index + seats_count - (self.dealer_seat_index + 1)) % seats_count
+        };
+
+        ranked_players.sort_by(
+            |(identifier1, hand_rank1, kickers1), (identifier2, hand_rank2, kickers2)| {
+                hand_rank2
+                    .cmp(hand_rank1)
+                    .then_with(|| kickers2.cmp(kickers1))
+                    .then_with(|| {
+                        distance_from_dealer(identifier1).cmp(&distance_from_dealer(identifier2))
+                    })
+            },
+        );
+
+        let mut ranked_groups: Vec<Vec<Uuid>> = Vec::new();
+        let mut previous: Option<(HandRank, Vec<Rank>)> = None;
+
+        for (player_identifier, hand_rank, kicker_ranks) in ranked_players {
+            let is_tied =
+                previous
+                    .as_ref()
+                    .is_some_and(|(previous_hand_rank, previous_kicker_ranks)| {
+                        hand_rank.cmp(previous_hand_rank) == std::cmp::Ordering::Equal
+                            && kicker_ranks == *previous_kicker_ranks
+                    });
+
+            if is_tied {
+                if let Some(group) = ranked_groups.last_mut() {
+                    group.push(player_identifier);
+                }
+            } else {
+                ranked_groups.push(vec![player_identifier]);
+            }
+
+            previous = Some((hand_rank, kicker_ranks));
+        }
+
+        ranked_groups
+    }
+
+    /// Determine which player or players won the round and how the pot(s) should be divided.
+    ///
+    /// When a player is all in for less than the other players bet, the pot is first split into a main pot
+    /// and side pots with create_side_pots(). The main pot is awarded to the winning players and each
+    /// side pot is awarded with distribute_side_pots().
+    ///
+    /// The winning players are sorted by seat starting from the left of the dealer, so any chips left over
+    /// from splitting the main pot go to the winners closest to the left of the dealer.
+    ///
+    /// The chips are awarded to the winners without printing anything. Use print_round_summary() to display the result.
+    pub fn determine_round_result(
+        &mut self,
+        player_hands: &HashMap<Uuid, Hand>,
+        table_cards: &Hand,
+        winning_players: &HashMap<Uuid, Vec<HandRank>>,
+    ) -> RoundResult {
+        for observer in self.observers.iter_mut() {
+            observer.on_round_end(winning_players);
+        }
+
+        let pot_amount = self.main_pot.amount;
+        self.create_side_pots();
+        let mut winner_identifiers: Vec<Uuid> = winning_players.keys().copied().collect();
+        self.sort_by_position_after_dealer(&mut winner_identifiers);
+        let winning_hand_rank = winner_identifiers
+            .first()
+            .and_then(|player_identifier| winning_players.get(player_identifier))
+            .and_then(|hand_ranks| hand_ranks.first())
+            .copied();
+        let mut chips_won: HashMap<Uuid, u32> = HashMap::new();
+        match winner_identifiers.len() {
+            1 => {
+                // Allocate winnings from the main pot to the winner.
+                let player_identifier = winner_identifiers[0];
+                match self.award_main_pot_to(player_identifier) {
+                    Ok(amount) => {
+                        chips_won.insert(player_identifier, amount);
+                    }
+                    Err(error) => eprintln!("Error: {}", error),
+                }
+            }
+            n if n > 1 => {
+                // Divide the main pot equally for the multiple winners starting from the left of the dealer.
+                match self.main_pot.distribute_all_chips() {
+                    Ok(main_pot_chips) => {
+                        match self.award_pot_split_evenly(&winner_identifiers, main_pot_chips) {
+                            Ok(amounts) => {
+                                chips_won.extend(winner_identifiers.iter().copied().zip(amounts));
+                            }
+                            Err(error) => {
+                                eprintln!("Error: {}", error);
+                                self.main_pot.amount = main_pot_chips;
+                            }
+                        }
+                    }
+                    Err(error) => eprintln!("Error: {}", error),
+                }
+            }
+            _ => {
+                panic!("Error: No winning player was determined.");
+            }
+        }
+        for (player_identifier, amount) in self.distribute_side_pots(player_hands, table_cards) {
+            *chips_won.entry(player_identifier).or_default() += amount;
+        }
+
+        let profit_loss: HashMap<Uuid, i64> = self
+            .players
+            .keys()
+            .filter_map(|player_identifier| {
+                self.player_profit_loss_for_round(*player_identifier)
+                    .map(|amount| (*player_identifier, amount))
+            })
+            .collect();
+        self.record_player_statistics(player_hands, &winner_identifiers);
+        self.record_chip_count_history();
+        self.hand_history.push(HandResult {
+            pot_amount,
+            winner_identifiers,
+            winning_hand_rank,
+            profit_loss,
+            actions: self.player_action_history.clone(),
+            starting_stacks: self
+                .players_in_seat_order()
+                .into_iter()
+                .map(|player| (player.identifier, player.chips_at_start_of_round))
+                .collect(),
+        });
+
+        RoundResult {
+            player_hands: player_hands.clone(),
+            table_cards: table_cards.clone(),
+            winning_players: winning_players.clone(),
+            pot_amount,
+            chips_won,
+        }
+    }
+
+    /// Split the chips in the main pot into a main pot and side pots based on how much each player bet during the hand.
+    ///
+    /// A new pot starts at the amount each player who has not folded went all in for, from the smallest to the largest.
+    /// Only the players who bet at least that amount are eligible to win the pot, so a player who is all in
+    /// is only eligible for the pots they were able to match. The side pots are created in order from the
+    /// smallest to the largest. Nothing changes when no player is all in for less than the others bet.
+    pub fn create_side_pots(&mut self) {
+        let contributions: Vec<(Uuid, u32, bool)> = self
+            .players
+            .values()
+            .map(|player| {
+                (
+                    player.identifier,
+                    player.chips_at_start_of_round.saturating_sub(player.chips),
+                    player.is_folded(),
+                )
+            })
+            .filter(|(_, contribution, _)| *contribution > 0)
+            .collect();
+        let total_contributed: u32 = contributions
+            .iter()
+            .map(|(_, contribution, _)| contribution)
+            .sum();
+        if total_contributed > self.main_pot.amount {
+            return;
+        }
+
+        let mut levels: Vec<u32> = contributions
+            .iter()
+            .filter(|(player_identifier, _, is_folded)| {
+                !is_folded
+                    && self
+                        .players
+                        .get(player_identifier)
+                        .is_some_and(|player| player.chips == 0)
+            })
+            .map(|(_, contribution, _)| *contribution)
+            .collect();
+        if let Some(highest_contribution) = contributions
+            .iter()
+            .map(|(_, contribution, _)| *contribution)
+            .max()
+        {
+            levels.push(highest_contribution);
+        }
+        levels.sort();
+        levels.dedup();
+        if levels.len() < 2 {
+            return;
+        }
+
+        let mut pots: Vec<Pot> = Vec::new();
+        let mut previous_level = 0;
+        // Chips that no remaining player is eligible for are added to the previous pot.
+        let mut unclaimed_chips = self.main_pot.amount - total_contributed;
+        for level in levels {
+            let amount: u32 = contributions
+                .iter()
+                .map(|(_, contribution, _)| {
+                    (*contribution).min(level) - (*contribution).min(previous_level)
+                })
+                .sum();
+            let eligible_players: HashSet<Uuid> = contributions
+                .iter()
+                .filter(|(_, contribution, is_folded)| !is_folded && *contribution >= level)
+                .map(|(player_identifier, _, _)| *player_identifier)
+                .collect();
+
+            if eligible_players.is_empty() {
+                match pots.last_mut() {
+                    Some(pot) => {
+                        if let Err(error) = pot.add_chips(amount) {
+                            eprintln!("Error: {}", error);
+                        }
+                    }
+                    None => unclaimed_chips += amount,
+                }
+            } else {
+                pots.push(Pot::new(amount + unclaimed_chips, eligible_players));
+                unclaimed_chips = 0;
+            }
+            previous_level = level;
+        }
+
+        let mut pots = pots.into_iter();
+        if let Some(main_pot) = pots.next() {
+            self.main_pot = main_pot;
+            self.side_pots.extend(pots);
+        }
+    }
+
+    /// Award each side pot to the best hands among the players eligible for it, starting with the first side pot created.
+    ///
+    /// Returns the chips each player won from the side pots.
+    pub fn distribute_side_pots(
+        &mut self,
+        player_hands: &HashMap<Uuid, Hand>,
+        table_cards: &Hand,
+    ) -> HashMap<Uuid, u32> {
+        let mut chips_won: HashMap<Uuid, u32> = HashMap::new();
+
+        for mut side_pot in std::mem::take(&mut self.side_pots) {
+            let eligible_hands: HashMap<Uuid, Hand> = player_hands
+                .iter()
+                .filter(|(player_identifier, _)| {
+                    side_pot.eligible_players.contains(player_identifier)
+                })
+                .map(|(player_identifier, hand)| (*player_identifier, hand.clone()))
+                .collect();
+            let (winning_players, _) = self.rank_hands(&eligible_hands, table_cards);
+            let winner_identifiers: Vec<Uuid> = winning_players.keys().copied().collect();
+
+            let side_pot_chips = match side_pot.distribute_all_chips() {
+                Ok(chips) => chips,
+                Err(error) => {
+                    eprintln!("Error: {}", error);
+                    continue;
+                }
+            };
+            match self.award_pot_split_evenly(&winner_identifiers, side_pot_chips) {
+                Ok(amounts) => {
+                    for (player_identifier, amount) in winner_identifiers.into_iter().zip(amounts) {
+                        *chips_won.entry(player_identifier).or_default() += amount;
+                    }
+                }
+                Err(error) => {
+                    eprintln!("Error: {}", error);
+                    side_pot.amount = side_pot_chips;
+                    self.side_pots.push(side_pot);
+                }
+            }
+        }
+
+        chips_won
+    }
+
+    /// Record the outcome of the hand for every player who was dealt in and the chips of every player at the table.
+    ///
+    /// A hand is a win for a sole winner, a push for every player who split the pot, and a loss for everyone else.
+    fn record_player_statistics(
+        &mut self,
+        player_hands: &HashMap<Uuid, Hand>,
+        winner_identifiers: &[Uuid],
+    ) {
+        for player in self.players.values() {
+            let statistics = self
+                .player_statistics
+                .entry(player.identifier)
+                .or_insert_with(|| Statistics {
+                    name: player.name.clone(),
+                    ..Statistics::default()
+                });
+
+            if player_hands.contains_key(&player.identifier) {
+                statistics.hands_played += 1;
+                if !winner_identifiers.contains(&player.identifier) {
+                    statistics.losses += 1;
+                } else if winner_identifiers.len() == 1 {
+                    statistics.wins += 1;
+                } else {
+                    statistics.pushes += 1;
+                }
+            }
+            statistics.chip_history.push(player.chips);
+        }
+    }
+
+    /// Add every player's chips to their chip count history if any player's chips changed during the round.
+    fn record_chip_count_history(&mut self) {
+        let chips_changed = self
+            .players
+            .values()
+            .any(|player| player.chips != player.chips_at_start_of_round);
+        if !chips_changed {
+            return;
+        }
+
+        for player in self.players.values_mut() {
+            player.chip_count_history.push(player.chips);
+        }
+    }
+
+    /// Print a sparkline of each player's chip count history, including the players who were eliminated.
+    ///
+    /// Every sparkline is scaled to the same range so the players can be compared with each other.
+    pub fn print_chip_history(&self) {
+        print!("{}", self.format_chip_history());
+    }
+
+    fn format_chip_history(&self) -> String {
+        const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+        // The players still seated are listed first, followed by the eliminated players from the last one out.
+        let players: Vec<&Player> = self
+            .players_in_seat_order()
+            .into_iter()
+            .chain(self.eliminated_players.iter().rev())
+            .filter(|player| !player.chip_count_history.is_empty())
+            .collect();
+
+        let all_chips = players
+            .iter()
+            .flat_map(|player| player.chip_count_history.iter().copied());
+        let minimum_chips = all_chips.clone().min().unwrap_or(0);
+        let maximum_chips = all_chips.max().unwrap_or(0);
+        let name_width = players
+            .iter()
+            .map(|player| player.name.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut chip_history = String::new();
+        chip_history.push_str("****************\n");
+        chip_history.push_str("* CHIP HISTORY *\n");
+        chip_history.push_str("****************\n");
+
+        for player in players {
+            let sparkline: String = player
+                .chip_count_history
+                .iter()
+                .map(|&chips| {
+                    let level = if maximum_chips == minimum_chips {
+                        BLOCKS.len() - 1
+                    } else {
+                        ((chips - minimum_chips) as usize * (BLOCKS.len() - 1))
+                            / (maximum_chips - minimum_chips) as usize
+                    };
+                    BLOCKS[level]
+                })
+                .collect();
+
+            chip_history.push_str(&format!(
+                "{:<name_width$} {} {}\n",
+                player.name, sparkline, player.chips
+            ));
+        }
+
+        chip_history
+    }
+
+    /// Get the results of every hand the player has played, including after they have left the table.
+    pub fn get_player_statistics(&self, player_identifier: Uuid) -> Option<&Statistics> {
+        self.player_statistics.get(&player_identifier)
+    }
+
+    /// Print the wins, losses, pushes, and chips of every player who has played a hand.
+    /// Players are printed from the most to the fewest wins.
+    pub fn print_statistics(&self) {
+        let mut player_statistics: Vec<&Statistics> = self.player_statistics.values().collect();
+        player_statistics.sort_by(|statistics1, statistics2| {
+            statistics2
+                .wins
+                .cmp(&statistics1.wins)
+                .then_with(|| statistics1.name.cmp(&statistics2.name))
+        });
+
+        println!("**************");
+        println!("* STATISTICS *");
+        println!("**************");
+
+        for statistics in player_statistics {
+            let chips = statistics.chip_history.last().copied().unwrap_or(0);
+            println!(
+                "{}: {} win{}, {} loss{}, {} push{} in {} hand{} | {} chip{}",
+                statistics.name,
+                statistics.wins,
+                if statistics.wins == 1 { "" } else { "s" },
+                statistics.losses,
+                if statistics.losses == 1 { "" } else { "es" },
+                statistics.pushes,
+                if statistics.pushes == 1 { "" } else { "es" },
+                statistics.hands_played,
+                if statistics.hands_played == 1 {
+                    ""
+                } else {
+                    "s"
+                },
+                chips,
+                if chips == 1 { "" } else { "s" }
+            );
+        }
+        println!();
+    }
+
+    /// Print the winners of the round, the hands they won with, and the chips they won.
+    pub fn print_round_summary(&self, result: &RoundResult) {
+        let mut winner_identifiers: Vec<Uuid> = result.winning_players.keys().copied().collect();
+        self.sort_by_position_after_dealer(&mut winner_identifiers);
+        let verb = if winner_identifiers.len() > 1 {
+            "pushes"
+        } else {
+            "wins"
+        };
+
+        for player_identifier in winner_identifiers.iter() {
+            let (Some(player), Some(hand_ranks)) = (
+                self.players.get(player_identifier),
+                result.winning_players.get(player_identifier),
+            ) else {
+                eprintln!(
+                    "Error: Unable to find player with the id {}",
+                    player_identifier
+                );
+                continue;
+            };
+
+            if hand_ranks.len() > 1 {
+                println!(
+                    "\n{} {} with {} and {}",
+                    player.name, verb, hand_ranks[0], hand_ranks[1]
+                );
+            } else if let Some(hand_rank) = hand_ranks.last() {
+                println!("\n{} {} with {}", player.name, verb, hand_rank);
+            }
+
+            // The hole cards stay face down until they are shown at showdown.
+            if let Some(hand) = result.player_hands.get(player_identifier) {
+                let mut revealed_hand = hand.clone();
+                revealed_hand.reveal();
+                println!("{} shows {}", player.name, revealed_hand.to_symbols());
+
+                let all_cards = Hand::new_from_cards(reveal_cards(hand, &result.table_cards));
+                if let Some(best_five) = all_cards.best_five() {
+                    println!(
+                        "{}'s best five cards are {}",
+                        player.name,
+                        Hand::new_from_cards(best_five.to_vec()).to_symbols()
+                    );
+                }
+            }
+
+            let chips_won = result
+                .chips_won
+                .get(player_identifier)
+                .copied()
+                .unwrap_or(0);
+            println!(
+                "{} wins {} chip{}.",
+                player.name,
+                chips_won,
+                if chips_won == 1 { "" } else { "s" }
+            );
+        }
+    }
+
+    /// Divide the pot amount equally between the provided players and add the chips to each player.
+    ///
+    /// In the event of a pot that cannot be split equally, the additional chips are allocated one at
+    /// a time to each player starting with the first player to the left of the dealer.
+    ///
+    /// Returns the amount of chips each player received in the same order as the provided players.
+    pub fn award_pot_split_evenly(
+        &mut self,
+        player_identifiers: &[Uuid],
+        pot_amount: u32,
+    ) -> Result<Vec<u32>, AwardError> {
+        if player_identifiers.is_empty() {
+            return Err(AwardError::NoPlayers);
+        }
+        if player_identifiers
+            .iter()
+            .any(|player_identifier| !self.players.contains_key(player_identifier))
+        {
+            return Err(AwardError::PlayerNotFound);
+        }
+
+        let player_count = match u32::try_from(player_identifiers.len()) {
+            Ok(number) => number,
+            Err(error) => {
+                panic!(
+                    "Couldn't convert {} to u32: {error}",
+                    player_identifiers.len()
+                )
+            }
+        };
+        let divided_chips_amount = pot_amount / player_count;
+        let remainder_chips_amount = pot_amount % player_count;
+
+        // Distribute the remainder starting from the first player to the left of the dealer.
+        let mut sorted_player_identifiers = player_identifiers.to_vec();
+        self.sort_by_position_after_dealer(&mut sorted_player_identifiers);
+        let remainder_player_identifiers: HashSet<Uuid> = sorted_player_identifiers
+            .into_iter()
+            .take(remainder_chips_amount as usize)
+            .collect();
+
+        let mut total_chips: Vec<u32> = Vec::new();
+        for player_identifier in player_identifiers {
+            let mut chips_won = divided_chips_amount;
+            if remainder_player_identifiers.contains(player_identifier) {
+                chips_won += 1;
+            }
+
+            if let Some(player) = self.players.get_mut(player_identifier) {
+                player.add_chips(chips_won);
+            }
+            total_chips.push(chips_won);
+        }
+
+        Ok(total_chips)
+    }
+
+    /// Sort the players by their seat starting with the first seat to the left of the dealer.
+    fn sort_by_position_after_dealer(&self, player_identifiers: &mut [Uuid]) {
+        let deal_order = self.seats_in_deal_order();
+        player_identifiers.sort_by_key(|player_identifier| {
+            deal_order
+                .iter()
+                .position(|identifier| identifier == player_identifier)
+                .unwrap_or(0)
+        });
+    }
+
+    /// Move all chips in the main pot to the provided player.
+    ///
+    /// Returns the amount of chips awarded.
+    pub fn award_main_pot_to(&mut self, player_identifier: Uuid) -> Result<u32, AwardError> {
+        let Some(player) = self.players.get_mut(&player_identifier) else {
+            return Err(AwardError::PlayerNotFound);
+        };
+
+        let main_pot_chips: u32 = self
+            .main_pot
+            .distribute_all_chips()
+            .map_err(|_| AwardError::EmptyPot)?;
+        player.add_chips(main_pot_chips);
+
+        Ok(main_pot_chips)
+    }
+
+    /// Returns all the cards to the deck.
+    pub fn reset_deck(
+        &mut self,
+        player_hands: HashMap<Uuid, Hand>,
+        table_cards: Hand,
+        burned_cards: Hand,
+    ) {
+        // Return cards from the players' hands to the deck
+        for (_player, hand) in player_hands.iter() {
+            if let (Some(card1), Some(card2)) = (hand.cards.first(), hand.cards.last()) {
+                self.deck.insert_at_top(*card1).unwrap();
+                self.deck.insert_at_top(*card2).unwrap();
+            }
+        }
+
+        // Return cards from the table to the deck
+        for card in table_cards.get_cards() {
+            self.deck.insert_at_top(*card).unwrap();
+        }
+
+        // Return cards from the burned pile to the deck
+        for card in burned_cards.get_cards() {
+            self.deck.insert_at_top(*card).unwrap();
+        }
+
+        for card in self.burned_cards.get_cards() {
+            self.deck.insert_at_top(*card).unwrap();
+        }
+        self.burned_cards = Hand::new();
+
+        self.hand_in_progress = false;
+    }
+
+    /// Export the hand history as text modeled on Portable Bridge Notation.
+    ///
+    /// Every completed hand is written as its own block, and the blocks are separated by a blank line.
+    /// A block starts with tag pairs for the game, date, blinds, hand number, and the players in seat order with
+    /// the chips they had at the start of the hand, followed by one line for every action taken in the hand.
+    #[cfg(feature = "export")]
+    pub fn export_to_pbn_format(&self) -> String {
+        let date = chrono::Utc::now().format("%Y.%m.%d");
+        let mut blocks: Vec<String> = Vec::new();
+
+        for (hand_index, hand_result) in self.hand_history.iter().enumerate() {
+            let mut block = String::new();
+            block.push_str(&format!(
+                "[Game \"Texas hold 'em {}\"]\n",
+                self.betting_mode
+            ));
+            block.push_str(&format!("[Date \"{date}\"]\n"));
+            block.push_str(&format!(
+                "[Blinds \"{}/{}\"]\n",
+                self.small_blind_amount, self.big_blind_amount
+            ));
+            block.push_str(&format!("[Hand \"{}\"]\n", hand_index + 1));
+
+            for (seat_index, (player_identifier, starting_chips)) in
+                hand_result.starting_stacks.iter().enumerate()
+            {
+                block.push_str(&format!(
+                    "[Seat{} \"{}\" \"{}\"]\n",
+                    seat_index + 1,
+                    self.player_name(player_identifier),
+                    starting_chips
+                ));
+            }
+
+            block.push('\n');
+
+            for record in &hand_result.actions {
+                let name = self.player_name(&record.player_identifier);
+
+                let action_line = match record.action {
+                    PlayerAction::Call() => format!("{name} calls"),
+                    PlayerAction::Check() => format!("{name} checks"),
+                    PlayerAction::Fold() => format!("{name} folds"),
+                    PlayerAction::Raise(amount) => format!("{name} raises {amount}"),
+                };
+
+                block.push_str(&action_line);
+                block.push('\n');
+            }
+
+            blocks.push(block);
+        }
+
+        blocks.join("\n")
+    }
+
+    /// Save the table settings, the dealer, and the players in seat order to a JSON file.
+    ///
+    /// The state is meant to be saved between hands. Observers, CPU strategies, and the hand in progress are not saved.
+    ///
+    /// Requires the "serde" feature.
+    #[cfg(feature = "serde")]
+    pub fn save_state(&self, path: &std::path::Path) -> Result<(), io::Error> {
+        let state = GameState {
+            minimum_chips_buy_in_amount: self.minimum_chips_buy_in_amount,
+            maximum_players_count: self.maximum_players_count,
+            small_blind_amount: self.small_blind_amount,
+            big_blind_amount: self.big_blind_amount,
+            dealer_seat_index: self.dealer_seat_index,
+            game_over: self.game_over,
+            betting_mode: self.betting_mode,
+            players: self.players_in_seat_order().into_iter().cloned().collect(),
+        };
+
+        let json = serde_json::to_string_pretty(&state)?;
+        std::fs::write(path, json)
+    }
+
+    /// Load a game from a JSON file written by save_state().
+    ///
+    /// Requires the "serde" feature.
+    #[cfg(feature = "serde")]
+    pub fn load_state(path: &std::path::Path) -> Result<Self, io::Error> {
+        let json = std::fs::read_to_string(path)?;
+        let state: GameState = serde_json::from_str(&json)?;
+
+        let mut texas_hold_em = Self::new(
+            state.minimum_chips_buy_in_amount,
+            state.maximum_players_count,
+            state.small_blind_amount,
+            state.big_blind_amount,
+        )
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error.to_string()))?;
+        texas_hold_em.game_over = state.game_over;
+        texas_hold_em.betting_mode = state.betting_mode;
+        for player in state.players {
+            texas_hold_em.seats.push(player.identifier);
+            texas_hold_em.players.insert(player.identifier, player);
+        }
+        if texas_hold_em.validate_seat_integrity().is_err() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "The saved players do not each have exactly one seat.",
+            ));
+        }
+        if !texas_hold_em.seats.is_empty() {
+            texas_hold_em.dealer_seat_index = state.dealer_seat_index % texas_hold_em.seats.len();
+        }
+
+        Ok(texas_hold_em)
+    }
+
+    /// Get the amount of chips the player has gained or lost since the start of the current round.
+    ///
+    /// Returns None if the player is not at the table.
+    pub fn player_profit_loss_for_round(&self, player_identifier: Uuid) -> Option<i64> {
+        let player = self.players.get(&player_identifier)?;
+
+        Some(i64::from(player.chips) - i64::from(player.chips_at_start_of_round))
+    }
+
+    /// Get the hand most recently dealt to the player in text notation, such as "Kh Qh".
+    ///
+    /// Returns None if the player is not at the table or has not been dealt a hand.
+    pub fn format_hand_as_string(&self, player_identifier: Uuid) -> Option<String> {
+        let hand = self.dealt_hands.get(&player_identifier)?;
+
+        Some(
+            hand.cards
+                .iter()
+                .map(|card| card.to_notation())
+                .collect::<Vec<String>>()
+                .join(" "),
+        )
+    }
+
+    /// Get the result of every hand played in the game in the order they were played.
+    pub fn hand_history(&self) -> &[HandResult] {
+        &self.hand_history
+    }
+
+    /// Get the number of rounds that have been played to a showdown in the current session.
+    pub fn rounds_played(&self) -> u32 {
+        self.rounds_played
+    }
+
+    /// Get a summary of every round played in the current session in the order they were played.
+    pub fn round_history(&self) -> &[RoundSummary] {
+        &self.round_history
+    }
+
+    /// Get the rank of the hand the player held at the showdown of the current hand.
+    ///
+    /// Returns None if the hands have not been ranked yet or the player folded before the showdown.
+    pub fn get_player_rank_in_hand(&self, player_identifier: Uuid) -> Option<HandRank> {
+        self.player_hand_ranks.get(&player_identifier).copied()
+    }
+
+    /// Count how many hands in the hand history were won with the same kind of hand as the provided rank.
+    ///
+    /// Only the variant is compared, so any Flush counts towards a Flush regardless of its cards.
+    pub fn count_hands_won_by_rank(&self, rank: &HandRank) -> usize {
+        self.hand_history
+            .iter()
+            .filter_map(|hand_result| hand_result.winning_hand_rank)
+            .filter(|winning_hand_rank| {
+                std::mem::discriminant(winning_hand_rank) == std::mem::discriminant(rank)
+            })
+            .count()
+    }
+
+    /// Get the kind of hand that has won the most hands in the hand history.
+    ///
+    /// The first winning hand of that kind is returned. Ties are broken in favor of the stronger kind of hand.
+    pub fn most_common_winning_rank(&self) -> Option<HandRank> {
+        let mut winning_ranks: Vec<HandRank> = Vec::new();
+        for hand_result in self.hand_history.iter() {
+            if let Some(winning_hand_rank) = hand_result.winning_hand_rank {
+                if !winning_ranks.iter().any(|hand_rank| {
+                    std::mem::discriminant(hand_rank) == std::mem::discriminant(&winning_hand_rank)
+                }) {
+                    winning_ranks.push(winning_hand_rank);
+                }
+            }
+        }
+
+        winning_ranks.into_iter().max_by_key(|hand_rank| {
+            (
+                self.count_hands_won_by_rank(hand_rank),
+                hand_rank.rank_class(),
+            )
+        })
+    }
+
+    /// Find pairs of players who consistently fold when the other raises, which may indicate collusion.
+    ///
+    /// For every pair of players, the correlation between one player raising and the other folding is calculated over
+    /// the hands in the hand history where both players acted. A pair is returned when the correlation is above the
+    /// threshold in both directions. This is not proof of cheating, only a signal that the pair may be worth reviewing.
+    pub fn detect_collusion(&self, threshold: f64) -> Vec<(Uuid, Uuid)> {
+        // The players who acted in each hand along with whether or not they raised or folded.
+        let hands: Vec<HashMap<Uuid, (bool, bool)>> = self
+            .hand_history
+            .iter()
+            .map(|hand_result| {
+                let mut actions: HashMap<Uuid, (bool, bool)> = HashMap::new();
+                for record in hand_result.actions.iter() {
+                    let (raised, folded) = actions.entry(record.player_identifier).or_default();
+                    match record.action {
+                        PlayerAction::Raise(_) => *raised = true,
+                        PlayerAction::Fold() => *folded = true,
+                        _ => {}
+                    }
+                }
+                actions
+            })
+            .collect();
+
+        let mut player_identifiers: Vec<Uuid> = hands
+            .iter()
+            .flat_map(|actions| actions.keys().copied())
+            .collect::<HashSet<Uuid>>()
+            .into_iter()
+            .collect();
+        player_identifiers.sort();
+
+        // The correlation between the raiser raising and the folder folding across the hands they both played.
+        let raise_fold_correlation = |raiser: &Uuid, folder: &Uuid| -> f64 {
+            let mut counts = [[0.0_f64; 2]; 2];
+            for actions in hands.iter() {
+                if let (Some((raised, _)), Some((_, folded))) =
+                    (actions.get(raiser), actions.get(folder))
+                {
+                    counts[usize::from(*raised)][usize::from(*folded)] += 1.0;
+                }
+            }
+
+            let denominator = ((counts[1][0] + counts[1][1])
+                * (counts[0][0] + counts[0][1])
+                * (counts[0][1] + counts[1][1])
+                * (counts[0][0] + counts[1][0]))
+                .sqrt();
+            if denominator == 0.0 {
+                return 0.0;
+            }
+
+            (counts[1][1] * counts[0][0] - counts[1][0] * counts[0][1]) / denominator
+        };
+
+        let mut suspicious_pairs: Vec<(Uuid, Uuid)> = Vec::new();
+        for (index, first) in player_identifiers.iter().enumerate() {
+            for second in player_identifiers.iter().skip(index + 1) {
+                if raise_fold_correlation(first, second) > threshold
+                    && raise_fold_correlation(second, first) > threshold
+                {
+                    suspicious_pairs.push((*first, *second));
+                }
+            }
+        }
+
+        suspicious_pairs
+    }
+
+    /// Aggregate statistics over every hand played in the game.
+    pub fn statistics(&self) -> GameStatistics {
+        let total_hands = self.hand_history.len();
+        let total_chips_in_play = self
+            .players
+            .values()
+            .map(|player| player.chips)
+            .sum::<u32>()
+            + self.main_pot.amount
+            + self.side_pots.iter().map(|pot| pot.amount).sum::<u32>();
+
+        let mut hand_type_frequencies: HashMap<String, usize> = HashMap::new();
+        for hand_result in self.hand_history.iter() {
+            if let Some(hand_rank) = hand_result.winning_hand_rank {
+                *hand_type_frequencies
+                    .entry(hand_rank.variant_name().to_string())
+                    .or_default() += 1;
+            }
+        }
+
+        let average_pot = if total_hands == 0 {
+            0.0
+        } else {
+            let total_pot_amount: u64 = self
+                .hand_history
+                .iter()
+                .map(|hand_result| u64::from(hand_result.pot_amount))
+                .sum();
+            total_pot_amount as f64 / total_hands as f64
+        };
+
+        // The first hand wins ties for the biggest pot.
+        let biggest_hand = self
+            .hand_history
+            .iter()
+            .rev()
+            .max_by_key(|hand_result| hand_result.pot_amount);
+        let biggest_pot = biggest_hand.map_or(0, |hand_result| hand_result.pot_amount);
+        let biggest_pot_winner = biggest_hand.and_then(|hand_result| {
+            let names: Vec<&str> = hand_result
+                .winner_identifiers
+                .iter()
+                .filter_map(|player_identifier| self.find_player(player_identifier))
+                .map(|player| player.name.as_str())
+                .collect();
+
+            if names.is_empty() {
+                None
+            } else {
+                Some(names.join(", "))
+            }
+        });
+
+        let mut profit_loss_totals: HashMap<Uuid, (i64, usize)> = HashMap::new();
+        for hand_result in self.hand_history.iter() {
+            for (player_identifier, amount) in hand_result.profit_loss.iter() {
+                let (total, hands) = profit_loss_totals.entry(*player_identifier).or_default();
+                *total += amount;
+                *hands += 1;
+            }
+        }
+        let average_profit_loss_per_player: HashMap<String, f64> = profit_loss_totals
+            .iter()
+            .filter_map(|(player_identifier, (total, hands))| {
+                let player = self.find_player(player_identifier)?;
+                Some((player.name.clone(), *total as f64 / *hands as f64))
+            })
+            .collect();
+
+        GameStatistics {
+            total_hands,
+            total_chips_in_play,
+            average_pot,
+            hand_type_frequencies,
+            biggest_pot,
+            biggest_pot_winner,
+            average_profit_loss_per_player,
+        }
+    }
+
+    /// Resets the main pot and all side pots to be empty.
+    pub fn reset_pots(&mut self) {
+        self.main_pot = Pot::new(0, HashSet::new());
+        self.side_pots = Vec::new();
+        self.end_betting_round();
+    }
+}
+
+impl Default for TexasHoldEm {
+    fn default() -> Self {
+        Self {
+            game_over: false,
+            deck: Deck::new(),
+            players: HashMap::new(),
+            seats: Vec::new(),
+            dealer_seat_index: 0,
+            big_blind_identifier: None,
+            main_pot: Pot::new(0, HashSet::new()),
+            side_pots: Vec::new(),
+            minimum_chips_buy_in_amount: 100,
+            maximum_players_count: 10,
+            small_blind_amount: 2,
+            big_blind_amount: 5,
+            betting_mode: BettingMode::NoLimit,
+            hand_in_progress: false,
+            current_betting_round: None,
+            observers: Vec::new(),
+            pending_removal: HashSet::new(),
+            pending_joins: Vec::new(),
+            street: Street::PreFlop,
+            player_action_history: Vec::new(),
+            player_hand_ranks: HashMap::new(),
+            dealt_hands: HashMap::new(),
+            hand_history: Vec::new(),
+            rounds_played: 0,
+            round_history: Vec::new(),
+            player_statistics: HashMap::new(),
+            eliminated_players: Vec::new(),
+            burned_cards: Hand::new(),
+            randomize_seats: false,
+            auto_remove_broke: false,
+            cut_deck: false,
+            community_cards: Hand::new(),
+            cpu_strategies: HashMap::new(),
+            rng: None,
+            verbose: false,
+        }
+    }
+}
+
+/// The parts of a game that are saved by save_state() and restored by load_state().
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize, serde::Serialize)]
+struct GameState {
+    minimum_chips_buy_in_amount: u32,
+    maximum_players_count: usize,
+    small_blind_amount: u32,
+    big_blind_amount: u32,
+    dealer_seat_index: usize,
+    game_over: bool,
+    betting_mode: BettingMode,
+    players: Vec<Player>,
+}
+
+/// Check that the blinds are positive and in order, and that the minimum buy-in covers enough big blinds.
+fn validate_config(
+    minimum_chips_buy_in_amount: u32,
+    small_blind_amount: u32,
+    big_blind_amount: u32,
+) -> Result<(), ConfigError> {
+    if small_blind_amount == 0 {
+        return Err(ConfigError::ZeroSmallBlind);
+    }
+    if big_blind_amount < small_blind_amount {
+        return Err(ConfigError::BigBlindBelowSmallBlind);
+    }
+    let minimum_buy_in = big_blind_amount.saturating_mul(MINIMUM_BUY_IN_BIG_BLINDS);
+    if minimum_chips_buy_in_amount < minimum_buy_in {
+        return Err(ConfigError::BuyInTooSmall);
+    }
+
+    Ok(())
+}
+
+/// Get the kicker cards from highest to lowest, leaving out every card that shares a rank with the hand rank.
+///
+/// Only the cards that complete a five card hand are kickers, the same as in compare_kickers().
+fn kicker_cards_by_rank(hand_rank: &HandRank, all_cards: &[Card]) -> Vec<Card> {
+    let hand_rank_ranks = hand_rank.component_ranks();
+    let mut kicker_cards: Vec<Card> = all_cards
+        .iter()
+        .filter(|card| !hand_rank_ranks.contains(&card.rank))
+        .copied()
+        .collect();
+    kicker_cards.sort_by(|card1, card2| card2.cmp(card1));
+    kicker_cards.truncate(5_usize.saturating_sub(hand_rank.len()));
+
+    kicker_cards
+}
+
+/// Combine a player's hand with the table cards and turn them all face up for the showdown.
+fn reveal_cards(hand: &Hand, table_cards: &Hand) -> Vec<Card> {
+    table_cards
+        .get_cards()
+        .iter()
+        .chain(hand.get_cards())
+        .map(|card| Card {
+            face_up: true,
+            ..*card
+        })
+        .collect()
+}
+
+/// The Pot manages how many chips have been bet and who the winnings should be allocated to.
+#[derive(Clone)]
+struct Pot {
+    amount: u32,
+    eligible_players: HashSet<Uuid>,
+}
+
+impl Pot {
+    fn new(amount: u32, eligible_players: HashSet<Uuid>) -> Self {
+        Self {
+            amount,
+            eligible_players,
+        }
+    }
+
+    fn add_eligible_player(&mut self, identifier: Uuid) {
+        self.eligible_players.insert(identifier);
+    }
+
+    fn add_chips(&mut self, chips: u32) -> Result<(), &'static str> {
+        self.amount = self
+            .amount
+            .checked_add(chips)
+            .ok_or("The pot is unable to hold that many chips.")?;
+        Ok(())
+    }
+
+    /// Empty the pot and return its chips.
+    ///
+    /// Returns an error if the pot is already empty so that its chips are unable to be distributed twice.
+    fn distribute_all_chips(&mut self) -> Result<u32, &'static str> {
+        if self.amount == 0 {
+            return Err("The pot is empty.");
+        }
+
+        let chips = self.amount;
+        self.amount = 0;
+        Ok(chips)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use casino_cards::card;
+    use casino_cards::card::{Card, Rank, Suit};
+
+    use crate::games::observers::StatisticsObserver;
+
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that a single winner is correctly chosen.
+    #[test]
+    fn rank_all_hands_identifies_winner() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let two_of_diamonds = card!(Two, Diamond);
+        let two_of_hearts = card!(Two, Heart);
+        let three_of_clubs = card!(Three, Club);
+        let four_of_diamonds = card!(Four, Diamond);
+        let five_of_clubs = card!(Five, Club);
+        let six_of_diamonds = card!(Six, Diamond);
+        let eight_of_spades = card!(Eight, Spade);
+        let nine_of_clubs = card!(Nine, Club);
+        let nine_of_hearts = card!(Nine, Heart);
+        let ten_of_spades = card!(Ten, Spade);
+        let jack_of_clubs = card!(Jack, Club);
+        let queen_of_hearts = card!(Queen, Heart);
+        let king_of_clubs = card!(King, Club);
+        let ace_of_hearts = card!(Ace, Heart);
+        let ace_of_spades = card!(Ace, Spade);
+
+        let flush = HandRank::Flush([
+            three_of_clubs,
+            five_of_clubs,
+            nine_of_clubs,
+            jack_of_clubs,
+            king_of_clubs,
+        ]);
+
+        let table_cards: Vec<Card> = vec![
+            two_of_diamonds,
+            three_of_clubs,
+            eight_of_spades,
+            jack_of_clubs,
+            king_of_clubs,
+        ];
+
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+        let table_cards = Hand::new_from_cards(table_cards);
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player1_cards: Vec<Card> = vec![five_of_clubs, nine_of_clubs];
+        let player1_hand = Hand::new_from_cards(player1_cards);
+        player_hands.insert(player1.identifier, player1_hand);
+
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player2_cards: Vec<Card> = vec![two_of_hearts, ten_of_spades];
+        let player2_hand = Hand::new_from_cards(player2_cards);
+        player_hands.insert(player2.identifier, player2_hand);
+
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+        let player3_cards: Vec<Card> = vec![four_of_diamonds, queen_of_hearts];
+        let player3_hand = Hand::new_from_cards(player3_cards);
+        player_hands.insert(player3.identifier, player3_hand);
+
+        let player4 = game.new_player_with_chips("Player 4", 100);
+        game.add_player(player4.clone()).unwrap();
+        let player4_cards: Vec<Card> = vec![ace_of_hearts, ace_of_spades];
+        let player4_hand = Hand::new_from_cards(player4_cards);
+        player_hands.insert(player4.identifier, player4_hand);
+
+        let player5 = game.new_player_with_chips("Player 5", 100);
+        game.add_player(player5.clone()).unwrap();
+        let player5_cards: Vec<Card> = vec![six_of_diamonds, nine_of_hearts];
+        let player5_hand = Hand::new_from_cards(player5_cards);
+        player_hands.insert(player5.identifier, player5_hand);
+
+        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+
+        assert_eq!(leading_players.len(), 1);
+        assert!(leading_players.contains_key(&player1.identifier));
+        assert_eq!(leading_players.get(&player1.identifier).unwrap()[0], flush);
+    }
+
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that a single winner is correctly chosen when the winning hand ranks combines the table and hand
+    /// but one player has a higher kicker (high card) than the other.
+    #[test]
+    fn rank_all_hands_identifies_winner_based_on_kicker_with_hand_winner() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let two_of_diamonds = card!(Two, Diamond);
+        let two_of_hearts = card!(Two, Heart);
+        let six_of_diamonds = card!(Six, Diamond);
+        let nine_of_clubs = card!(Nine, Club);
+        let nine_of_hearts = card!(Nine, Heart);
+        let nine_of_spades = card!(Nine, Spade);
+        let ten_of_spades = card!(Ten, Spade);
+        let jack_of_clubs = card!(Jack, Club);
+        let ace_of_spades = card!(Ace, Spade);
+
+        let two_pair1 = HandRank::TwoPair([
+            nine_of_clubs,
+            nine_of_hearts,
+            two_of_diamonds,
+            two_of_hearts,
+        ]);
+
+        let table_cards: Vec<Card> = vec![
+            two_of_diamonds,
+            two_of_hearts,
+            nine_of_clubs,
+            ten_of_spades,
+            jack_of_clubs,
+        ];
+
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+        let table_cards = Hand::new_from_cards(table_cards);
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player1_cards: Vec<Card> = vec![nine_of_hearts, ace_of_spades];
+        let player1_hand = Hand::new_from_cards(player1_cards);
+        player_hands.insert(player1.identifier, player1_hand);
+
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player2_cards: Vec<Card> = vec![nine_of_spades, six_of_diamonds];
+        let player2_hand = Hand::new_from_cards(player2_cards);
+        player_hands.insert(player2.identifier, player2_hand);
+
+        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+
+        assert_eq!(leading_players.len(), 1);
+        assert!(leading_players.contains_key(&player1.identifier));
+        assert_eq!(
+            leading_players.get(&player1.identifier).unwrap()[0],
+            two_pair1
+        );
+    }
+
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that a single winner is correctly chosen when the winning hand ranks is on the table
+    /// but one player has a higher kicker (high card) than the other.
+    #[test]
+    fn rank_all_hands_identifies_winner_based_on_kicker_with_table_winner() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let two_of_diamonds = card!(Two, Diamond);
+        let two_of_hearts = card!(Two, Heart);
+        let six_of_diamonds = card!(Six, Diamond);
+        let nine_of_clubs = card!(Nine, Club);
+        let nine_of_hearts = card!(Nine, Heart);
+        let ten_of_hearts = card!(Ten, Heart);
+        let ten_of_spades = card!(Ten, Spade);
+        let jack_of_clubs = card!(Jack, Club);
+        let ace_of_spades = card!(Ace, Spade);
+
+        let two_pair1 = HandRank::TwoPair([
+            nine_of_clubs,
+            nine_of_hearts,
+            two_of_diamonds,
+            two_of_hearts,
+        ]);
+
+        let table_cards: Vec<Card> = vec![
+            two_of_diamonds,
+            two_of_hearts,
+            nine_of_clubs,
+            nine_of_hearts,
+            jack_of_clubs,
+        ];
+
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+        let table_cards = Hand::new_from_cards(table_cards);
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player1_cards: Vec<Card> = vec![ten_of_spades, ace_of_spades];
+        let player1_hand = Hand::new_from_cards(player1_cards);
+        player_hands.insert(player1.identifier, player1_hand);
+
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player2_cards: Vec<Card> = vec![six_of_diamonds, ten_of_hearts];
+        let player2_hand = Hand::new_from_cards(player2_cards);
+        player_hands.insert(player2.identifier, player2_hand);
+
+        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+
+        assert_eq!(leading_players.len(), 1);
+        assert!(leading_players.contains_key(&player1.identifier));
+        assert_eq!(
+            leading_players.get(&player1.identifier).unwrap()[0],
+            two_pair1
+        );
+    }
+
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that a single winner is correctly chosen.
+    #[test]
+    fn rank_all_hands_identifies_push_with_winning_table_flush() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let two_of_diamonds = card!(Two, Diamond);
+        let two_of_hearts = card!(Two, Heart);
+        let three_of_clubs = card!(Three, Club);
+        let four_of_diamonds = card!(Four, Diamond);
+        let five_of_clubs = card!(Five, Club);
+        let six_of_diamonds = card!(Six, Diamond);
+        let eight_of_spades = card!(Eight, Spade);
+        let nine_of_clubs = card!(Nine, Club);
+        let nine_of_hearts = card!(Nine, Heart);
+        let ten_of_spades = card!(Ten, Spade);
+        let jack_of_clubs = card!(Jack, Club);
+        let queen_of_hearts = card!(Queen, Heart);
+        let king_of_clubs = card!(King, Club);
+        let ace_of_hearts = card!(Ace, Heart);
+        let ace_of_spades = card!(Ace, Spade);
+
+        let flush = HandRank::Flush([
+            three_of_clubs,
+            five_of_clubs,
+            nine_of_clubs,
+            jack_of_clubs,
+            king_of_clubs,
+        ]);
+
+        let table_cards: Vec<Card> = vec![
+            three_of_clubs,
+            five_of_clubs,
+            nine_of_clubs,
+            jack_of_clubs,
+            king_of_clubs,
+        ];
+
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+        let table_cards = Hand::new_from_cards(table_cards);
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player1_cards: Vec<Card> = vec![two_of_diamonds, eight_of_spades];
+        let player1_hand = Hand::new_from_cards(player1_cards);
+        player_hands.insert(player1.identifier, player1_hand);
+
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player2_cards: Vec<Card> = vec![two_of_hearts, ten_of_spades];
+        let player2_hand = Hand::new_from_cards(player2_cards);
+        player_hands.insert(player2.identifier, player2_hand);
+
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+        let player3_cards: Vec<Card> = vec![four_of_diamonds, queen_of_hearts];
+        let player3_hand = Hand::new_from_cards(player3_cards);
+        player_hands.insert(player3.identifier, player3_hand);
+
+        let player4 = game.new_player_with_chips("Player 4", 100);
+        game.add_player(player4.clone()).unwrap();
+        let player4_cards: Vec<Card> = vec![ace_of_hearts, ace_of_spades];
+        let player4_hand = Hand::new_from_cards(player4_cards);
+        player_hands.insert(player4.identifier, player4_hand);
+
+        let player5 = game.new_player_with_chips("Player 5", 100);
+        game.add_player(player5.clone()).unwrap();
+        let player5_cards: Vec<Card> = vec![six_of_diamonds, nine_of_hearts];
+        let player5_hand = Hand::new_from_cards(player5_cards);
+        player_hands.insert(player5.identifier, player5_hand);
+
+        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+
+        assert_eq!(leading_players.len(), 5);
+        assert_eq!(leading_players.get(&player1.identifier).unwrap()[0], flush);
+        assert_eq!(leading_players.get(&player2.identifier).unwrap()[0], flush);
+        assert_eq!(leading_players.get(&player3.identifier).unwrap()[0], flush);
+        assert_eq!(leading_players.get(&player4.identifier).unwrap()[0], flush);
+        assert_eq!(leading_players.get(&player5.identifier).unwrap()[0], flush);
+    }
+
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that a single winner is correctly chosen.
+    #[test]
+    fn rank_all_hands_identifies_higher_flush_in_hand_wins() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let two_of_diamonds = card!(Two, Diamond);
+        let two_of_hearts = card!(Two, Heart);
+        let three_of_clubs = card!(Three, Club);
+        let four_of_diamonds = card!(Four, Diamond);
+        let five_of_clubs = card!(Five, Club);
+        let six_of_diamonds = card!(Six, Diamond);
+        let seven_of_clubs = card!(Seven, Club);
+        let eight_of_spades = card!(Eight, Spade);
+        let nine_of_clubs = card!(Nine, Club);
+        let nine_of_hearts = card!(Nine, Heart);
+        let jack_of_clubs = card!(Jack, Club);
+        let queen_of_hearts = card!(Queen, Heart);
+        let king_of_clubs = card!(King, Club);
+        let ace_of_hearts = card!(Ace, Heart);
+        let ace_of_spades = card!(Ace, Spade);
+
+        let flush1 = HandRank::Flush([
+            three_of_clubs,
+            five_of_clubs,
+            nine_of_clubs,
+            jack_of_clubs,
+            king_of_clubs,
+        ]);
+
+        let flush2 = HandRank::Flush([
+            three_of_clubs,
+            seven_of_clubs,
+            nine_of_clubs,
+            jack_of_clubs,
+            king_of_clubs,
+        ]);
+
+        let table_cards: Vec<Card> = vec![
+            three_of_clubs,
+            two_of_diamonds,
+            nine_of_clubs,
+            jack_of_clubs,
+            king_of_clubs,
+        ];
+
+        let winning_flush = HandRank::Flush([
+            three_of_clubs,
+            seven_of_clubs,
+            nine_of_clubs,
+            jack_of_clubs,
+            king_of_clubs,
+        ]);
+
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+        let table_cards = Hand::new_from_cards(table_cards);
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player1_cards: Vec<Card> = vec![five_of_clubs, eight_of_spades];
+        let player1_hand = Hand::new_from_cards(player1_cards);
+        player_hands.insert(player1.identifier, player1_hand);
+
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player2_cards: Vec<Card> = vec![two_of_hearts, seven_of_clubs];
+        let player2_hand = Hand::new_from_cards(player2_cards);
+        player_hands.insert(player2.identifier, player2_hand);
+
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+        let player3_cards: Vec<Card> = vec![four_of_diamonds, queen_of_hearts];
+        let player3_hand = Hand::new_from_cards(player3_cards);
+        player_hands.insert(player3.identifier, player3_hand);
+
+        let player4 = game.new_player_with_chips("Player 4", 100);
+        game.add_player(player4.clone()).unwrap();
+        let player4_cards: Vec<Card> = vec![ace_of_hearts, ace_of_spades];
+        let player4_hand = Hand::new_from_cards(player4_cards);
+        player_hands.insert(player4.identifier, player4_hand);
+
+        let player5 = game.new_player_with_chips("Player 5", 100);
+        game.add_player(player5.clone()).unwrap();
+        let player5_cards: Vec<Card> = vec![six_of_diamonds, nine_of_hearts];
+        let player5_hand = Hand::new_from_cards(player5_cards);
+        player_hands.insert(player5.identifier, player5_hand);
+
+        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+
+        assert_ne!(flush1, flush2);
+        assert_eq!(winning_flush, flush2);
+        assert_eq!(leading_players.len(), 1);
+        assert!(!leading_players.contains_key(&player1.identifier));
+        assert!(leading_players.contains_key(&player2.identifier));
+        assert_eq!(flush2, leading_players.get(&player2.identifier).unwrap()[0]);
+        assert_eq!(
+            winning_flush,
+            leading_players.get(&player2.identifier).unwrap()[0]
+        );
+    }
+
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that all players push when the winning hand is on the table.
+    #[test]
+    fn rank_all_hands_identifies_push_with_winning_table_straight() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let two_of_diamonds = card!(Two, Diamond);
+        let three_of_clubs = card!(Three, Club);
+        let four_of_hearts = card!(Four, Heart);
+        let five_of_diamonds = card!(Five, Diamond);
+        let six_of_clubs = card!(Six, Club);
+        let seven_of_spades = card!(Seven, Spade);
+        let five_of_clubs = card!(Five, Club);
+        let nine_of_spades = card!(Nine, Spade);
+        let ten_of_diamonds = card!(Ten, Diamond);
+        let jack_of_clubs = card!(Jack, Club);
+        let jack_of_hearts = card!(Jack, Heart);
+        let jack_of_spades = card!(Jack, Spade);
+        let queen_of_spades = card!(Queen, Spade);
+        let king_of_diamonds = card!(King, Diamond);
+        let ace_of_hearts = card!(Ace, Heart);
+
+        let straight = HandRank::Straight([
+            ten_of_diamonds,
+            jack_of_clubs,
+            queen_of_spades,
+            king_of_diamonds,
+            ace_of_hearts,
+        ]);
+
+        let table_cards: Vec<Card> = vec![
+            ten_of_diamonds,
+            jack_of_clubs,
+            queen_of_spades,
+            king_of_diamonds,
+            ace_of_hearts,
+        ];
+
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+        let table_cards = Hand::new_from_cards(table_cards);
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player1_cards: Vec<Card> = vec![three_of_clubs, four_of_hearts];
+        let player1_hand = Hand::new_from_cards(player1_cards);
+        player_hands.insert(player1.identifier, player1_hand);
+
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player2_cards: Vec<Card> = vec![five_of_diamonds, six_of_clubs];
+        let player2_hand = Hand::new_from_cards(player2_cards);
+        player_hands.insert(player2.identifier, player2_hand);
+
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+        let player3_cards: Vec<Card> = vec![seven_of_spades, nine_of_spades];
+        let player3_hand = Hand::new_from_cards(player3_cards);
+        player_hands.insert(player3.identifier, player3_hand);
+
+        let player4 = game.new_player_with_chips("Player 4", 100);
+        game.add_player(player4.clone()).unwrap();
+        let player4_cards: Vec<Card> = vec![two_of_diamonds, five_of_clubs];
+        let player4_hand = Hand::new_from_cards(player4_cards);
+        player_hands.insert(player4.identifier, player4_hand);
+
+        let player5 = game.new_player_with_chips("Player 5", 100);
+        game.add_player(player5.clone()).unwrap();
+        let player5_cards: Vec<Card> = vec![jack_of_hearts, jack_of_spades];
+        let player5_hand = Hand::new_from_cards(player5_cards);
+        player_hands.insert(player5.identifier, player5_hand);
+
+        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+
+        assert_eq!(leading_players.len(), 5);
+        assert_eq!(
+            leading_players.get(&player1.identifier).unwrap()[0],
+            straight
+        );
+        assert_eq!(
+            leading_players.get(&player2.identifier).unwrap()[0],
+            straight
+        );
+        assert_eq!(
+            leading_players.get(&player3.identifier).unwrap()[0],
+            straight
+        );
+        assert_eq!(
+            leading_players.get(&player4.identifier).unwrap()[0],
+            straight
+        );
+        assert_eq!(
+            leading_players.get(&player5.identifier).unwrap()[0],
+            straight
+        );
+    }
+
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that multiple equal hands result in a push for all involved players.
+    #[test]
+    fn rank_all_hands_identifies_push_with_equal_winning_hand_straights() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let two_of_diamonds = card!(Two, Diamond);
+        let three_of_clubs = card!(Three, Club);
+        let four_of_hearts = card!(Four, Heart);
+        let five_of_clubs = card!(Five, Club);
+        let five_of_diamonds = card!(Five, Diamond);
+        let seven_of_spades = card!(Seven, Spade);
+        let nine_of_spades = card!(Nine, Spade);
+        let ten_of_diamonds = card!(Ten, Diamond);
+        let ten_of_hearts = card!(Ten, Heart);
+        let jack_of_clubs = card!(Jack, Club);
+        let jack_of_hearts = card!(Jack, Heart);
+        let jack_of_spades = card!(Jack, Spade);
+        let queen_of_spades = card!(Queen, Spade);
+        let king_of_diamonds = card!(King, Diamond);
+        let ace_of_hearts = card!(Ace, Heart);
+
+        let straight1 = HandRank::Straight([
+            ten_of_diamonds,
+            jack_of_clubs,
+            queen_of_spades,
+            king_of_diamonds,
+            ace_of_hearts,
+        ]);
+
+        let straight2 = HandRank::Straight([
+            ten_of_hearts,
+            jack_of_clubs,
+            queen_of_spades,
+            king_of_diamonds,
+            ace_of_hearts,
+        ]);
+
+        let table_cards: Vec<Card> = vec![
+            three_of_clubs,
+            jack_of_clubs,
+            queen_of_spades,
+            king_of_diamonds,
+            ace_of_hearts,
+        ];
+
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+        let table_cards = Hand::new_from_cards(table_cards);
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player1_cards: Vec<Card> = vec![four_of_hearts, ten_of_diamonds];
+        let player1_hand = Hand::new_from_cards(player1_cards);
+        player_hands.insert(player1.identifier, player1_hand);
+
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player2_cards: Vec<Card> = vec![five_of_diamonds, ten_of_hearts];
+        let player2_hand = Hand::new_from_cards(player2_cards);
+        player_hands.insert(player2.identifier, player2_hand);
+
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+        let player3_cards: Vec<Card> = vec![seven_of_spades, nine_of_spades];
+        let player3_hand = Hand::new_from_cards(player3_cards);
+        player_hands.insert(player3.identifier, player3_hand);
+
+        let player4 = game.new_player_with_chips("Player 4", 100);
+        game.add_player(player4.clone()).unwrap();
+        let player4_cards: Vec<Card> = vec![two_of_diamonds, five_of_clubs];
+        let player4_hand = Hand::new_from_cards(player4_cards);
+        player_hands.insert(player4.identifier, player4_hand);
+
+        let player5 = game.new_player_with_chips("Player 5", 100);
+        game.add_player(player5.clone()).unwrap();
+        let player5_cards: Vec<Card> = vec![jack_of_hearts, jack_of_spades];
+        let player5_hand = Hand::new_from_cards(player5_cards);
+        player_hands.insert(player5.identifier, player5_hand);
+
+        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+
+        assert_eq!(leading_players.len(), 2);
+        assert!(leading_players.contains_key(&player1.identifier));
+        assert!(leading_players.contains_key(&player2.identifier));
+        assert_eq!(
+            leading_players.get(&player1.identifier).unwrap()[0],
+            straight1
+        );
+        assert_eq!(
+            leading_players.get(&player2.identifier).unwrap()[0],
+            straight2
+        );
+        assert_eq!(
+            *leading_players.get(&player1.identifier).unwrap(),
+            *leading_players.get(&player2.identifier).unwrap()
+        );
+    }
+
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that multiple equal hands result in a push for all involved players.
+    #[test]
+    fn rank_all_hands_identifies_higher_straight_beats_ace_low_straight() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let two_of_diamonds = card!(Two, Diamond);
+        let three_of_clubs = card!(Three, Club);
+        let four_of_hearts = card!(Four, Heart);
+        let five_of_clubs = card!(Five, Club);
+        let five_of_diamonds = card!(Five, Diamond);
+        let six_of_spades = card!(Six, Spade);
+        let nine_of_spades = card!(Nine, Spade);
+        let ten_of_diamonds = card!(Ten, Diamond);
+        let ten_of_hearts = card!(Ten, Heart);
+        let jack_of_clubs = card!(Jack, Club);
+        let jack_of_hearts = card!(Jack, Heart);
+        let jack_of_spades = card!(Jack, Spade);
+        let queen_of_spades = card!(Queen, Spade);
+        let king_of_diamonds = card!(King, Diamond);
+        let ace_of_hearts = card!(Ace, Heart);
+
+        let straight = HandRank::Straight([
+            two_of_diamonds,
+            three_of_clubs,
+            four_of_hearts,
+            five_of_diamonds,
+            six_of_spades,
+        ]);
+
+        let table_cards: Vec<Card> = vec![
+            two_of_diamonds,
+            three_of_clubs,
+            four_of_hearts,
+            five_of_diamonds,
+            king_of_diamonds,
+        ];
+
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+        let table_cards = Hand::new_from_cards(table_cards);
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player1_cards: Vec<Card> = vec![ten_of_diamonds, ace_of_hearts];
+        let player1_hand = Hand::new_from_cards(player1_cards);
+        player_hands.insert(player1.identifier, player1_hand);
+
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player2_cards: Vec<Card> = vec![six_of_spades, jack_of_clubs];
+        let player2_hand = Hand::new_from_cards(player2_cards);
+        player_hands.insert(player2.identifier, player2_hand);
+
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+        let player3_cards: Vec<Card> = vec![nine_of_spades, ten_of_hearts];
+        let player3_hand = Hand::new_from_cards(player3_cards);
+        player_hands.insert(player3.identifier, player3_hand);
+
+        let player4 = game.new_player_with_chips("Player 4", 100);
+        game.add_player(player4.clone()).unwrap();
+        let player4_cards: Vec<Card> = vec![five_of_clubs, queen_of_spades];
+        let player4_hand = Hand::new_from_cards(player4_cards);
+        player_hands.insert(player4.identifier, player4_hand);
+
+        let player5 = game.new_player_with_chips("Player 5", 100);
+        game.add_player(player5.clone()).unwrap();
+        let player5_cards: Vec<Card> = vec![jack_of_hearts, jack_of_spades];
+        let player5_hand = Hand::new_from_cards(player5_cards);
+        player_hands.insert(player5.identifier, player5_hand);
+
+        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+
+        assert_eq!(leading_players.len(), 1);
+        assert!(!leading_players.contains_key(&player1.identifier));
+        assert!(leading_players.contains_key(&player2.identifier));
+        assert_eq!(
+            leading_players.get(&player2.identifier).unwrap()[0],
+            straight
+        );
+    }
+
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that hand ranking correctly updates the leader for a pair that is higher than the previously set high pair.
+    #[test]
+    fn rank_all_hands_identifies_higher_pair_as_winner_over_previous_high_pair() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let two_of_spades = card!(Two, Spade);
+        let four_of_clubs = card!(Four, Club);
+        let five_of_diamonds = card!(Five, Diamond);
+        let six_of_clubs = card!(Six, Club);
+        let seven_of_hearts = card!(Seven, Heart);
+        let nine_of_clubs = card!(Nine, Club);
+        let nine_of_spades = card!(Nine, Spade);
+        let ten_of_clubs = card!(Ten, Club);
+        let ten_of_diamonds = card!(Ten, Diamond);
+        let ten_of_spades = card!(Ten, Spade);
+        let jack_of_clubs = card!(Jack, Club);
+        let jack_of_spades = card!(Jack, Spade);
+        let queen_of_diamonds = card!(Queen, Diamond);
+        let queen_of_hearts = card!(Queen, Heart);
+        let king_of_diamonds = card!(King, Diamond);
+        let ace_of_hearts = card!(Ace, Heart);
+        let ace_of_spades = card!(Ace, Spade);
+
+        let pair = HandRank::Pair([ace_of_hearts, ace_of_spades]);
+
+        let table_cards: Vec<Card> = vec![
+            queen_of_diamonds,
+            jack_of_clubs,
+            five_of_diamonds,
+            two_of_spades,
+            ace_of_spades,
+        ];
+
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+        let table_cards = Hand::new_from_cards(table_cards);
+
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player2_cards: Vec<Card> = vec![jack_of_spades, nine_of_spades];
+        let player2_hand = Hand::new_from_cards(player2_cards);
+        player_hands.insert(player2.identifier, player2_hand);
+
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+        let player3_cards: Vec<Card> = vec![nine_of_clubs, four_of_clubs];
+        let player3_hand = Hand::new_from_cards(player3_cards);
+        player_hands.insert(player3.identifier, player3_hand);
+
+        let player4 = game.new_player_with_chips("Player 4", 100);
+        game.add_player(player4.clone()).unwrap();
+        let player4_cards: Vec<Card> = vec![six_of_clubs, ten_of_spades];
+        let player4_hand = Hand::new_from_cards(player4_cards);
         player_hands.insert(player4.identifier, player4_hand);
 
         let player5 = game.new_player_with_chips("Player 5", 100);
         game.add_player(player5.clone()).unwrap();
-        let player5_cards: Vec<Card> = vec![six_of_diamonds, nine_of_hearts];
-        let player5_hand = Hand::new_from_cards(player5_cards);
-        player_hands.insert(player5.identifier, player5_hand);
+        let player5_cards: Vec<Card> = vec![seven_of_hearts, queen_of_hearts];
+        let player5_hand = Hand::new_from_cards(player5_cards);
+        player_hands.insert(player5.identifier, player5_hand);
+
+        let player6 = game.new_player_with_chips("Player 6", 100);
+        game.add_player(player6.clone()).unwrap();
+        let player6_cards: Vec<Card> = vec![ten_of_diamonds, ten_of_clubs];
+        let player6_hand = Hand::new_from_cards(player6_cards);
+        player_hands.insert(player6.identifier, player6_hand);
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player1_cards: Vec<Card> = vec![king_of_diamonds, ace_of_hearts];
+        let player1_hand = Hand::new_from_cards(player1_cards);
+        player_hands.insert(player1.identifier, player1_hand);
+
+        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+
+        assert_eq!(leading_players.len(), 1);
+        assert!(leading_players.contains_key(&player1.identifier));
+        assert_eq!(leading_players.get(&player1.identifier).unwrap()[0], pair);
+    }
+
+    /// Tests fold_player().
+    ///
+    /// Tests that a player can fold during a hand and is then excluded from ranking.
+    #[test]
+    fn fold_player_works() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+
+        assert_eq!(
+            game.fold_player(player1.identifier),
+            Err(FoldError::NotInBettingRound)
+        );
+
+        game.shuffle_deck();
+        let player_hands = game.deal_hands_to_all_players();
+        let table_cards = Hand::new();
+
+        assert_eq!(
+            game.fold_player(Uuid::new_v4()),
+            Err(FoldError::PlayerNotFound)
+        );
+        assert_eq!(game.fold_player(player1.identifier), Ok(()));
+        assert_eq!(
+            game.fold_player(player1.identifier),
+            Err(FoldError::AlreadyFolded)
+        );
+
+        // The folded player keeps their seat and chips.
+        assert!(game.seats.contains(&player1.identifier));
+        assert_eq!(game.players.get(&player1.identifier).unwrap().chips, 100);
+
+        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+
+        assert_eq!(leading_players.len(), 1);
+        assert!(leading_players.contains_key(&player2.identifier));
+    }
+
+    /// Tests pot_to_stack_ratio().
+    ///
+    /// Tests that the ratio is calculated from the main pot and the player's chips.
+    #[test]
+    fn pot_to_stack_ratio_works() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+
+        assert_eq!(game.pot_to_stack_ratio(Uuid::new_v4()), None);
+        assert_eq!(game.pot_to_stack_ratio(player1.identifier), Some(0.0));
+
+        game.add_chips_to_main_pot(50).unwrap();
+        assert_eq!(game.pot_to_stack_ratio(player1.identifier), Some(0.5));
+
+        game.subtract_chips_from_player(&player1.identifier, 100);
+        assert_eq!(
+            game.pot_to_stack_ratio(player1.identifier),
+            Some(f64::INFINITY)
+        );
+    }
+
+    /// Tests apply_player_action().
+    ///
+    /// Tests that a player with fewer chips than the amount to call is able to call all in for their remaining chips.
+    #[test]
+    fn apply_player_action_allows_short_stack_to_call_all_in() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        game.players.get_mut(&player2.identifier).unwrap().chips = 20;
+        game.reset_player_round_state();
+
+        game.start_betting_round(1);
+        game.apply_player_action(player2.identifier, &PlayerAction::Check())
+            .unwrap();
+        game.apply_player_action(player1.identifier, &PlayerAction::Raise(50))
+            .unwrap();
+        assert_eq!(game.get_amount_to_call(&player2.identifier), 50);
+        assert_eq!(
+            game.parse_player_action(player2.identifier, "all-in"),
+            Some(PlayerAction::Call())
+        );
+
+        game.apply_player_action(player2.identifier, &PlayerAction::Call())
+            .unwrap();
+
+        let short_stack = &game.players[&player2.identifier];
+        assert_eq!(short_stack.chips, 0);
+        assert!(short_stack.is_all_in());
+        assert!(!short_stack.is_folded());
+        assert_eq!(game.main_pot.amount, 70);
+        assert!(game.betting_round_complete());
+    }
+
+    /// Tests run_betting_round_with_bots().
+    ///
+    /// Tests that bots act in turn order until the betting round is complete.
+    #[test]
+    fn run_betting_round_with_bots_works() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+
+        game.post_blind(true);
+        game.post_blind(false);
+        game.start_betting_round(game.get_under_the_gun_seat_index());
+
+        let bot_identifiers: HashSet<Uuid> =
+            HashSet::from([player1.identifier, player2.identifier, player3.identifier]);
+        let actions = game.run_betting_round_with_bots(&bot_identifiers);
+
+        assert_eq!(
+            actions,
+            vec![
+                (player1.identifier, PlayerAction::Call()),
+                (player2.identifier, PlayerAction::Call()),
+                (player3.identifier, PlayerAction::Check()),
+            ]
+        );
+        assert!(game.betting_round_complete());
+        assert_eq!(game.main_pot.amount, 9);
+    }
+
+    /// Tests run_betting_round_with_bots().
+    ///
+    /// Tests that the betting round pauses on a human player's turn and that a raise reopens the betting.
+    #[test]
+    fn run_betting_round_with_bots_pauses_for_human() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+
+        game.post_blind(true);
+        game.post_blind(false);
+        game.start_betting_round(game.get_under_the_gun_seat_index());
+
+        let bot_identifiers: HashSet<Uuid> =
+            HashSet::from([player2.identifier, player3.identifier]);
+
+        let actions = game.run_betting_round_with_bots(&bot_identifiers);
+        assert!(actions.is_empty());
+        assert_eq!(game.next_to_act(), Some(player1.identifier));
+
+        game.apply_player_action(player1.identifier, &PlayerAction::Raise(5))
+            .unwrap();
+
+        let actions = game.run_betting_round_with_bots(&bot_identifiers);
+        assert_eq!(
+            actions,
+            vec![
+                (player2.identifier, PlayerAction::Call()),
+                (player3.identifier, PlayerAction::Call()),
+            ]
+        );
+        assert!(game.betting_round_complete());
+        assert_eq!(game.main_pot.amount, 24);
+    }
+
+    /// Tests award_main_pot_to().
+    ///
+    /// Tests that all chips in the main pot are moved to the provided player.
+    #[test]
+    fn award_main_pot_to_works() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+
+        game.add_chips_to_main_pot(25).unwrap();
+
+        assert_eq!(
+            game.award_main_pot_to(Uuid::new_v4()),
+            Err(AwardError::PlayerNotFound)
+        );
+        assert_eq!(game.main_pot.amount, 25);
+
+        assert_eq!(game.award_main_pot_to(player1.identifier), Ok(25));
+        assert_eq!(game.main_pot.amount, 0);
+        assert_eq!(game.players.get(&player1.identifier).unwrap().chips, 125);
+
+        assert_eq!(
+            game.award_main_pot_to(player1.identifier),
+            Err(AwardError::EmptyPot)
+        );
+        assert_eq!(game.players.get(&player1.identifier).unwrap().chips, 125);
+    }
+
+    /// Tests add_chips_to_main_pot().
+    ///
+    /// Tests that adding chips which would overflow the main pot is an error and leaves the pot unchanged.
+    #[test]
+    fn add_chips_to_main_pot_rejects_overflow() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        game.add_chips_to_main_pot(u32::MAX).unwrap();
+        assert!(game.add_chips_to_main_pot(1).is_err());
+        assert_eq!(game.get_main_pot_amount(), u32::MAX);
+    }
+
+    /// Tests shuffle_deck().
+    ///
+    /// Tests that cutting the deck after shuffling keeps every card and is repeatable with a seed.
+    #[test]
+    fn shuffle_deck_cuts_deck() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+        game.set_cut_deck(true);
+        game.rng = Some(StdRng::seed_from_u64(7));
+        game.shuffle_deck();
+        assert_eq!(game.deck.len(), 52);
+
+        let mut uncut_game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+        uncut_game.rng = Some(StdRng::seed_from_u64(7));
+        uncut_game.shuffle_deck();
+        assert_ne!(game.deck, uncut_game.deck);
+
+        let mut repeated_game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+        repeated_game.set_cut_deck(true);
+        repeated_game.rng = Some(StdRng::seed_from_u64(7));
+        repeated_game.shuffle_deck();
+        assert_eq!(game.deck, repeated_game.deck);
+    }
+
+    /// Tests round_history().
+    ///
+    /// Tests that every simulated round is counted and summarized with its winners, pot, and hand ranks.
+    #[test]
+    fn round_history_records_each_round() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        assert_eq!(game.rounds_played(), 0);
+
+        let round_results = [
+            game.simulate_round_with_seed(1),
+            game.simulate_round_with_seed(2),
+        ];
+
+        assert_eq!(game.rounds_played(), 2);
+        assert_eq!(game.round_history().len(), 2);
+        for (index, (round_summary, round_result)) in game
+            .round_history()
+            .iter()
+            .zip(round_results.iter())
+            .enumerate()
+        {
+            assert_eq!(round_summary.round_number, index as u32 + 1);
+            assert_eq!(round_summary.pot_amount, round_result.pot_amount);
+            assert_eq!(
+                round_summary.winner_identifiers.len(),
+                round_result.winning_players.len()
+            );
+            assert!(round_summary
+                .winner_identifiers
+                .iter()
+                .all(|identifier| round_result.winning_players.contains_key(identifier)));
+            assert!(round_summary.hand_ranks.contains_key(&player1.identifier));
+            assert!(round_summary.hand_ranks.contains_key(&player2.identifier));
+        }
+
+        game.reset_for_new_session(vec![player1, player2]);
+        assert_eq!(game.rounds_played(), 0);
+        assert!(game.round_history().is_empty());
+    }
+
+    /// Tests round_history().
+    ///
+    /// Tests that a player who folds has no hand rank in the summary, even after being ranked earlier.
+    #[test]
+    fn round_history_leaves_out_folded_players() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+        for name in ["Player 1", "Player 2", "Player 3"] {
+            let player = game.new_player_with_chips(name, 100);
+            game.add_player(player).unwrap();
+        }
+
+        game.play_hand_with_all_checks();
+        assert_eq!(game.round_history()[0].hand_ranks.len(), 3);
+
+        // The first player to act folds, and the blinds check the hand down.
+        let mut input = "fold\ncall\ncheck\ncheck\ncheck\ncheck\ncheck\ncheck\ncheck\n".as_bytes();
+        game.play_round_with_betting(&mut input);
+        let folded_player_identifier = game.hand_history[1]
+            .actions
+            .iter()
+            .find(|record| record.action == PlayerAction::Fold())
+            .unwrap()
+            .player_identifier;
+
+        let round_summary = &game.round_history()[1];
+        assert_eq!(round_summary.hand_ranks.len(), 2);
+        assert!(!round_summary
+            .hand_ranks
+            .contains_key(&folded_player_identifier));
+        assert_eq!(game.get_player_rank_in_hand(folded_player_identifier), None);
+
+        // Ranking the hands again after a player folds drops the rank they had earlier in the hand.
+        let player_hands = game.deal_hands_to_all_players();
+        game.rank_all_hands(&player_hands, &Hand::new());
+        let player_identifier = game.seats[0];
+        assert!(game.get_player_rank_in_hand(player_identifier).is_some());
+        game.fold_player(player_identifier).unwrap();
+        game.rank_all_hands(&player_hands, &Hand::new());
+        assert_eq!(game.get_player_rank_in_hand(player_identifier), None);
+    }
+
+    /// Tests with_verbose().
+    ///
+    /// Tests that the game is quiet by default and that a verbose game still plays a full round.
+    #[test]
+    fn with_verbose_works() {
+        assert!(!TexasHoldEm::new(100, 10, 1, 3).unwrap().verbose);
+
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap().with_verbose(true);
+        assert!(game.verbose);
+        let statistics = StatisticsObserver::new();
+        game.add_observer(Box::new(statistics.clone()));
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+
+        game.simulate_round_with_seed(7);
+
+        assert_eq!(statistics.hands_dealt(&player1.identifier), 1);
+        assert_eq!(statistics.hands_dealt(&player2.identifier), 1);
+        assert_eq!(
+            game.players[&player1.identifier].chips + game.players[&player2.identifier].chips,
+            200
+        );
+    }
+
+    /// Tests add_observer().
+    ///
+    /// Tests that observers are notified of the events in a simulated round.
+    #[test]
+    fn observers_are_notified_of_round_events() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+        let statistics = StatisticsObserver::new();
+        game.add_observer(Box::new(statistics.clone()));
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+
+        game.simulate_round();
+
+        assert_eq!(statistics.rounds_started(), 1);
+        assert_eq!(statistics.rounds_ended(), 1);
+        assert_eq!(statistics.hands_dealt(&player1.identifier), 1);
+        assert_eq!(statistics.hands_dealt(&player2.identifier), 1);
+        assert!(
+            statistics.rounds_won(&player1.identifier) + statistics.rounds_won(&player2.identifier)
+                >= 1
+        );
+    }
+
+    /// Tests export_to_pbn_format().
+    ///
+    /// Tests that a block is written for every hand with the starting stacks and the actions of that hand.
+    #[cfg(feature = "export")]
+    #[test]
+    fn export_to_pbn_format_works() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+
+        // Player 2 and Player 3 fold to Player 1's big blind, and then Player 3 and Player 1 fold to Player 2's.
+        let mut input = "fold\nfold\n".as_bytes();
+        game.play_round_with_betting(&mut input);
+        let mut input = "fold\nfold\n".as_bytes();
+        game.play_round_with_betting(&mut input);
+
+        let export = game.export_to_pbn_format();
+        let blocks: Vec<&str> = export.split("\n\n[").collect();
+
+        assert_eq!(blocks.len(), 2);
+        assert!(blocks[0].starts_with("[Game \"Texas hold 'em No-limit\"]\n[Date \""));
+        assert!(blocks[0].contains("[Blinds \"1/3\"]\n[Hand \"1\"]\n"));
+        assert!(blocks[0].contains(
+            "[Seat1 \"Player 1\" \"100\"]\n[Seat2 \"Player 2\" \"100\"]\n[Seat3 \"Player 3\" \"100\"]\n"
+        ));
+        assert!(blocks[0].ends_with("\nPlayer 2 folds\nPlayer 3 folds"));
+        assert!(blocks[1].contains("[Hand \"2\"]\n"));
+        assert!(blocks[1].contains(
+            "[Seat1 \"Player 1\" \"101\"]\n[Seat2 \"Player 2\" \"100\"]\n[Seat3 \"Player 3\" \"99\"]\n"
+        ));
+        assert!(blocks[1].ends_with("\nPlayer 3 folds\nPlayer 1 folds\n"));
+    }
+
+    /// Tests player_action_history().
+    ///
+    /// Tests that every action is recorded with its street and the pot before and after the action.
+    #[test]
+    fn player_action_history_records_actions() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+
+        game.shuffle_deck();
+        game.post_blind(true);
+        game.post_blind(false);
+        game.deal_hands_to_all_players();
+        game.start_betting_round(game.get_under_the_gun_seat_index());
+
+        game.apply_player_action(player1.identifier, &PlayerAction::Call())
+            .unwrap();
+        game.apply_player_action(player2.identifier, &PlayerAction::Fold())
+            .unwrap();
+        game.apply_player_action(player3.identifier, &PlayerAction::Check())
+            .unwrap();
+
+        game.end_betting_round();
+        game.start_betting_round(game.get_small_blind_seat_index());
+        game.apply_player_action(player3.identifier, &PlayerAction::Raise(10))
+            .unwrap();
+
+        assert_eq!(
+            game.player_action_history(),
+            &[
+                ActionRecord {
+                    player_identifier: player1.identifier,
+                    street: Street::PreFlop,
+                    action: PlayerAction::Call(),
+                    pot_before: 4,
+                    pot_after: 7,
+                },
+                ActionRecord {
+                    player_identifier: player2.identifier,
+                    street: Street::PreFlop,
+                    action: PlayerAction::Fold(),
+                    pot_before: 7,
+                    pot_after: 7,
+                },
+                ActionRecord {
+                    player_identifier: player3.identifier,
+                    street: Street::PreFlop,
+                    action: PlayerAction::Check(),
+                    pot_before: 7,
+                    pot_after: 7,
+                },
+                ActionRecord {
+                    player_identifier: player3.identifier,
+                    street: Street::Flop,
+                    action: PlayerAction::Raise(10),
+                    pot_before: 7,
+                    pot_after: 17,
+                },
+            ]
+        );
+
+        // The history is cleared when the next hand is dealt.
+        game.reset_pots();
+        game.deal_hands_to_all_players();
+        assert!(game.player_action_history().is_empty());
+    }
+
+    /// Tests is_in_position() and position_name().
+    ///
+    /// Tests that positions are named relative to the dealer and that the last active player is in position.
+    #[test]
+    fn positions_are_identified_relative_to_the_dealer() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let mut players = Vec::new();
+        for i in 1..=6 {
+            let player = game.new_player_with_chips(&format!("Player {i}"), 100);
+            game.add_player(player.clone()).unwrap();
+            players.push(player);
+        }
+
+        let position_names: Vec<&str> = players
+            .iter()
+            .map(|player| game.position_name(player.identifier).unwrap())
+            .collect();
+        assert_eq!(position_names, vec!["BTN", "SB", "BB", "UTG", "MP", "CO"]);
+        assert_eq!(game.position_name(Uuid::new_v4()), None);
+
+        assert!(game.is_in_position(players[0].identifier));
+        assert!(!game.is_in_position(players[5].identifier));
+
+        // The cutoff is in position once the dealer folds.
+        game.shuffle_deck();
+        game.deal_hands_to_all_players();
+        game.fold_player(players[0].identifier).unwrap();
+
+        assert!(!game.is_in_position(players[0].identifier));
+        assert!(game.is_in_position(players[5].identifier));
+    }
+
+    /// Tests rank_all_players().
+    ///
+    /// Tests that every player is ranked from best to worst with tied players grouped together.
+    #[test]
+    fn rank_all_players_orders_players_from_best_to_worst() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let table_cards = Hand::new_from_cards(vec![
+            card!(Two, Diamond),
+            card!(Three, Club),
+            card!(Eight, Spade),
+            card!(Jack, Heart),
+            card!(King, Club),
+        ]);
+
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+
+        // A pair of Aces.
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        player_hands.insert(
+            player1.identifier,
+            Hand::new_from_cards(vec![card!(Ace, Heart), card!(Ace, Spade)]),
+        );
+
+        // A pair of Kings with an Ace kicker.
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        player_hands.insert(
+            player2.identifier,
+            Hand::new_from_cards(vec![card!(King, Heart), card!(Ace, Club)]),
+        );
+
+        // High card.
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+        player_hands.insert(
+            player3.identifier,
+            Hand::new_from_cards(vec![card!(Four, Heart), card!(Nine, Club)]),
+        );
+
+        // A pair of Kings with an Ace kicker.
+        let player4 = game.new_player_with_chips("Player 4", 100);
+        game.add_player(player4.clone()).unwrap();
+        player_hands.insert(
+            player4.identifier,
+            Hand::new_from_cards(vec![card!(King, Spade), card!(Ace, Diamond)]),
+        );
+
+        // A pair of Kings with a Queen kicker.
+        let player5 = game.new_player_with_chips("Player 5", 100);
+        game.add_player(player5.clone()).unwrap();
+        player_hands.insert(
+            player5.identifier,
+            Hand::new_from_cards(vec![card!(King, Diamond), card!(Queen, Club)]),
+        );
 
-        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+        let ranked_players = game.rank_all_players(&player_hands, &table_cards);
 
-        assert_eq!(leading_players.len(), 1);
-        assert!(leading_players.contains_key(&player1.identifier));
-        assert_eq!(leading_players.get(&player1.identifier).unwrap()[0], flush);
+        assert_eq!(
+            ranked_players,
+            vec![
+                vec![player1.identifier],
+                vec![player2.identifier, player4.identifier],
+                vec![player5.identifier],
+                vec![player3.identifier],
+            ]
+        );
     }
 
-    /// Tests rank_all_hands().
+    /// Tests rank_all_players().
     ///
-    /// Tests that a single winner is correctly chosen when the winning hand ranks combines the table and hand
-    /// but one player has a higher kicker (high card) than the other.
+    /// Tests that two sets of three of a kind among seven cards rank as a full house, matching the winners from rank_hands().
     #[test]
-    fn rank_all_hands_identifies_winner_based_on_kicker_with_hand_winner() {
-        let mut game = TexasHoldEm::new(100, 10, 1, 3);
+    fn rank_all_players_matches_rank_hands_with_two_sets_of_three_of_a_kind() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let table_cards = Hand::new_from_cards(vec![
+            card!(King, Diamond),
+            card!(King, Club),
+            card!(King, Spade),
+            card!(Seven, Heart),
+            card!(Two, Club),
+        ]);
 
-        let two_of_diamonds = card!(Two, Diamond);
-        let two_of_hearts = card!(Two, Heart);
-        let six_of_diamonds = card!(Six, Diamond);
-        let nine_of_clubs = card!(Nine, Club);
-        let nine_of_hearts = card!(Nine, Heart);
-        let nine_of_spades = card!(Nine, Spade);
-        let ten_of_spades = card!(Ten, Spade);
-        let jack_of_clubs = card!(Jack, Club);
-        let ace_of_spades = card!(Ace, Spade);
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
 
-        let two_pair1 = HandRank::TwoPair([
-            nine_of_clubs,
-            nine_of_hearts,
-            two_of_diamonds,
-            two_of_hearts,
-        ]);
+        // Three Kings and three Sevens, which make a full house.
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        player_hands.insert(
+            player1.identifier,
+            Hand::new_from_cards(vec![card!(Seven, Spade), card!(Seven, Diamond)]),
+        );
 
-        let table_cards: Vec<Card> = vec![
-            two_of_diamonds,
-            two_of_hearts,
-            nine_of_clubs,
-            ten_of_spades,
-            jack_of_clubs,
-        ];
+        // Three Kings with an Ace and Queen as kickers.
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        player_hands.insert(
+            player2.identifier,
+            Hand::new_from_cards(vec![card!(Ace, Heart), card!(Queen, Club)]),
+        );
+
+        let ranked_players = game.rank_all_players(&player_hands, &table_cards);
+        let (winning_players, _) = game.rank_hands(&player_hands, &table_cards);
+
+        assert_eq!(ranked_players[0], vec![player1.identifier]);
+        assert_eq!(
+            winning_players.keys().copied().collect::<Vec<Uuid>>(),
+            ranked_players[0]
+        );
+    }
+
+    /// Tests deal_card() and deal_card_face_up().
+    ///
+    /// Tests that players' cards are dealt face down and table cards are dealt face up.
+    #[test]
+    fn hands_are_dealt_face_down() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+
+        game.shuffle_deck();
+        let player_hands = game.deal_hands_to_all_players();
+
+        for hand in player_hands.values() {
+            assert!(hand.get_cards().iter().all(|card| !card.face_up));
+            assert_eq!(hand.to_symbols(), "🂠 🂠");
+        }
+
+        let table_card = game.deal_card_face_up().unwrap();
+        assert!(table_card.face_up);
+        assert_ne!(table_card.to_string(), "🂠");
+    }
+
+    /// Tests remove_player_after_round().
+    ///
+    /// Tests that a scheduled player keeps their seat until the round is over.
+    #[test]
+    fn remove_player_after_round_works() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        game.add_player(player3.clone()).unwrap();
+
+        game.remove_player_after_round(player2.identifier);
+        assert!(game.seats.contains(&player2.identifier));
+        assert!(game.players.contains_key(&player2.identifier));
+
+        game.simulate_round();
+
+        assert!(!game.seats.contains(&player2.identifier));
+        assert!(!game.players.contains_key(&player2.identifier));
+        assert_eq!(game.seats.len(), 2);
+        assert!(game.pending_removal.is_empty());
+    }
+
+    /// Tests add_player_for_next_round().
+    ///
+    /// Tests that a player joining during a hand is only seated at the start of the next round.
+    #[test]
+    fn add_player_for_next_round_works() {
+        let mut game = TexasHoldEm::new(100, 3, 1, 3).unwrap();
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        game.add_player(player1.clone()).unwrap();
+        let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player2.clone()).unwrap();
+
+        game.deal_hands_to_all_players();
+        assert!(game.is_hand_in_progress());
+
+        let player3 = game.new_player_with_chips("Player 3", 100);
+        assert_eq!(game.add_player_for_next_round(player3.clone()), Ok(()));
+        assert_eq!(
+            game.add_player_for_next_round(player3.clone()),
+            Err(AddPlayerError::TableFull)
+        );
+        assert!(!game.seats.contains(&player3.identifier));
+
+        game.simulate_round();
+
+        assert!(!game.is_hand_in_progress());
+        assert!(game.seats.contains(&player3.identifier));
+        assert!(game.players.contains_key(&player3.identifier));
+        assert!(game.pending_joins.is_empty());
+
+        let mut game2 = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+        let player4 = game2.new_player_with_chips("Player 4", 50);
+        assert_eq!(
+            game2.add_player_for_next_round(player4),
+            Err(AddPlayerError::NotEnoughChips)
+        );
+        game2.add_player(player1.clone()).unwrap();
+        assert_eq!(
+            game2.add_player_for_next_round(player1),
+            Err(AddPlayerError::AlreadyJoined)
+        );
+    }
+
+    /// Tests assign_random_seats().
+    ///
+    /// Tests that every player keeps a seat after the seats are randomly assigned.
+    #[test]
+    fn assign_random_seats_keeps_every_player_seated() {
+        let mut texas_hold_em = TexasHoldEm::default();
+        for index in 0..8 {
+            let player = texas_hold_em.new_player_with_chips(&format!("Player {}", index), 100);
+            texas_hold_em.add_player(player).unwrap();
+        }
+        let original_seats = texas_hold_em.seats.clone();
+
+        texas_hold_em.assign_random_seats();
+
+        assert_eq!(texas_hold_em.seats.len(), original_seats.len());
+        for player_identifier in original_seats.iter() {
+            assert!(texas_hold_em.seats.contains(player_identifier));
+        }
+    }
+
+    /// Tests print_action_prompt().
+    ///
+    /// Tests that the prompt lists the legal actions and what they cost.
+    #[test]
+    fn format_action_prompt_lists_legal_actions() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 5, 5).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 150);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 150);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 150);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+
+        texas_hold_em.deal_hands_to_all_players();
+        texas_hold_em.post_blind(true);
+        texas_hold_em.post_blind(false);
+        texas_hold_em.start_betting_round(texas_hold_em.get_under_the_gun_seat_index());
+
+        let under_the_gun_identifier = texas_hold_em.next_to_act().unwrap();
+        assert_eq!(texas_hold_em.get_current_bet(), 5);
+        assert_eq!(
+            texas_hold_em
+                .format_action_prompt(under_the_gun_identifier)
+                .unwrap(),
+            "Player 1 [150 chips] | Pot: 10 | To call: 5 | Actions: fold, call (5), raise (min 10), all-in (150)"
+        );
+        assert!(texas_hold_em.format_action_prompt(Uuid::new_v4()).is_none());
+    }
+
+    /// Tests award_pot_split_evenly().
+    ///
+    /// Tests that remainder chips go to the players closest to the left of the dealer.
+    #[test]
+    fn award_pot_split_evenly_distributes_remainder_from_left_of_dealer() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+
+        // Player 1 is the dealer, so Player 2 and then Player 3 receive the remainder first.
+        let chips_won = texas_hold_em
+            .award_pot_split_evenly(&[player1.identifier, player3.identifier], 11)
+            .unwrap();
+        assert_eq!(chips_won, vec![5, 6]);
+        assert_eq!(texas_hold_em.players[&player1.identifier].chips, 105);
+        assert_eq!(texas_hold_em.players[&player3.identifier].chips, 106);
+
+        let chips_won = texas_hold_em
+            .award_pot_split_evenly(
+                &[player1.identifier, player2.identifier, player3.identifier],
+                5,
+            )
+            .unwrap();
+        assert_eq!(chips_won, vec![1, 2, 2]);
+
+        assert_eq!(
+            texas_hold_em.award_pot_split_evenly(&[], 10),
+            Err(AwardError::NoPlayers)
+        );
+        assert_eq!(
+            texas_hold_em.award_pot_split_evenly(&[Uuid::new_v4()], 10),
+            Err(AwardError::PlayerNotFound)
+        );
+    }
+
+    /// Tests set_blind_amounts().
+    ///
+    /// Tests that the blind amounts are updated and invalid amounts are rejected.
+    #[test]
+    fn set_blind_amounts_works() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        assert_eq!(texas_hold_em.get_blind_amounts(), (1, 2));
+
+        assert!(texas_hold_em.set_blind_amounts(2, 5).is_ok());
+        assert_eq!(texas_hold_em.get_blind_amounts(), (2, 5));
+
+        assert_eq!(
+            texas_hold_em.set_blind_amounts(0, 5),
+            Err(ConfigError::ZeroSmallBlind)
+        );
+        assert_eq!(
+            texas_hold_em.set_blind_amounts(5, 2),
+            Err(ConfigError::BigBlindBelowSmallBlind)
+        );
+        assert_eq!(
+            texas_hold_em.set_blind_amounts(5, 10),
+            Err(ConfigError::BuyInTooSmall)
+        );
+        assert_eq!(texas_hold_em.get_blind_amounts(), (2, 5));
+    }
+
+    /// Tests set_minimum_buy_in().
+    ///
+    /// Tests that the minimum buy-in is updated and must cover 20 big blinds.
+    #[test]
+    fn set_minimum_buy_in_works() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+
+        assert!(texas_hold_em.set_minimum_buy_in(40).is_ok());
+        assert_eq!(texas_hold_em.get_minimum_buy_in(), 40);
+
+        assert_eq!(
+            texas_hold_em.set_minimum_buy_in(39),
+            Err(ConfigError::BuyInTooSmall)
+        );
+        assert_eq!(texas_hold_em.get_minimum_buy_in(), 40);
+    }
+
+    /// Tests new().
+    ///
+    /// Tests that a game is unable to be created with invalid blind or buy-in amounts.
+    #[test]
+    fn new_rejects_invalid_config() {
+        assert!(TexasHoldEm::new(100, 10, 1, 2).is_ok());
+        assert!(matches!(
+            TexasHoldEm::new(100, 10, 2, 1),
+            Err(ConfigError::BigBlindBelowSmallBlind)
+        ));
+        assert!(matches!(
+            TexasHoldEm::new(100, 10, 0, 2),
+            Err(ConfigError::ZeroSmallBlind)
+        ));
+        assert!(matches!(
+            TexasHoldEm::new(100, 10, 5, 10),
+            Err(ConfigError::BuyInTooSmall)
+        ));
+        assert_eq!(
+            ConfigError::BuyInTooSmall.to_string(),
+            format!(
+                "The minimum buy-in must be at least {} times the big blind amount.",
+                MINIMUM_BUY_IN_BIG_BLINDS
+            )
+        );
+    }
+
+    /// Tests reset_player_round_state().
+    ///
+    /// Tests that folded players are able to play the next hand.
+    #[test]
+    fn reset_player_round_state_works() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+
+        texas_hold_em.deal_hands_to_all_players();
+        texas_hold_em.fold_player(player1.identifier).unwrap();
+        assert!(texas_hold_em.players[&player1.identifier].is_folded());
+
+        texas_hold_em.reset_player_round_state();
+        assert!(!texas_hold_em.players[&player1.identifier].is_folded());
+    }
+
+    /// Tests statistics().
+    ///
+    /// Tests that statistics are aggregated from a known sequence of hands.
+    #[test]
+    fn statistics_aggregates_hand_history() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+
+        assert_eq!(
+            texas_hold_em.statistics(),
+            GameStatistics {
+                total_chips_in_play: 200,
+                ..GameStatistics::default()
+            }
+        );
+
+        let pair = HandRank::Pair([card!(King, Club), card!(King, Heart)]);
+        let flush = HandRank::Flush([
+            card!(Two, Spade),
+            card!(Five, Spade),
+            card!(Nine, Spade),
+            card!(Jack, Spade),
+            card!(Ace, Spade),
+        ]);
 
-        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
-        let table_cards = Hand::new_from_cards(table_cards);
+        texas_hold_em.subtract_chips_from_player(&player2.identifier, 10);
+        texas_hold_em.add_chips_to_main_pot(10).unwrap();
+        texas_hold_em.determine_round_result(
+            &HashMap::new(),
+            &Hand::new(),
+            &HashMap::from([(player1.identifier, vec![pair])]),
+        );
 
-        let player1 = game.new_player_with_chips("Player 1", 100);
-        game.add_player(player1.clone()).unwrap();
-        let player1_cards: Vec<Card> = vec![nine_of_hearts, ace_of_spades];
-        let player1_hand = Hand::new_from_cards(player1_cards);
-        player_hands.insert(player1.identifier, player1_hand);
+        texas_hold_em.subtract_chips_from_player(&player1.identifier, 30);
+        texas_hold_em.add_chips_to_main_pot(30).unwrap();
+        texas_hold_em.determine_round_result(
+            &HashMap::new(),
+            &Hand::new(),
+            &HashMap::from([(player2.identifier, vec![flush])]),
+        );
 
-        let player2 = game.new_player_with_chips("Player 2", 100);
-        game.add_player(player2.clone()).unwrap();
-        let player2_cards: Vec<Card> = vec![nine_of_spades, six_of_diamonds];
-        let player2_hand = Hand::new_from_cards(player2_cards);
-        player_hands.insert(player2.identifier, player2_hand);
+        texas_hold_em.subtract_chips_from_player(&player1.identifier, 20);
+        texas_hold_em.add_chips_to_main_pot(20).unwrap();
+        texas_hold_em.determine_round_result(
+            &HashMap::new(),
+            &Hand::new(),
+            &HashMap::from([(player2.identifier, vec![pair])]),
+        );
 
-        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+        let statistics = texas_hold_em.statistics();
+        assert_eq!(statistics.total_hands, 3);
+        assert_eq!(statistics.total_chips_in_play, 200);
+        assert_eq!(statistics.average_pot, 20.0);
+        assert_eq!(statistics.hand_type_frequencies.get("Pair"), Some(&2));
+        assert_eq!(statistics.hand_type_frequencies.get("Flush"), Some(&1));
+        assert_eq!(statistics.biggest_pot, 30);
+        assert_eq!(
+            statistics.biggest_pot_winner,
+            Some(String::from("Player 2"))
+        );
 
-        assert_eq!(leading_players.len(), 1);
-        assert!(leading_players.contains_key(&player1.identifier));
+        // Eliminated players are still named in the statistics.
+        texas_hold_em
+            .players
+            .get_mut(&player2.identifier)
+            .unwrap()
+            .chips = 0;
+        texas_hold_em.remove_losers();
+        let statistics = texas_hold_em.statistics();
         assert_eq!(
-            leading_players.get(&player1.identifier).unwrap()[0],
-            two_pair1
+            statistics.biggest_pot_winner,
+            Some(String::from("Player 2"))
         );
+        assert!(statistics
+            .average_profit_loss_per_player
+            .contains_key("Player 2"));
     }
 
-    /// Tests rank_all_hands().
+    /// Tests deal_hands_to_all_players().
     ///
-    /// Tests that a single winner is correctly chosen when the winning hand ranks is on the table
-    /// but one player has a higher kicker (high card) than the other.
+    /// Tests that one card is dealt to each player before any player receives a second card.
     #[test]
-    fn rank_all_hands_identifies_winner_based_on_kicker_with_table_winner() {
-        let mut game = TexasHoldEm::new(100, 10, 1, 3);
+    fn deal_hands_to_all_players_deals_one_card_at_a_time() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+
+        // Cards are dealt from the end of the deck.
+        texas_hold_em.deck = Deck::from_cards(vec![
+            card!(Five, Club),
+            card!(Four, Club),
+            card!(Three, Club),
+            card!(Two, Club),
+        ])
+        .unwrap();
+
+        // Player 1 is the dealer, so Player 2 is dealt to first.
+        let player_hands = texas_hold_em.deal_hands_to_all_players();
+        let player1_ranks: Vec<Rank> = player_hands[&player1.identifier]
+            .get_cards()
+            .iter()
+            .map(|card| card.rank)
+            .collect();
+        let player2_ranks: Vec<Rank> = player_hands[&player2.identifier]
+            .get_cards()
+            .iter()
+            .map(|card| card.rank)
+            .collect();
 
-        let two_of_diamonds = card!(Two, Diamond);
-        let two_of_hearts = card!(Two, Heart);
-        let six_of_diamonds = card!(Six, Diamond);
-        let nine_of_clubs = card!(Nine, Club);
-        let nine_of_hearts = card!(Nine, Heart);
-        let ten_of_hearts = card!(Ten, Heart);
-        let ten_of_spades = card!(Ten, Spade);
-        let jack_of_clubs = card!(Jack, Club);
-        let ace_of_spades = card!(Ace, Spade);
+        assert_eq!(player2_ranks, vec![Rank::Two, Rank::Four]);
+        assert_eq!(player1_ranks, vec![Rank::Three, Rank::Five]);
+    }
 
-        let two_pair1 = HandRank::TwoPair([
-            nine_of_clubs,
-            nine_of_hearts,
-            two_of_diamonds,
-            two_of_hearts,
-        ]);
+    /// Tests deal_hands_to_all_players().
+    ///
+    /// Tests that the deal starts to the left of the dealer's seat even when a seated player cannot be found.
+    #[test]
+    fn deal_hands_to_all_players_starts_left_of_the_dealer_seat() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+
+        // Player 2 is the dealer and Player 1 still has a seat, but is missing from the players.
+        texas_hold_em.dealer_seat_index = 1;
+        texas_hold_em.players.remove(&player1.identifier);
+
+        // Cards are dealt from the end of the deck.
+        texas_hold_em.deck = Deck::from_cards(vec![
+            card!(Five, Club),
+            card!(Four, Club),
+            card!(Three, Club),
+            card!(Two, Club),
+        ])
+        .unwrap();
+
+        let player_hands = texas_hold_em.deal_hands_to_all_players();
+        let player3_ranks: Vec<Rank> = player_hands[&player3.identifier]
+            .get_cards()
+            .iter()
+            .map(|card| card.rank)
+            .collect();
 
-        let table_cards: Vec<Card> = vec![
-            two_of_diamonds,
-            two_of_hearts,
-            nine_of_clubs,
-            nine_of_hearts,
-            jack_of_clubs,
-        ];
+        assert_eq!(player_hands.len(), 2);
+        assert_eq!(player3_ranks, vec![Rank::Two, Rank::Four]);
+    }
 
-        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
-        let table_cards = Hand::new_from_cards(table_cards);
+    /// Tests burn_card().
+    ///
+    /// Tests that burned cards are dealt face down and returned to the deck with reset_deck().
+    #[test]
+    fn burn_card_works() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
 
-        let player1 = game.new_player_with_chips("Player 1", 100);
-        game.add_player(player1.clone()).unwrap();
-        let player1_cards: Vec<Card> = vec![ten_of_spades, ace_of_spades];
-        let player1_hand = Hand::new_from_cards(player1_cards);
-        player_hands.insert(player1.identifier, player1_hand);
+        let burned_card = texas_hold_em.burn_card().unwrap();
+        assert!(!burned_card.face_up);
+        assert_eq!(texas_hold_em.burned_cards.get_cards(), &[burned_card]);
+        assert_eq!(texas_hold_em.deck.len(), 51);
 
-        let player2 = game.new_player_with_chips("Player 2", 100);
-        game.add_player(player2.clone()).unwrap();
-        let player2_cards: Vec<Card> = vec![six_of_diamonds, ten_of_hearts];
-        let player2_hand = Hand::new_from_cards(player2_cards);
-        player_hands.insert(player2.identifier, player2_hand);
+        texas_hold_em.reset_deck(HashMap::new(), Hand::new(), Hand::new());
+        assert_eq!(texas_hold_em.deck.len(), 52);
+        assert!(texas_hold_em.burned_cards.get_cards().is_empty());
+    }
 
-        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+    /// Tests eject_player_for_unsportsmanlike_conduct().
+    ///
+    /// Tests that the ejected player is no longer seated and is returned with their chips.
+    #[test]
+    fn eject_player_for_unsportsmanlike_conduct_works() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 150);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        assert_eq!(texas_hold_em.seats.len(), 2);
+
+        let ejected_player = texas_hold_em
+            .eject_player_for_unsportsmanlike_conduct(player2.identifier)
+            .unwrap();
+        assert_eq!(ejected_player.identifier, player2.identifier);
+        assert_eq!(ejected_player.chips, 150);
+        assert_eq!(texas_hold_em.seats.len(), 1);
+        assert!(!texas_hold_em.seats.contains(&player2.identifier));
+        assert!(!texas_hold_em.players.contains_key(&player2.identifier));
 
-        assert_eq!(leading_players.len(), 1);
-        assert!(leading_players.contains_key(&player1.identifier));
         assert_eq!(
-            leading_players.get(&player1.identifier).unwrap()[0],
-            two_pair1
+            texas_hold_em.eject_player_for_unsportsmanlike_conduct(player2.identifier),
+            Err(EjectError::PlayerNotFound)
         );
     }
 
-    /// Tests rank_all_hands().
+    /// Tests simulate_round_with_seed().
     ///
-    /// Tests that a single winner is correctly chosen.
+    /// Tests that the same seed produces the same result for the same players.
     #[test]
-    fn rank_all_hands_identifies_push_with_winning_table_flush() {
-        let mut game = TexasHoldEm::new(100, 10, 1, 3);
-
-        let two_of_diamonds = card!(Two, Diamond);
-        let two_of_hearts = card!(Two, Heart);
-        let three_of_clubs = card!(Three, Club);
-        let four_of_diamonds = card!(Four, Diamond);
-        let five_of_clubs = card!(Five, Club);
-        let six_of_diamonds = card!(Six, Diamond);
-        let eight_of_spades = card!(Eight, Spade);
-        let nine_of_clubs = card!(Nine, Club);
-        let nine_of_hearts = card!(Nine, Heart);
-        let ten_of_spades = card!(Ten, Spade);
-        let jack_of_clubs = card!(Jack, Club);
-        let queen_of_hearts = card!(Queen, Heart);
-        let king_of_clubs = card!(King, Club);
-        let ace_of_hearts = card!(Ace, Heart);
-        let ace_of_spades = card!(Ace, Spade);
+    fn simulate_round_with_seed_is_deterministic() {
+        let player1 = Player::new_with_chips("Player 1", 100, 0);
+        let player2 = Player::new_with_chips("Player 2", 100, 0);
+        let player3 = Player::new_with_chips("Player 3", 100, 0);
+
+        let mut round_results: Vec<RoundResult> = Vec::new();
+        for _ in 0..2 {
+            let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+            texas_hold_em.add_player(player1.clone()).unwrap();
+            texas_hold_em.add_player(player2.clone()).unwrap();
+            texas_hold_em.add_player(player3.clone()).unwrap();
+
+            round_results.push(texas_hold_em.simulate_round_with_seed(42));
+        }
 
-        let flush = HandRank::Flush([
-            three_of_clubs,
-            five_of_clubs,
-            nine_of_clubs,
-            jack_of_clubs,
-            king_of_clubs,
-        ]);
+        assert_eq!(round_results[0], round_results[1]);
+        assert_eq!(round_results[0].player_hands.len(), 3);
+        assert_eq!(round_results[0].table_cards.get_cards().len(), 5);
+        assert!(!round_results[0].winning_players.is_empty());
+    }
 
-        let table_cards: Vec<Card> = vec![
-            three_of_clubs,
-            five_of_clubs,
-            nine_of_clubs,
-            jack_of_clubs,
-            king_of_clubs,
-        ];
+    /// Tests number_of_active_streets_remaining().
+    ///
+    /// Tests that the streets remaining decrease as each betting round ends.
+    #[test]
+    fn number_of_active_streets_remaining_works() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1).unwrap();
+        texas_hold_em.add_player(player2).unwrap();
+        assert_eq!(texas_hold_em.number_of_active_streets_remaining(), 0);
+
+        texas_hold_em.deal_hands_to_all_players();
+        assert_eq!(texas_hold_em.number_of_active_streets_remaining(), 3);
+
+        for streets_remaining in [2, 1, 0, 0] {
+            texas_hold_em.end_betting_round();
+            assert_eq!(
+                texas_hold_em.number_of_active_streets_remaining(),
+                streets_remaining
+            );
+        }
+    }
 
-        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
-        let table_cards = Hand::new_from_cards(table_cards);
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that every kicker is compared when the players share the pair on the table and the first kicker.
+    /// With 9♥ 9♠ 7♣ 3♦ 2♠ on the table, K♠ Q♣ beats K♦ J♥ on the second kicker.
+    /// With 9♥ 9♠ 7♣ 7♦ 2♠ on the table, both players have Two Pair and only the King plays, so they push.
+    #[test]
+    fn rank_all_hands_compares_every_kicker_with_table_pair() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
 
         let player1 = game.new_player_with_chips("Player 1", 100);
         game.add_player(player1.clone()).unwrap();
-        let player1_cards: Vec<Card> = vec![two_of_diamonds, eight_of_spades];
-        let player1_hand = Hand::new_from_cards(player1_cards);
-        player_hands.insert(player1.identifier, player1_hand);
-
         let player2 = game.new_player_with_chips("Player 2", 100);
         game.add_player(player2.clone()).unwrap();
-        let player2_cards: Vec<Card> = vec![two_of_hearts, ten_of_spades];
-        let player2_hand = Hand::new_from_cards(player2_cards);
-        player_hands.insert(player2.identifier, player2_hand);
 
-        let player3 = game.new_player_with_chips("Player 3", 100);
-        game.add_player(player3.clone()).unwrap();
-        let player3_cards: Vec<Card> = vec![four_of_diamonds, queen_of_hearts];
-        let player3_hand = Hand::new_from_cards(player3_cards);
-        player_hands.insert(player3.identifier, player3_hand);
+        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
+        player_hands.insert(
+            player1.identifier,
+            Hand::new_from_cards(vec![card!(King, Spade), card!(Queen, Club)]),
+        );
+        player_hands.insert(
+            player2.identifier,
+            Hand::new_from_cards(vec![card!(King, Diamond), card!(Jack, Heart)]),
+        );
 
-        let player4 = game.new_player_with_chips("Player 4", 100);
-        game.add_player(player4.clone()).unwrap();
-        let player4_cards: Vec<Card> = vec![ace_of_hearts, ace_of_spades];
-        let player4_hand = Hand::new_from_cards(player4_cards);
-        player_hands.insert(player4.identifier, player4_hand);
+        let table_cards = Hand::new_from_cards(vec![
+            card!(Nine, Heart),
+            card!(Nine, Spade),
+            card!(Seven, Club),
+            card!(Three, Diamond),
+            card!(Two, Spade),
+        ]);
+        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
 
-        let player5 = game.new_player_with_chips("Player 5", 100);
-        game.add_player(player5.clone()).unwrap();
-        let player5_cards: Vec<Card> = vec![six_of_diamonds, nine_of_hearts];
-        let player5_hand = Hand::new_from_cards(player5_cards);
-        player_hands.insert(player5.identifier, player5_hand);
+        assert_eq!(leading_players.len(), 1);
+        assert!(leading_players.contains_key(&player1.identifier));
+        assert_eq!(
+            leading_players.get(&player1.identifier).unwrap()[0],
+            HandRank::Pair([card!(Nine, Heart), card!(Nine, Spade)])
+        );
 
+        let table_cards = Hand::new_from_cards(vec![
+            card!(Nine, Heart),
+            card!(Nine, Spade),
+            card!(Seven, Club),
+            card!(Seven, Diamond),
+            card!(Two, Spade),
+        ]);
         let leading_players = game.rank_all_hands(&player_hands, &table_cards);
 
-        assert_eq!(leading_players.len(), 5);
-        assert_eq!(leading_players.get(&player1.identifier).unwrap()[0], flush);
-        assert_eq!(leading_players.get(&player2.identifier).unwrap()[0], flush);
-        assert_eq!(leading_players.get(&player3.identifier).unwrap()[0], flush);
-        assert_eq!(leading_players.get(&player4.identifier).unwrap()[0], flush);
-        assert_eq!(leading_players.get(&player5.identifier).unwrap()[0], flush);
+        assert_eq!(leading_players.len(), 2);
     }
 
-    /// Tests rank_all_hands().
+    /// Tests close_betting().
     ///
-    /// Tests that a single winner is correctly chosen.
+    /// Tests that raises are rejected once betting is closed and allowed again on the next street.
     #[test]
-    fn rank_all_hands_identifies_higher_flush_in_hand_wins() {
-        let mut game = TexasHoldEm::new(100, 10, 1, 3);
-
-        let two_of_diamonds = card!(Two, Diamond);
-        let two_of_hearts = card!(Two, Heart);
-        let three_of_clubs = card!(Three, Club);
-        let four_of_diamonds = card!(Four, Diamond);
-        let five_of_clubs = card!(Five, Club);
-        let six_of_diamonds = card!(Six, Diamond);
-        let seven_of_clubs = card!(Seven, Club);
-        let eight_of_spades = card!(Eight, Spade);
-        let nine_of_clubs = card!(Nine, Club);
-        let nine_of_hearts = card!(Nine, Heart);
-        let jack_of_clubs = card!(Jack, Club);
-        let queen_of_hearts = card!(Queen, Heart);
-        let king_of_clubs = card!(King, Club);
-        let ace_of_hearts = card!(Ace, Heart);
-        let ace_of_spades = card!(Ace, Spade);
-
-        let flush1 = HandRank::Flush([
-            three_of_clubs,
-            five_of_clubs,
-            nine_of_clubs,
-            jack_of_clubs,
-            king_of_clubs,
-        ]);
+    fn close_betting_rejects_raises() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1).unwrap();
+        texas_hold_em.add_player(player2).unwrap();
+
+        texas_hold_em.deal_hands_to_all_players();
+        assert!(!texas_hold_em.is_betting_open());
+        texas_hold_em.post_blind(true);
+        texas_hold_em.post_blind(false);
+        assert!(texas_hold_em.is_betting_open());
+
+        texas_hold_em.start_betting_round(texas_hold_em.get_under_the_gun_seat_index());
+        texas_hold_em.close_betting();
+        let player_identifier = texas_hold_em.next_to_act().unwrap();
+        assert!(texas_hold_em
+            .apply_player_action(player_identifier, &PlayerAction::Raise(10))
+            .is_err());
+        assert!(texas_hold_em
+            .apply_player_action(player_identifier, &PlayerAction::Call())
+            .is_ok());
+
+        texas_hold_em.end_betting_round();
+        assert!(!texas_hold_em.is_betting_open());
+        texas_hold_em.start_betting_round(texas_hold_em.get_small_blind_seat_index());
+        let player_identifier = texas_hold_em.next_to_act().unwrap();
+        assert!(texas_hold_em
+            .apply_player_action(player_identifier, &PlayerAction::Raise(10))
+            .is_ok());
+        assert!(texas_hold_em.is_betting_open());
+    }
 
-        let flush2 = HandRank::Flush([
-            three_of_clubs,
-            seven_of_clubs,
-            nine_of_clubs,
-            jack_of_clubs,
-            king_of_clubs,
-        ]);
+    /// Tests play_hand_with_all_checks().
+    ///
+    /// Tests that the players only check or call the big blind and that the pot is paid out to the winners.
+    #[test]
+    fn play_hand_with_all_checks_works() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        for index in 0..3 {
+            let player = texas_hold_em.new_player_with_chips(&format!("Player {}", index), 100);
+            texas_hold_em.add_player(player).unwrap();
+        }
 
-        let table_cards: Vec<Card> = vec![
-            three_of_clubs,
-            two_of_diamonds,
-            nine_of_clubs,
-            jack_of_clubs,
-            king_of_clubs,
-        ];
+        let round_result = texas_hold_em.play_hand_with_all_checks();
 
-        let winning_flush = HandRank::Flush([
-            three_of_clubs,
-            seven_of_clubs,
-            nine_of_clubs,
-            jack_of_clubs,
-            king_of_clubs,
-        ]);
+        assert_eq!(round_result.pot_amount, 6);
+        assert!(!round_result.winning_players.is_empty());
+        assert!(texas_hold_em
+            .player_action_history()
+            .iter()
+            .all(|action_record| matches!(
+                action_record.action,
+                PlayerAction::Check() | PlayerAction::Call()
+            )));
+        assert_eq!(
+            texas_hold_em
+                .players
+                .values()
+                .map(|player| player.chips)
+                .sum::<u32>(),
+            300
+        );
+    }
 
-        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
-        let table_cards = Hand::new_from_cards(table_cards);
+    /// Tests deal_n_community_cards().
+    ///
+    /// Tests that a card is burned before the community cards are dealt and invalid requests deal nothing.
+    #[test]
+    fn deal_n_community_cards_works() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
 
-        let player1 = game.new_player_with_chips("Player 1", 100);
-        game.add_player(player1.clone()).unwrap();
-        let player1_cards: Vec<Card> = vec![five_of_clubs, eight_of_spades];
-        let player1_hand = Hand::new_from_cards(player1_cards);
-        player_hands.insert(player1.identifier, player1_hand);
+        let community_cards = texas_hold_em.deal_n_community_cards(3);
+        assert_eq!(community_cards.len(), 3);
+        assert!(community_cards.iter().all(|card| card.face_up));
+        assert_eq!(texas_hold_em.burned_cards.get_cards().len(), 1);
+        assert_eq!(texas_hold_em.deck.len(), 48);
 
-        let player2 = game.new_player_with_chips("Player 2", 100);
-        game.add_player(player2.clone()).unwrap();
-        let player2_cards: Vec<Card> = vec![two_of_hearts, seven_of_clubs];
-        let player2_hand = Hand::new_from_cards(player2_cards);
-        player_hands.insert(player2.identifier, player2_hand);
+        assert!(texas_hold_em.deal_n_community_cards(0).is_empty());
+        assert!(texas_hold_em.deal_n_community_cards(48).is_empty());
+        assert_eq!(texas_hold_em.deck.len(), 48);
 
-        let player3 = game.new_player_with_chips("Player 3", 100);
-        game.add_player(player3.clone()).unwrap();
-        let player3_cards: Vec<Card> = vec![four_of_diamonds, queen_of_hearts];
-        let player3_hand = Hand::new_from_cards(player3_cards);
-        player_hands.insert(player3.identifier, player3_hand);
+        assert_eq!(texas_hold_em.deal_turn().len(), 1);
+        assert_eq!(texas_hold_em.deck.len(), 46);
+    }
 
-        let player4 = game.new_player_with_chips("Player 4", 100);
-        game.add_player(player4.clone()).unwrap();
-        let player4_cards: Vec<Card> = vec![ace_of_hearts, ace_of_spades];
-        let player4_hand = Hand::new_from_cards(player4_cards);
-        player_hands.insert(player4.identifier, player4_hand);
+    /// Tests determine_round_result().
+    ///
+    /// Tests that the result records the chips each winner received without needing to be printed.
+    #[test]
+    fn determine_round_result_returns_chips_won() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_chips_to_main_pot(9).unwrap();
+
+        let pair = HandRank::Pair([card!(King, Club), card!(King, Heart)]);
+        let winning_players = HashMap::from([
+            (player1.identifier, vec![pair]),
+            (player2.identifier, vec![pair]),
+        ]);
+        let round_result =
+            texas_hold_em.determine_round_result(&HashMap::new(), &Hand::new(), &winning_players);
+
+        assert_eq!(round_result.pot_amount, 9);
+        assert_eq!(round_result.winning_players, winning_players);
+        assert_eq!(round_result.chips_won[&player1.identifier], 4);
+        assert_eq!(round_result.chips_won[&player2.identifier], 5);
+        texas_hold_em.print_round_summary(&round_result);
+    }
 
-        let player5 = game.new_player_with_chips("Player 5", 100);
-        game.add_player(player5.clone()).unwrap();
-        let player5_cards: Vec<Card> = vec![six_of_diamonds, nine_of_hearts];
-        let player5_hand = Hand::new_from_cards(player5_cards);
-        player_hands.insert(player5.identifier, player5_hand);
+    /// Tests determine_round_result().
+    ///
+    /// Tests that when three players tie, the remainder chip goes to the player immediately left of the dealer.
+    #[test]
+    fn determine_round_result_awards_remainder_to_left_of_dealer() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+        // Player 2 is the dealer, so Player 3 is immediately left of the dealer.
+        texas_hold_em.rotate_dealer().unwrap();
+        texas_hold_em.add_chips_to_main_pot(10).unwrap();
+
+        let pair = HandRank::Pair([card!(King, Club), card!(King, Heart)]);
+        let winning_players = HashMap::from([
+            (player1.identifier, vec![pair]),
+            (player2.identifier, vec![pair]),
+            (player3.identifier, vec![pair]),
+        ]);
+        let round_result =
+            texas_hold_em.determine_round_result(&HashMap::new(), &Hand::new(), &winning_players);
 
-        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+        assert_eq!(round_result.chips_won[&player1.identifier], 3);
+        assert_eq!(round_result.chips_won[&player2.identifier], 3);
+        assert_eq!(round_result.chips_won[&player3.identifier], 4);
+        assert_eq!(texas_hold_em.players[&player3.identifier].chips, 104);
+    }
 
-        assert_ne!(flush1, flush2);
-        assert_eq!(winning_flush, flush2);
-        assert_eq!(leading_players.len(), 1);
-        assert!(!leading_players.contains_key(&player1.identifier));
-        assert!(leading_players.contains_key(&player2.identifier));
-        assert_eq!(flush2, leading_players.get(&player2.identifier).unwrap()[0]);
+    /// Tests play_n_tournaments().
+    ///
+    /// Tests that every tournament starts from the same players and chips.
+    #[test]
+    fn play_n_tournaments_works() {
+        let mut texas_hold_em = TexasHoldEm::new(200, 10, 5, 10).unwrap();
+        // Allow players with fewer chips than the minimum buy-in to join the table.
+        texas_hold_em.minimum_chips_buy_in_amount = 1;
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 20);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 20);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+
+        let all_statistics = texas_hold_em.play_n_tournaments(3);
+
+        assert_eq!(all_statistics.len(), 3);
+        for statistics in all_statistics.iter() {
+            assert!(statistics.total_hands > 0);
+            assert_eq!(statistics.total_chips_in_play, 40);
+        }
+
+        texas_hold_em.reset_for_new_session(vec![player1.clone(), player2.clone()]);
         assert_eq!(
-            winning_flush,
-            leading_players.get(&player2.identifier).unwrap()[0]
+            texas_hold_em.seats,
+            vec![player1.identifier, player2.identifier]
         );
+        assert_eq!(texas_hold_em.players[&player1.identifier].chips, 20);
+        assert!(texas_hold_em.hand_history().is_empty());
     }
 
-    /// Tests rank_all_hands().
+    /// Tests reset_for_new_session().
     ///
-    /// Tests that all players push when the winning hand is on the table.
+    /// Tests that CPU players keep their strategies in the next session, even after being eliminated.
     #[test]
-    fn rank_all_hands_identifies_push_with_winning_table_straight() {
-        let mut game = TexasHoldEm::new(100, 10, 1, 3);
+    fn reset_for_new_session_keeps_cpu_strategies() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let cpu_player = texas_hold_em.new_player_with_chips("CPU 1", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em
+            .add_cpu_player(
+                cpu_player.clone(),
+                Box::new(ConservativeStrategy::default()),
+            )
+            .unwrap();
 
-        let two_of_diamonds = card!(Two, Diamond);
-        let three_of_clubs = card!(Three, Club);
-        let four_of_hearts = card!(Four, Heart);
-        let five_of_diamonds = card!(Five, Diamond);
-        let six_of_clubs = card!(Six, Club);
-        let seven_of_spades = card!(Seven, Spade);
-        let five_of_clubs = card!(Five, Club);
-        let nine_of_spades = card!(Nine, Spade);
-        let ten_of_diamonds = card!(Ten, Diamond);
-        let jack_of_clubs = card!(Jack, Club);
-        let jack_of_hearts = card!(Jack, Heart);
-        let jack_of_spades = card!(Jack, Spade);
-        let queen_of_spades = card!(Queen, Spade);
-        let king_of_diamonds = card!(King, Diamond);
-        let ace_of_hearts = card!(Ace, Heart);
+        texas_hold_em
+            .players
+            .get_mut(&cpu_player.identifier)
+            .unwrap()
+            .chips = 0;
+        texas_hold_em.remove_losers();
 
-        let straight = HandRank::Straight([
-            ten_of_diamonds,
-            jack_of_clubs,
-            queen_of_spades,
-            king_of_diamonds,
-            ace_of_hearts,
-        ]);
+        texas_hold_em.reset_for_new_session(vec![player1.clone(), cpu_player.clone()]);
+        assert!(texas_hold_em.is_cpu_player(&cpu_player.identifier));
+        assert!(!texas_hold_em.is_cpu_player(&player1.identifier));
 
-        let table_cards: Vec<Card> = vec![
-            ten_of_diamonds,
-            jack_of_clubs,
-            queen_of_spades,
-            king_of_diamonds,
-            ace_of_hearts,
-        ];
+        texas_hold_em.reset_for_new_session(vec![player1.clone()]);
+        assert!(!texas_hold_em.is_cpu_player(&cpu_player.identifier));
+    }
 
-        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
-        let table_cards = Hand::new_from_cards(table_cards);
+    /// Tests flag_short_stacked_players().
+    ///
+    /// Tests that players without chips are not dealt a hand and short-stacked players are flagged as all in.
+    #[test]
+    fn flag_short_stacked_players_works() {
+        let mut texas_hold_em = TexasHoldEm::new(200, 10, 5, 10).unwrap();
+        // Allow players with fewer chips than the minimum buy-in to join the table.
+        texas_hold_em.minimum_chips_buy_in_amount = 1;
+        assert_eq!(texas_hold_em.minimum_chips_to_play(), 10);
+
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 5);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 1);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+        texas_hold_em.subtract_chips_from_player(&player3.identifier, 1);
+
+        texas_hold_em.flag_short_stacked_players();
+        let player_hands = texas_hold_em.deal_hands_to_all_players();
+
+        assert!(!texas_hold_em.players[&player1.identifier].is_all_in());
+        assert!(texas_hold_em.players[&player2.identifier].is_all_in());
+        assert!(player_hands.contains_key(&player1.identifier));
+        assert!(player_hands.contains_key(&player2.identifier));
+        assert!(!player_hands.contains_key(&player3.identifier));
+    }
 
-        let player1 = game.new_player_with_chips("Player 1", 100);
-        game.add_player(player1.clone()).unwrap();
-        let player1_cards: Vec<Card> = vec![three_of_clubs, four_of_hearts];
-        let player1_hand = Hand::new_from_cards(player1_cards);
-        player_hands.insert(player1.identifier, player1_hand);
+    /// Tests create_main_pot_from_blinds().
+    ///
+    /// Tests that posting both blinds returns the sum of the small blind and the big blind.
+    #[test]
+    fn create_main_pot_from_blinds_works() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 2, 5).unwrap();
+
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player1).unwrap();
+        texas_hold_em.add_player(player2).unwrap();
+        texas_hold_em.add_player(player3).unwrap();
+
+        assert_eq!(texas_hold_em.create_main_pot_from_blinds(), 7);
+        assert_eq!(texas_hold_em.get_main_pot_amount(), 7);
+    }
+
+    /// Tests seats_in_deal_order().
+    ///
+    /// Tests that the deal order starts to the left of the dealer and ends with the dealer.
+    #[test]
+    fn seats_in_deal_order_ends_with_dealer() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        assert!(texas_hold_em.seats_in_deal_order().is_empty());
+
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+
+        texas_hold_em.rotate_dealer().unwrap();
+        assert_eq!(
+            texas_hold_em.seats_in_deal_order(),
+            vec![player3.identifier, player1.identifier, player2.identifier]
+        );
+    }
 
+    /// Tests get_player_rank_in_hand().
+    ///
+    /// Tests that the ranks from the showdown are recorded and cleared when the next hand is dealt.
+    #[test]
+    fn get_player_rank_in_hand_works() {
+        let mut game = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = game.new_player_with_chips("Player 1", 100);
         let player2 = game.new_player_with_chips("Player 2", 100);
+        game.add_player(player1.clone()).unwrap();
         game.add_player(player2.clone()).unwrap();
-        let player2_cards: Vec<Card> = vec![five_of_diamonds, six_of_clubs];
-        let player2_hand = Hand::new_from_cards(player2_cards);
-        player_hands.insert(player2.identifier, player2_hand);
 
-        let player3 = game.new_player_with_chips("Player 3", 100);
-        game.add_player(player3.clone()).unwrap();
-        let player3_cards: Vec<Card> = vec![seven_of_spades, nine_of_spades];
-        let player3_hand = Hand::new_from_cards(player3_cards);
-        player_hands.insert(player3.identifier, player3_hand);
+        let table_cards = Hand::new_from_cards(vec![
+            card!(Two, Club),
+            card!(Seven, Diamond),
+            card!(Nine, Heart),
+            card!(Jack, Spade),
+            card!(Four, Heart),
+        ]);
+        let player_hands = HashMap::from([
+            (
+                player1.identifier,
+                Hand::new_from_cards(vec![card!(Ace, Heart), card!(Ace, Spade)]),
+            ),
+            (
+                player2.identifier,
+                Hand::new_from_cards(vec![card!(King, Heart), card!(Queen, Club)]),
+            ),
+        ]);
+        assert_eq!(game.get_player_rank_in_hand(player1.identifier), None);
 
-        let player4 = game.new_player_with_chips("Player 4", 100);
-        game.add_player(player4.clone()).unwrap();
-        let player4_cards: Vec<Card> = vec![two_of_diamonds, five_of_clubs];
-        let player4_hand = Hand::new_from_cards(player4_cards);
-        player_hands.insert(player4.identifier, player4_hand);
+        game.rank_all_hands(&player_hands, &table_cards);
+        assert_eq!(
+            game.get_player_rank_in_hand(player1.identifier),
+            Some(HandRank::Pair([card!(Ace, Heart), card!(Ace, Spade)]))
+        );
+        assert_eq!(
+            game.get_player_rank_in_hand(player2.identifier),
+            Some(HandRank::HighCard(card!(King, Heart)))
+        );
 
-        let player5 = game.new_player_with_chips("Player 5", 100);
-        game.add_player(player5.clone()).unwrap();
-        let player5_cards: Vec<Card> = vec![jack_of_hearts, jack_of_spades];
-        let player5_hand = Hand::new_from_cards(player5_cards);
-        player_hands.insert(player5.identifier, player5_hand);
+        game.deal_hands_to_all_players();
+        assert_eq!(game.get_player_rank_in_hand(player1.identifier), None);
+    }
 
-        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+    /// Tests rotate_dealer_by().
+    ///
+    /// Tests that rotating the dealer by the number of seats returns the button to the same player.
+    #[test]
+    fn rotate_dealer_by_number_of_seats_is_a_no_op() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        texas_hold_em.rotate_dealer_by(3);
+        assert_eq!(texas_hold_em.dealer_seat_index, 0);
+
+        for name in ["Player 1", "Player 2", "Player 3"] {
+            let player = texas_hold_em.new_player_with_chips(name, 100);
+            texas_hold_em.add_player(player).unwrap();
+        }
+
+        texas_hold_em.rotate_dealer_by(2);
+        assert_eq!(texas_hold_em.dealer_seat_index, 2);
+
+        texas_hold_em.rotate_dealer_by(texas_hold_em.seats.len());
+        assert_eq!(texas_hold_em.dealer_seat_index, 2);
+    }
+
+    /// Tests print_table_state().
+    ///
+    /// Tests that the table state shows the street, face up table cards, pot, and each player's status.
+    #[test]
+    fn format_table_state_shows_players_and_pot() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        // Allow players with fewer chips than the minimum buy-in to join the table.
+        texas_hold_em.minimum_chips_buy_in_amount = 1;
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 1);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 50);
+        texas_hold_em.add_player(player1).unwrap();
+        texas_hold_em.add_player(player2).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+
+        texas_hold_em.flag_short_stacked_players();
+        texas_hold_em
+            .players
+            .get_mut(&player3.identifier)
+            .unwrap()
+            .fold();
+        texas_hold_em.add_chips_to_main_pot(30).unwrap();
+
+        let table_cards = Hand::new_from_cards(vec![card!(Ace, Spade), card!(King, Heart).flip()]);
 
-        assert_eq!(leading_players.len(), 5);
         assert_eq!(
-            leading_players.get(&player1.identifier).unwrap()[0],
-            straight
+            texas_hold_em.format_table_state(&table_cards),
+            format!(
+                "Street: Pre-flop\nTable cards: {}\nPot: 30\nPlayer 1 [100 chips] active\nPlayer 2 [1 chip] all-in\nPlayer 3 [50 chips] folded\n",
+                Hand::new_from_cards(vec![card!(Ace, Spade)]).to_symbols()
+            )
         );
+    }
+
+    /// Tests validate_seat_integrity().
+    ///
+    /// Tests that seats and players that do not match are reported.
+    #[test]
+    fn validate_seat_integrity_detects_mismatches() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        assert_eq!(texas_hold_em.validate_seat_integrity(), Ok(()));
+
+        texas_hold_em.seats.push(player1.identifier);
         assert_eq!(
-            leading_players.get(&player2.identifier).unwrap()[0],
-            straight
+            texas_hold_em.validate_seat_integrity(),
+            Err(IntegrityError::DuplicateSeat(player1.identifier))
         );
+
+        texas_hold_em.seats = vec![player1.identifier];
         assert_eq!(
-            leading_players.get(&player3.identifier).unwrap()[0],
-            straight
+            texas_hold_em.validate_seat_integrity(),
+            Err(IntegrityError::PlayerWithoutSeat(player2.identifier))
         );
+
+        texas_hold_em.players.remove(&player1.identifier);
         assert_eq!(
-            leading_players.get(&player4.identifier).unwrap()[0],
-            straight
+            texas_hold_em.validate_seat_integrity(),
+            Err(IntegrityError::SeatWithoutPlayer(player1.identifier))
+        );
+    }
+
+    /// Tests auto_post_blinds_for_round().
+    ///
+    /// Tests that the small blind is dead and the next player posts the big blind when the previous big blind was eliminated.
+    #[test]
+    fn auto_post_blinds_for_round_handles_eliminated_big_blind() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        let player4 = texas_hold_em.new_player_with_chips("Player 4", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+        texas_hold_em.add_player(player4.clone()).unwrap();
+
+        // Player 2 is the dealer, Player 3 posts the small blind, and Player 4 posts the big blind.
+        let round_result = texas_hold_em.simulate_round();
+        assert_eq!(round_result.pot_amount, 3);
+        assert_eq!(texas_hold_em.big_blind_identifier, Some(player4.identifier));
+
+        // Player 4 is eliminated, so Player 1 would skip the big blind if they posted the small blind.
+        texas_hold_em
+            .players
+            .get_mut(&player4.identifier)
+            .unwrap()
+            .chips = 0;
+        texas_hold_em.remove_losers();
+        assert_eq!(texas_hold_em.seats.len(), 3);
+
+        let round_result = texas_hold_em.simulate_round();
+        assert_eq!(
+            texas_hold_em.seats[texas_hold_em.dealer_seat_index],
+            player3.identifier
         );
+        assert_eq!(round_result.pot_amount, 2);
+        assert_eq!(texas_hold_em.big_blind_identifier, Some(player1.identifier));
         assert_eq!(
-            leading_players.get(&player5.identifier).unwrap()[0],
-            straight
+            texas_hold_em.get_first_to_act_before_the_flop_seat_index(),
+            1
         );
+
+        // Both blinds are posted again once the big blind has moved on.
+        let round_result = texas_hold_em.simulate_round();
+        assert_eq!(round_result.pot_amount, 3);
+        assert_eq!(texas_hold_em.big_blind_identifier, Some(player2.identifier));
     }
 
-    /// Tests rank_all_hands().
+    /// Tests player_profit_loss_for_round().
     ///
-    /// Tests that multiple equal hands result in a push for all involved players.
+    /// Tests that the chips gained or lost since the start of the round are tracked for each player.
     #[test]
-    fn rank_all_hands_identifies_push_with_equal_winning_hand_straights() {
-        let mut game = TexasHoldEm::new(100, 10, 1, 3);
+    fn player_profit_loss_for_round_works() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        assert_eq!(
+            texas_hold_em.player_profit_loss_for_round(Uuid::new_v4()),
+            None
+        );
 
-        let two_of_diamonds = card!(Two, Diamond);
-        let three_of_clubs = card!(Three, Club);
-        let four_of_hearts = card!(Four, Heart);
-        let five_of_clubs = card!(Five, Club);
-        let five_of_diamonds = card!(Five, Diamond);
-        let seven_of_spades = card!(Seven, Spade);
-        let nine_of_spades = card!(Nine, Spade);
-        let ten_of_diamonds = card!(Ten, Diamond);
-        let ten_of_hearts = card!(Ten, Heart);
-        let jack_of_clubs = card!(Jack, Club);
-        let jack_of_hearts = card!(Jack, Heart);
-        let jack_of_spades = card!(Jack, Spade);
-        let queen_of_spades = card!(Queen, Spade);
-        let king_of_diamonds = card!(King, Diamond);
-        let ace_of_hearts = card!(Ace, Heart);
+        for _ in 0..2 {
+            texas_hold_em.reset_player_round_state();
+            texas_hold_em.subtract_chips_from_player(&player2.identifier, 10);
+            texas_hold_em.add_chips_to_main_pot(10).unwrap();
+            texas_hold_em.determine_round_result(
+                &HashMap::new(),
+                &Hand::new(),
+                &HashMap::from([(player1.identifier, Vec::new())]),
+            );
 
-        let straight1 = HandRank::Straight([
-            ten_of_diamonds,
-            jack_of_clubs,
-            queen_of_spades,
-            king_of_diamonds,
-            ace_of_hearts,
-        ]);
+            assert_eq!(
+                texas_hold_em.player_profit_loss_for_round(player1.identifier),
+                Some(10)
+            );
+            assert_eq!(
+                texas_hold_em.player_profit_loss_for_round(player2.identifier),
+                Some(-10)
+            );
+        }
 
-        let straight2 = HandRank::Straight([
-            ten_of_hearts,
-            jack_of_clubs,
-            queen_of_spades,
-            king_of_diamonds,
-            ace_of_hearts,
+        let statistics = texas_hold_em.statistics();
+        assert_eq!(
+            statistics.average_profit_loss_per_player,
+            HashMap::from([
+                (String::from("Player 1"), 10.0),
+                (String::from("Player 2"), -10.0)
+            ])
+        );
+    }
+
+    /// Tests count_hands_won_by_rank() and most_common_winning_rank().
+    ///
+    /// Tests that winning hands are counted by their kind rather than their cards.
+    #[test]
+    fn count_hands_won_by_rank_works() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        assert_eq!(texas_hold_em.most_common_winning_rank(), None);
+
+        let pair_of_kings = HandRank::Pair([card!(King, Club), card!(King, Heart)]);
+        let pair_of_twos = HandRank::Pair([card!(Two, Club), card!(Two, Heart)]);
+        let flush = HandRank::Flush([
+            card!(Two, Spade),
+            card!(Five, Spade),
+            card!(Nine, Spade),
+            card!(Jack, Spade),
+            card!(Ace, Spade),
         ]);
 
-        let table_cards: Vec<Card> = vec![
-            three_of_clubs,
-            jack_of_clubs,
-            queen_of_spades,
-            king_of_diamonds,
-            ace_of_hearts,
+        for hand_rank in [pair_of_kings, flush, pair_of_twos] {
+            texas_hold_em.determine_round_result(
+                &HashMap::new(),
+                &Hand::new(),
+                &HashMap::from([(player1.identifier, vec![hand_rank])]),
+            );
+        }
+
+        assert_eq!(texas_hold_em.count_hands_won_by_rank(&pair_of_twos), 2);
+        assert_eq!(texas_hold_em.count_hands_won_by_rank(&flush), 1);
+        assert_eq!(
+            texas_hold_em.count_hands_won_by_rank(&HandRank::HighCard(card!(Ace, Club))),
+            0
+        );
+        assert_eq!(
+            texas_hold_em.most_common_winning_rank(),
+            Some(pair_of_kings)
+        );
+    }
+
+    /// Tests format_hand_as_string().
+    ///
+    /// Tests that a dealt hand is formatted in text notation that parses back into the same cards.
+    #[test]
+    fn format_hand_as_string_round_trips() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        assert_eq!(
+            texas_hold_em.format_hand_as_string(player1.identifier),
+            None
+        );
+
+        // Player 2 is dealt to first, so Player 1 receives the second and fourth cards.
+        texas_hold_em.deck = Deck::from_cards(vec![
+            card!(Queen, Heart),
+            card!(Two, Club),
+            card!(King, Heart),
+            card!(Three, Club),
+        ])
+        .unwrap();
+        let player_hands = texas_hold_em.deal_hands_to_all_players();
+
+        let formatted_hand = texas_hold_em
+            .format_hand_as_string(player1.identifier)
+            .unwrap();
+        assert_eq!(formatted_hand, "Kh Qh");
+
+        let parsed_hand: Hand = formatted_hand.parse().unwrap();
+        let dealt_cards: Vec<(Rank, Suit)> = player_hands[&player1.identifier]
+            .cards
+            .iter()
+            .map(|card| (card.rank, card.suit))
+            .collect();
+        let parsed_cards: Vec<(Rank, Suit)> = parsed_hand
+            .cards
+            .iter()
+            .map(|card| (card.rank, card.suit))
+            .collect();
+        assert_eq!(parsed_cards, dealt_cards);
+        assert_eq!(texas_hold_em.format_hand_as_string(Uuid::new_v4()), None);
+    }
+
+    /// Tests detect_collusion().
+    ///
+    /// Tests that a pair of players who fold whenever the other raises is detected while other pairs are not.
+    #[test]
+    fn detect_collusion_finds_correlated_pairs() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let mut player_identifiers = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+        player_identifiers.sort();
+        let [colluder1, colluder2, honest] = player_identifiers;
+
+        let hands = [
+            [
+                (colluder1, PlayerAction::Raise(10)),
+                (colluder2, PlayerAction::Fold()),
+                (honest, PlayerAction::Call()),
+            ],
+            [
+                (colluder2, PlayerAction::Raise(10)),
+                (colluder1, PlayerAction::Fold()),
+                (honest, PlayerAction::Call()),
+            ],
+            [
+                (honest, PlayerAction::Raise(10)),
+                (colluder1, PlayerAction::Call()),
+                (colluder2, PlayerAction::Call()),
+            ],
+            [
+                (colluder1, PlayerAction::Check()),
+                (colluder2, PlayerAction::Check()),
+                (honest, PlayerAction::Check()),
+            ],
         ];
+        for hand in hands {
+            texas_hold_em.hand_history.push(HandResult {
+                pot_amount: 0,
+                winner_identifiers: Vec::new(),
+                winning_hand_rank: None,
+                profit_loss: HashMap::new(),
+                starting_stacks: Vec::new(),
+                actions: hand
+                    .into_iter()
+                    .map(|(player_identifier, action)| ActionRecord {
+                        player_identifier,
+                        street: Street::PreFlop,
+                        action,
+                        pot_before: 0,
+                        pot_after: 0,
+                    })
+                    .collect(),
+            });
+        }
 
-        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
-        let table_cards = Hand::new_from_cards(table_cards);
+        assert_eq!(
+            texas_hold_em.detect_collusion(0.8),
+            vec![(colluder1, colluder2)]
+        );
+        assert!(TexasHoldEm::new(40, 10, 1, 2)
+            .unwrap()
+            .detect_collusion(0.8)
+            .is_empty());
+    }
 
-        let player1 = game.new_player_with_chips("Player 1", 100);
-        game.add_player(player1.clone()).unwrap();
-        let player1_cards: Vec<Card> = vec![four_of_hearts, ten_of_diamonds];
-        let player1_hand = Hand::new_from_cards(player1_cards);
-        player_hands.insert(player1.identifier, player1_hand);
+    /// Tests simulate_n_rounds().
+    ///
+    /// Tests that the provided number of rounds are played unless the game is already over.
+    #[test]
+    fn simulate_n_rounds_collects_each_result() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        texas_hold_em.add_player(player1).unwrap();
+        assert!(texas_hold_em.simulate_n_rounds(3).is_empty());
+
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player2).unwrap();
+        texas_hold_em.add_player(player3).unwrap();
+        texas_hold_em.game_over = false;
+
+        let round_results = texas_hold_em.simulate_n_rounds(3);
+        assert_eq!(round_results.len(), 3);
+        assert_eq!(texas_hold_em.hand_history().len(), 3);
+        for round_result in round_results {
+            assert_eq!(round_result.pot_amount, 3);
+        }
+    }
 
-        let player2 = game.new_player_with_chips("Player 2", 100);
-        game.add_player(player2.clone()).unwrap();
-        let player2_cards: Vec<Card> = vec![five_of_diamonds, ten_of_hearts];
-        let player2_hand = Hand::new_from_cards(player2_cards);
-        player_hands.insert(player2.identifier, player2_hand);
+    /// Tests run_heads_up_final().
+    ///
+    /// Tests that the final is played until one player has all of the chips and the other players are returned to their seats.
+    #[test]
+    fn run_heads_up_final_works() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        // Allow players with fewer chips than the minimum buy-in to join the table.
+        texas_hold_em.minimum_chips_buy_in_amount = 1;
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 10);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 50);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 10);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+        let original_seats = texas_hold_em.seats.clone();
 
-        let player3 = game.new_player_with_chips("Player 3", 100);
-        game.add_player(player3.clone()).unwrap();
-        let player3_cards: Vec<Card> = vec![seven_of_spades, nine_of_spades];
-        let player3_hand = Hand::new_from_cards(player3_cards);
-        player_hands.insert(player3.identifier, player3_hand);
+        assert_eq!(
+            texas_hold_em.run_heads_up_final(player1.identifier, player1.identifier),
+            None
+        );
 
-        let player4 = game.new_player_with_chips("Player 4", 100);
-        game.add_player(player4.clone()).unwrap();
-        let player4_cards: Vec<Card> = vec![two_of_diamonds, five_of_clubs];
-        let player4_hand = Hand::new_from_cards(player4_cards);
-        player_hands.insert(player4.identifier, player4_hand);
+        let winner = texas_hold_em
+            .run_heads_up_final(player1.identifier, player3.identifier)
+            .unwrap();
+        let loser = if winner == player1.identifier {
+            player3.identifier
+        } else {
+            player1.identifier
+        };
+
+        assert_eq!(texas_hold_em.players[&winner].chips, 20);
+        assert_eq!(texas_hold_em.players[&loser].chips, 0);
+        assert_eq!(texas_hold_em.players[&player2.identifier].chips, 50);
+        assert_eq!(texas_hold_em.seats, original_seats);
+    }
+
+    /// Tests get_small_blind_seat_index() and get_big_blind_seat_index().
+    ///
+    /// Tests that the dealer posts the small blind and acts first before the flop when playing heads-up.
+    #[test]
+    fn heads_up_dealer_posts_small_blind() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1).unwrap();
+        texas_hold_em.add_player(player2).unwrap();
+
+        assert_eq!(texas_hold_em.get_small_blind_seat_index(), 0);
+        assert_eq!(texas_hold_em.get_big_blind_seat_index(), 1);
+        assert_eq!(texas_hold_em.get_under_the_gun_seat_index(), 0);
+        assert_eq!(
+            texas_hold_em.get_first_to_act_after_the_flop_seat_index(),
+            1
+        );
+    }
+
+    /// Tests add_player_for_next_round().
+    ///
+    /// Tests that a player joining between hands is seated immediately.
+    #[test]
+    fn add_player_for_next_round_seats_immediately_between_hands() {
+        let mut game = TexasHoldEm::new(100, 10, 1, 3).unwrap();
+        assert!(!game.is_hand_in_progress());
+
+        let player1 = game.new_player_with_chips("Player 1", 100);
+        assert_eq!(game.add_player_for_next_round(player1.clone()), Ok(()));
+        assert!(game.seats.contains(&player1.identifier));
+        assert!(game.pending_joins.is_empty());
+
+        // A full table rejects the player through either path.
+        let mut game2 = TexasHoldEm::new(100, 2, 1, 3).unwrap();
+        for name in ["Player 1", "Player 2"] {
+            let player = game2.new_player_with_chips(name, 100);
+            assert_eq!(game2.add_player_for_next_round(player), Ok(()));
+        }
+        let player3 = game2.new_player_with_chips("Player 3", 100);
+        assert_eq!(
+            game2.add_player_for_next_round(player3.clone()),
+            Err(AddPlayerError::TableFull)
+        );
+        assert_eq!(
+            game2.add_player(player3),
+            Err("Unable to join the table. It is already at max capacity.")
+        );
+        assert_eq!(game2.seats.len(), 2);
+    }
 
-        let player5 = game.new_player_with_chips("Player 5", 100);
-        game.add_player(player5.clone()).unwrap();
-        let player5_cards: Vec<Card> = vec![jack_of_hearts, jack_of_spades];
-        let player5_hand = Hand::new_from_cards(player5_cards);
-        player_hands.insert(player5.identifier, player5_hand);
+    /// Tests award_bounty().
+    ///
+    /// Tests that the bounty on an eliminated player is moved to the bounty hunter and cleared.
+    #[test]
+    fn award_bounty_works() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = Player::new_with_chips("Player 1", 100, 25);
+        let player2 = Player::new_with_chips("Player 2", 100, 25);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
 
-        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+        assert_eq!(
+            texas_hold_em.award_bounty(player1.identifier, player2.identifier),
+            Err(BountyError::PlayerNotEliminated)
+        );
+        assert_eq!(
+            texas_hold_em.award_bounty(player1.identifier, player1.identifier),
+            Err(BountyError::SamePlayer)
+        );
+        assert_eq!(
+            texas_hold_em.award_bounty(Uuid::new_v4(), player2.identifier),
+            Err(BountyError::PlayerNotFound)
+        );
 
-        assert_eq!(leading_players.len(), 2);
-        assert!(leading_players.contains_key(&player1.identifier));
-        assert!(leading_players.contains_key(&player2.identifier));
+        texas_hold_em.subtract_chips_from_player(&player1.identifier, 100);
         assert_eq!(
-            leading_players.get(&player1.identifier).unwrap()[0],
-            straight1
+            texas_hold_em.award_bounty(player1.identifier, player2.identifier),
+            Ok(25)
         );
+        assert_eq!(texas_hold_em.players[&player2.identifier].chips, 125);
+        assert_eq!(texas_hold_em.players[&player1.identifier].bounty, 0);
         assert_eq!(
-            leading_players.get(&player2.identifier).unwrap()[0],
-            straight2
+            texas_hold_em.award_bounty(player1.identifier, player2.identifier),
+            Ok(0)
         );
+    }
+
+    /// Tests get_all_player_chips().
+    ///
+    /// Tests that the chip counts reflect the blinds paid and the pot won after a simulated round.
+    #[test]
+    fn get_all_player_chips_reflects_round_result() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+
+        let round_result = texas_hold_em.simulate_round_with_seed(7);
+
+        // Player 2 is the dealer, so Player 3 posts the small blind and Player 1 posts the big blind.
+        let blinds_paid = HashMap::from([
+            (player1.identifier, 2),
+            (player2.identifier, 0),
+            (player3.identifier, 1),
+        ]);
+        let all_player_chips = texas_hold_em.get_all_player_chips();
+        assert_eq!(all_player_chips.len(), 3);
+        for (player_identifier, blind_paid) in blinds_paid {
+            let chips_won = round_result
+                .chips_won
+                .get(&player_identifier)
+                .copied()
+                .unwrap_or(0);
+            assert_eq!(
+                all_player_chips[&player_identifier],
+                100 - blind_paid + chips_won
+            );
+        }
+        assert_eq!(all_player_chips.values().sum::<u32>(), 300);
+    }
+
+    /// Tests display_hand_rankings_table().
+    ///
+    /// Tests that every hand ranking is listed from the strongest to the weakest.
+    #[test]
+    fn format_hand_rankings_table_lists_every_hand_rank() {
+        let table = TexasHoldEm::format_hand_rankings_table();
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 10);
+        assert!(lines[1].starts_with("1    StraightFlush"));
+        assert!(lines[9].starts_with("9    HighCard"));
+        assert!(lines[9].ends_with("The highest card when no other hand is made."));
+    }
+
+    /// Tests run_showdown_only().
+    ///
+    /// Tests that the winner is determined without moving any chips or recording hand ranks.
+    #[test]
+    fn run_showdown_only_determines_winner_without_distributing_chips() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+
+        let player_hands = HashMap::from([
+            (
+                player1.identifier,
+                Hand::new_from_cards(vec![card!(Ace, Heart), card!(Ace, Spade)]),
+            ),
+            (
+                player2.identifier,
+                Hand::new_from_cards(vec![card!(King, Heart), card!(King, Spade)]),
+            ),
+        ]);
+        let board = [
+            card!(Two, Club),
+            card!(Seven, Diamond),
+            card!(Nine, Heart),
+            card!(Jack, Club),
+            card!(Four, Spade),
+        ];
+
+        let round_result = texas_hold_em.run_showdown_only(player_hands, board);
+
+        assert_eq!(round_result.winning_players.len(), 1);
+        assert!(round_result
+            .winning_players
+            .contains_key(&player1.identifier));
+        assert_eq!(round_result.table_cards.cards.len(), 5);
+        assert_eq!(round_result.pot_amount, 0);
+        assert!(round_result.chips_won.is_empty());
+        assert!(texas_hold_em
+            .get_all_player_chips()
+            .values()
+            .all(|chips| *chips == 100));
         assert_eq!(
-            *leading_players.get(&player1.identifier).unwrap(),
-            *leading_players.get(&player2.identifier).unwrap()
+            texas_hold_em.get_player_rank_in_hand(player1.identifier),
+            None
         );
     }
 
-    /// Tests rank_all_hands().
+    /// Tests play_round_with_betting().
     ///
-    /// Tests that multiple equal hands result in a push for all involved players.
+    /// Tests that illegal actions are rejected and that a folded player is excluded from later streets and the showdown.
     #[test]
-    fn rank_all_hands_identifies_higher_straight_beats_ace_low_straight() {
-        let mut game = TexasHoldEm::new(100, 10, 1, 3);
+    fn play_round_with_betting_excludes_folded_player() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+
+        // With three players the dealer is under the gun and folds after a raise below the minimum is rejected.
+        // The small blind is unable to check the big blind and calls instead, then both players check to the river.
+        let actions = "raise 3\nfold\ncheck\ncall\ncheck\n".to_owned() + &"check\n".repeat(6);
+        let mut input = actions.as_bytes();
+        let round_result = texas_hold_em.play_round_with_betting(&mut input);
+
+        assert!(input.is_empty());
+        let folded_identifiers: Vec<Uuid> = texas_hold_em
+            .player_action_history()
+            .iter()
+            .filter(|record| record.action == PlayerAction::Fold())
+            .map(|record| record.player_identifier)
+            .collect();
+        assert_eq!(folded_identifiers.len(), 1);
+        let folded_identifier = folded_identifiers[0];
+        assert!(!round_result
+            .winning_players
+            .contains_key(&folded_identifier));
+        assert_eq!(round_result.pot_amount, 4);
+
+        let all_player_chips = texas_hold_em.get_all_player_chips();
+        assert_eq!(all_player_chips[&folded_identifier], 100);
+        assert_eq!(all_player_chips.values().sum::<u32>(), 300);
+        assert!(texas_hold_em
+            .player_action_history()
+            .iter()
+            .all(|record| record.player_identifier != folded_identifier
+                || record.street == Street::PreFlop));
+    }
 
-        let two_of_diamonds = card!(Two, Diamond);
-        let three_of_clubs = card!(Three, Club);
-        let four_of_hearts = card!(Four, Heart);
-        let five_of_clubs = card!(Five, Club);
-        let five_of_diamonds = card!(Five, Diamond);
-        let six_of_spades = card!(Six, Spade);
-        let nine_of_spades = card!(Nine, Spade);
-        let ten_of_diamonds = card!(Ten, Diamond);
-        let ten_of_hearts = card!(Ten, Heart);
-        let jack_of_clubs = card!(Jack, Club);
-        let jack_of_hearts = card!(Jack, Heart);
-        let jack_of_spades = card!(Jack, Spade);
-        let queen_of_spades = card!(Queen, Spade);
-        let king_of_diamonds = card!(King, Diamond);
-        let ace_of_hearts = card!(Ace, Heart);
+    /// Tests determine_round_result() with create_side_pots() and distribute_side_pots().
+    ///
+    /// Tests that a player who is all in only wins the main pot and the side pot goes to the best remaining hand.
+    #[test]
+    fn determine_round_result_awards_side_pot_to_remaining_players() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        // Allow players with fewer chips than the minimum buy-in to join the table.
+        texas_hold_em.minimum_chips_buy_in_amount = 1;
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 20);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+        texas_hold_em.reset_player_round_state();
+
+        // Player 1 is all in for 20 chips and the other players each bet 50 chips.
+        for (player_identifier, amount) in [
+            (player1.identifier, 20),
+            (player2.identifier, 50),
+            (player3.identifier, 50),
+        ] {
+            texas_hold_em.subtract_chips_from_player(&player_identifier, amount);
+            texas_hold_em.add_chips_to_main_pot(amount).unwrap();
+        }
 
-        let straight = HandRank::Straight([
-            two_of_diamonds,
-            three_of_clubs,
-            four_of_hearts,
-            five_of_diamonds,
-            six_of_spades,
+        let player_hands = HashMap::from([
+            (
+                player1.identifier,
+                Hand::new_from_cards(vec![card!(Ace, Heart), card!(Ace, Spade)]),
+            ),
+            (
+                player2.identifier,
+                Hand::new_from_cards(vec![card!(King, Heart), card!(King, Spade)]),
+            ),
+            (
+                player3.identifier,
+                Hand::new_from_cards(vec![card!(Queen, Heart), card!(Queen, Diamond)]),
+            ),
+        ]);
+        let table_cards = Hand::new_from_cards(vec![
+            card!(Two, Club),
+            card!(Seven, Diamond),
+            card!(Nine, Heart),
+            card!(Jack, Spade),
+            card!(Four, Club),
         ]);
 
-        let table_cards: Vec<Card> = vec![
-            two_of_diamonds,
-            three_of_clubs,
-            four_of_hearts,
-            five_of_diamonds,
-            king_of_diamonds,
-        ];
+        let winning_players = texas_hold_em.rank_all_hands(&player_hands, &table_cards);
+        let round_result =
+            texas_hold_em.determine_round_result(&player_hands, &table_cards, &winning_players);
+
+        assert_eq!(round_result.pot_amount, 120);
+        assert_eq!(round_result.chips_won.get(&player1.identifier), Some(&60));
+        assert_eq!(round_result.chips_won.get(&player2.identifier), Some(&60));
+        assert_eq!(round_result.chips_won.get(&player3.identifier), None);
+        let all_player_chips = texas_hold_em.get_all_player_chips();
+        assert_eq!(all_player_chips[&player1.identifier], 60);
+        assert_eq!(all_player_chips[&player2.identifier], 110);
+        assert_eq!(all_player_chips[&player3.identifier], 50);
+        assert!(texas_hold_em.side_pots.is_empty());
+    }
 
-        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
-        let table_cards = Hand::new_from_cards(table_cards);
+    /// Tests betting_round_from_input() with determine_round_result().
+    ///
+    /// Tests that a player who calls all in for less than the current bet only wins the main pot and the side pot
+    /// goes to the best remaining hand.
+    #[test]
+    fn betting_round_from_input_creates_side_pot_for_short_all_in_call() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+        texas_hold_em
+            .players
+            .get_mut(&player1.identifier)
+            .unwrap()
+            .chips = 20;
+        texas_hold_em.reset_player_round_state();
+
+        // Player 2 bets 50 chips, Player 3 calls, and Player 1 calls all in for 20 chips.
+        let mut input = "raise 50\ncall\ncall\n".as_bytes();
+        texas_hold_em.betting_round_from_input(1, &mut input);
+
+        assert!(texas_hold_em.players[&player1.identifier].is_all_in());
+        assert_eq!(texas_hold_em.main_pot.amount, 120);
+
+        let player_hands = HashMap::from([
+            (
+                player1.identifier,
+                Hand::new_from_cards(vec![card!(Ace, Heart), card!(Ace, Spade)]),
+            ),
+            (
+                player2.identifier,
+                Hand::new_from_cards(vec![card!(King, Heart), card!(King, Spade)]),
+            ),
+            (
+                player3.identifier,
+                Hand::new_from_cards(vec![card!(Queen, Heart), card!(Queen, Diamond)]),
+            ),
+        ]);
+        let table_cards = Hand::new_from_cards(vec![
+            card!(Two, Club),
+            card!(Seven, Diamond),
+            card!(Nine, Heart),
+            card!(Jack, Spade),
+            card!(Four, Club),
+        ]);
 
-        let player1 = game.new_player_with_chips("Player 1", 100);
-        game.add_player(player1.clone()).unwrap();
-        let player1_cards: Vec<Card> = vec![ten_of_diamonds, ace_of_hearts];
-        let player1_hand = Hand::new_from_cards(player1_cards);
-        player_hands.insert(player1.identifier, player1_hand);
+        let winning_players = texas_hold_em.rank_all_hands(&player_hands, &table_cards);
+        let round_result =
+            texas_hold_em.determine_round_result(&player_hands, &table_cards, &winning_players);
+
+        // The main pot is 20 chips from each player and the side pot is the other 30 chips from Players 2 and 3.
+        assert_eq!(round_result.chips_won.get(&player1.identifier), Some(&60));
+        assert_eq!(round_result.chips_won.get(&player2.identifier), Some(&60));
+        assert_eq!(round_result.chips_won.get(&player3.identifier), None);
+        let all_player_chips = texas_hold_em.get_all_player_chips();
+        assert_eq!(all_player_chips[&player1.identifier], 60);
+        assert_eq!(all_player_chips[&player2.identifier], 110);
+        assert_eq!(all_player_chips[&player3.identifier], 50);
+    }
 
-        let player2 = game.new_player_with_chips("Player 2", 100);
-        game.add_player(player2.clone()).unwrap();
-        let player2_cards: Vec<Card> = vec![six_of_spades, jack_of_clubs];
-        let player2_hand = Hand::new_from_cards(player2_cards);
-        player_hands.insert(player2.identifier, player2_hand);
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that tied hands without any kickers, such as pairs before the flop, are ranked without recording a kicker.
+    #[test]
+    fn rank_all_hands_ties_without_kickers() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+
+        let player_hands = HashMap::from([
+            (
+                player1.identifier,
+                Hand::new_from_cards(vec![card!(King, Club), card!(King, Heart)]),
+            ),
+            (
+                player2.identifier,
+                Hand::new_from_cards(vec![card!(King, Diamond), card!(King, Spade)]),
+            ),
+        ]);
 
-        let player3 = game.new_player_with_chips("Player 3", 100);
-        game.add_player(player3.clone()).unwrap();
-        let player3_cards: Vec<Card> = vec![nine_of_spades, ten_of_hearts];
-        let player3_hand = Hand::new_from_cards(player3_cards);
-        player_hands.insert(player3.identifier, player3_hand);
+        let winning_players = texas_hold_em.rank_all_hands(&player_hands, &Hand::new());
 
-        let player4 = game.new_player_with_chips("Player 4", 100);
-        game.add_player(player4.clone()).unwrap();
-        let player4_cards: Vec<Card> = vec![five_of_clubs, queen_of_spades];
-        let player4_hand = Hand::new_from_cards(player4_cards);
-        player_hands.insert(player4.identifier, player4_hand);
+        assert_eq!(winning_players.len(), 2);
+        assert!(winning_players
+            .values()
+            .all(|hand_ranks| hand_ranks.len() == 1));
+    }
 
-        let player5 = game.new_player_with_chips("Player 5", 100);
-        game.add_player(player5.clone()).unwrap();
-        let player5_cards: Vec<Card> = vec![jack_of_hearts, jack_of_spades];
-        let player5_hand = Hand::new_from_cards(player5_cards);
-        player_hands.insert(player5.identifier, player5_hand);
+    /// Tests rank_all_hands().
+    ///
+    /// Tests that the kicker decides between players sharing Four of a Kind on the table.
+    #[test]
+    fn rank_all_hands_uses_kicker_with_four_of_a_kind_on_table() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+
+        let table_cards = Hand::new_from_cards(vec![
+            card!(Queen, Club),
+            card!(Queen, Diamond),
+            card!(Queen, Heart),
+            card!(Queen, Spade),
+            card!(Two, Club),
+        ]);
+        let player_hands = HashMap::from([
+            (
+                player1.identifier,
+                Hand::new_from_cards(vec![card!(Ace, Heart), card!(Three, Diamond)]),
+            ),
+            (
+                player2.identifier,
+                Hand::new_from_cards(vec![card!(King, Heart), card!(Five, Diamond)]),
+            ),
+        ]);
 
-        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+        let winning_players = texas_hold_em.rank_all_hands(&player_hands, &table_cards);
 
-        assert_eq!(leading_players.len(), 1);
-        assert!(!leading_players.contains_key(&player1.identifier));
-        assert!(leading_players.contains_key(&player2.identifier));
+        assert_eq!(winning_players.len(), 1);
+        assert!(winning_players.contains_key(&player1.identifier));
+
+        // When the kicker on the table is higher than both hands, the players split the pot.
+        let table_cards = Hand::new_from_cards(vec![
+            card!(Queen, Club),
+            card!(Queen, Diamond),
+            card!(Queen, Heart),
+            card!(Queen, Spade),
+            card!(Ace, Club),
+        ]);
+
+        let winning_players = texas_hold_em.rank_all_hands(&player_hands, &table_cards);
+
+        assert_eq!(winning_players.len(), 2);
+    }
+
+    /// Tests rank_all_hands() with Player::fold().
+    ///
+    /// Tests that a player who folds with the best possible hand is not a winner.
+    #[test]
+    fn rank_all_hands_excludes_folded_player_with_best_hand() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em
+            .players
+            .get_mut(&player1.identifier)
+            .unwrap()
+            .fold();
+
+        let table_cards = Hand::new_from_cards(vec![
+            card!(Ten, Heart),
+            card!(Jack, Heart),
+            card!(Queen, Heart),
+            card!(Two, Club),
+            card!(Three, Diamond),
+        ]);
+        let player_hands = HashMap::from([
+            (
+                player1.identifier,
+                Hand::new_from_cards(vec![card!(King, Heart), card!(Ace, Heart)]),
+            ),
+            (
+                player2.identifier,
+                Hand::new_from_cards(vec![card!(Seven, Spade), card!(Eight, Club)]),
+            ),
+        ]);
+
+        let winning_players = texas_hold_em.rank_all_hands(&player_hands, &table_cards);
+
+        assert_eq!(winning_players.len(), 1);
+        assert!(winning_players.contains_key(&player2.identifier));
         assert_eq!(
-            leading_players.get(&player2.identifier).unwrap()[0],
-            straight
+            texas_hold_em.get_player_rank_in_hand(player1.identifier),
+            None
         );
     }
 
-    /// Tests rank_all_hands().
+    /// Tests simulate_round().
     ///
-    /// Tests that hand ranking correctly updates the leader for a pair that is higher than the previously set high pair.
+    /// Tests that every player's folded state is reset at the end of the round.
     #[test]
-    fn rank_all_hands_identifies_higher_pair_as_winner_over_previous_high_pair() {
-        let mut game = TexasHoldEm::new(100, 10, 1, 3);
+    fn simulate_round_resets_folded_players() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player1).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3).unwrap();
+
+        // A player without chips is folded at the start of the round.
+        texas_hold_em
+            .players
+            .get_mut(&player2.identifier)
+            .unwrap()
+            .chips = 0;
+        texas_hold_em.simulate_round_with_seed(7);
+
+        assert!(texas_hold_em
+            .players
+            .values()
+            .all(|player| !player.is_folded()));
+    }
 
-        let two_of_spades = card!(Two, Spade);
-        let four_of_clubs = card!(Four, Club);
-        let five_of_diamonds = card!(Five, Diamond);
-        let six_of_clubs = card!(Six, Club);
-        let seven_of_hearts = card!(Seven, Heart);
-        let nine_of_clubs = card!(Nine, Club);
-        let nine_of_spades = card!(Nine, Spade);
-        let ten_of_clubs = card!(Ten, Club);
-        let ten_of_diamonds = card!(Ten, Diamond);
-        let ten_of_spades = card!(Ten, Spade);
-        let jack_of_clubs = card!(Jack, Club);
-        let jack_of_spades = card!(Jack, Spade);
-        let queen_of_diamonds = card!(Queen, Diamond);
-        let queen_of_hearts = card!(Queen, Heart);
-        let king_of_diamonds = card!(King, Diamond);
-        let ace_of_hearts = card!(Ace, Heart);
-        let ace_of_spades = card!(Ace, Spade);
+    /// Tests play_interactive_game_from_input().
+    ///
+    /// Tests that the table is filled with CPU players and the game ends once the human player is out of chips.
+    #[test]
+    fn play_interactive_game_from_input_ends_when_human_busts() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 2, 1, 2).unwrap();
+
+        // The human player folds every time the input runs out.
+        let mut input = "Tester\n".as_bytes();
+        texas_hold_em.play_interactive_game_from_input(&mut input);
+
+        assert!(texas_hold_em.game_over);
+        assert_eq!(texas_hold_em.players.len(), 1);
+        let remaining_player = texas_hold_em.players.values().next().unwrap();
+        assert!(texas_hold_em.is_cpu_player(&remaining_player.identifier));
+        assert_eq!(remaining_player.name, "CPU 1");
+        assert_eq!(remaining_player.chips, 80);
+    }
 
-        let pair = HandRank::Pair([ace_of_hearts, ace_of_spades]);
+    /// Tests get_player_statistics().
+    ///
+    /// Tests that every round is recorded as a win, loss, or push along with each player's chips.
+    #[test]
+    fn get_player_statistics_records_each_round() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+
+        let round_results: Vec<RoundResult> = (0..3)
+            .map(|seed| texas_hold_em.simulate_round_with_seed(seed))
+            .collect();
 
-        let table_cards: Vec<Card> = vec![
-            queen_of_diamonds,
-            jack_of_clubs,
-            five_of_diamonds,
-            two_of_spades,
-            ace_of_spades,
-        ];
+        for player in [&player1, &player2, &player3] {
+            let statistics = texas_hold_em
+                .get_player_statistics(player.identifier)
+                .unwrap();
+            assert_eq!(statistics.name, player.name);
+            assert_eq!(statistics.hands_played, 3);
+            assert_eq!(
+                statistics.wins + statistics.losses + statistics.pushes,
+                statistics.hands_played
+            );
+            assert_eq!(statistics.chip_history.len(), 3);
+            assert_eq!(
+                statistics.chip_history.last(),
+                texas_hold_em.get_all_player_chips().get(&player.identifier)
+            );
+        }
 
-        let mut player_hands: HashMap<Uuid, Hand> = HashMap::new();
-        let table_cards = Hand::new_from_cards(table_cards);
+        let total_wins: u32 = texas_hold_em
+            .player_statistics
+            .values()
+            .map(|statistics| statistics.wins)
+            .sum();
+        let sole_winner_rounds = round_results
+            .iter()
+            .filter(|round_result| round_result.winning_players.len() == 1)
+            .count();
+        assert_eq!(total_wins as usize, sole_winner_rounds);
+    }
 
-        let player2 = game.new_player_with_chips("Player 2", 100);
-        game.add_player(player2.clone()).unwrap();
-        let player2_cards: Vec<Card> = vec![jack_of_spades, nine_of_spades];
-        let player2_hand = Hand::new_from_cards(player2_cards);
-        player_hands.insert(player2.identifier, player2_hand);
+    /// Tests players_in_seat_order() and players_in_seat_order_mut().
+    ///
+    /// Tests that the players are returned in the order of their seats.
+    #[test]
+    fn players_in_seat_order_follows_seats() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 200);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 300);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+        texas_hold_em.seats = vec![player3.identifier, player1.identifier, player2.identifier];
+
+        let identifiers: Vec<Uuid> = texas_hold_em
+            .players_in_seat_order()
+            .iter()
+            .map(|player| player.identifier)
+            .collect();
+        assert_eq!(
+            identifiers,
+            vec![player3.identifier, player1.identifier, player2.identifier]
+        );
 
-        let player3 = game.new_player_with_chips("Player 3", 100);
-        game.add_player(player3.clone()).unwrap();
-        let player3_cards: Vec<Card> = vec![nine_of_clubs, four_of_clubs];
-        let player3_hand = Hand::new_from_cards(player3_cards);
-        player_hands.insert(player3.identifier, player3_hand);
+        for player in texas_hold_em.players_in_seat_order_mut() {
+            player.add_chips(1);
+        }
+        let chips: Vec<u32> = texas_hold_em
+            .players_in_seat_order()
+            .iter()
+            .map(|player| player.chips)
+            .collect();
+        assert_eq!(chips, vec![301, 101, 201]);
+    }
 
-        let player4 = game.new_player_with_chips("Player 4", 100);
-        game.add_player(player4.clone()).unwrap();
-        let player4_cards: Vec<Card> = vec![six_of_clubs, ten_of_spades];
-        let player4_hand = Hand::new_from_cards(player4_cards);
-        player_hands.insert(player4.identifier, player4_hand);
+    /// Tests save_state() and load_state().
+    ///
+    /// Tests that the chip counts, seat order, and dealer are preserved through a save and load.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn save_state_and_load_state_round_trip() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 250);
+        let player3 = texas_hold_em.new_player_with_chips("Player 3", 175);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_player(player3.clone()).unwrap();
+        texas_hold_em.seats = vec![player2.identifier, player3.identifier, player1.identifier];
+        texas_hold_em.rotate_dealer().unwrap();
+
+        let path = std::env::temp_dir().join(format!("texas_hold_em_{}.json", Uuid::new_v4()));
+        texas_hold_em.save_state(&path).unwrap();
+        let loaded = TexasHoldEm::load_state(&path);
+        std::fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+
+        assert_eq!(loaded.seats, texas_hold_em.seats);
+        assert_eq!(loaded.dealer_seat_index, texas_hold_em.dealer_seat_index);
+        assert_eq!(
+            loaded.get_all_player_chips(),
+            texas_hold_em.get_all_player_chips()
+        );
+        assert_eq!(loaded.players, texas_hold_em.players);
+        assert_eq!(loaded.small_blind_amount, 1);
+        assert_eq!(loaded.big_blind_amount, 2);
+    }
 
-        let player5 = game.new_player_with_chips("Player 5", 100);
-        game.add_player(player5.clone()).unwrap();
-        let player5_cards: Vec<Card> = vec![seven_of_hearts, queen_of_hearts];
-        let player5_hand = Hand::new_from_cards(player5_cards);
-        player_hands.insert(player5.identifier, player5_hand);
+    /// Tests simulate_round().
+    ///
+    /// Tests that a round with more players than the deck is able to deal to is not played.
+    #[test]
+    fn simulate_round_requires_enough_cards_for_every_player() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 23, 1, 2).unwrap();
+        for number in 1..=23 {
+            let player = texas_hold_em.new_player_with_chips(&format!("Player {}", number), 100);
+            texas_hold_em.add_player(player).unwrap();
+        }
 
-        let player6 = game.new_player_with_chips("Player 6", 100);
-        game.add_player(player6.clone()).unwrap();
-        let player6_cards: Vec<Card> = vec![ten_of_diamonds, ten_of_clubs];
-        let player6_hand = Hand::new_from_cards(player6_cards);
-        player_hands.insert(player6.identifier, player6_hand);
+        let round_result = texas_hold_em.simulate_round();
 
-        let player1 = game.new_player_with_chips("Player 1", 100);
-        game.add_player(player1.clone()).unwrap();
-        let player1_cards: Vec<Card> = vec![king_of_diamonds, ace_of_hearts];
-        let player1_hand = Hand::new_from_cards(player1_cards);
-        player_hands.insert(player1.identifier, player1_hand);
+        assert_eq!(round_result, RoundResult::default());
+        assert!(!texas_hold_em.hand_in_progress);
+        assert_eq!(texas_hold_em.deck.remaining(), 52);
+        assert_eq!(texas_hold_em.get_main_pot_amount(), 0);
+        assert!(texas_hold_em
+            .players
+            .values()
+            .all(|player| player.chips == 100));
+    }
 
-        let leading_players = game.rank_all_hands(&player_hands, &table_cards);
+    /// Tests betting_round_from_input() with BettingMode::PotLimit.
+    ///
+    /// Tests that a raise larger than the pot is rejected and the player is prompted again.
+    #[test]
+    fn pot_limit_rejects_raise_larger_than_pot() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        texas_hold_em.set_betting_mode(BettingMode::PotLimit);
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+        texas_hold_em.add_chips_to_main_pot(10).unwrap();
+
+        let mut input = "raise 11\nraise 10\ncall\n".as_bytes();
+        texas_hold_em.betting_round_from_input(0, &mut input);
+
+        assert!(input.is_empty());
+        let actions: Vec<(Uuid, PlayerAction)> = texas_hold_em
+            .player_action_history()
+            .iter()
+            .map(|record| (record.player_identifier, record.action.clone()))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                (player1.identifier, PlayerAction::Raise(10)),
+                (player2.identifier, PlayerAction::Call()),
+            ]
+        );
+        assert_eq!(texas_hold_em.get_main_pot_amount(), 30);
+    }
 
-        assert_eq!(leading_players.len(), 1);
-        assert!(leading_players.contains_key(&player1.identifier));
-        assert_eq!(leading_players.get(&player1.identifier).unwrap()[0], pair);
+    /// Tests rotate_dealer().
+    ///
+    /// Tests that rotating the dealer with one or no players seated does not panic.
+    #[test]
+    fn rotate_dealer_handles_empty_seats() {
+        let mut texas_hold_em = TexasHoldEm::new(100, 10, 1, 2).unwrap();
+        let player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        let player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        texas_hold_em.add_player(player1.clone()).unwrap();
+        texas_hold_em.add_player(player2.clone()).unwrap();
+
+        texas_hold_em.remove_player(&player2.identifier);
+        assert_eq!(texas_hold_em.rotate_dealer(), Ok(()));
+        assert_eq!(texas_hold_em.dealer_seat_index, 0);
+
+        texas_hold_em.remove_player(&player1.identifier);
+        assert!(texas_hold_em.rotate_dealer().is_err());
+
+        let round_result = texas_hold_em.simulate_round();
+        assert!(round_result.winning_players.is_empty());
+        assert!(!texas_hold_em.hand_in_progress);
+    }
+
+    /// Tests format_chip_history().
+    ///
+    /// Tests that every player's chip count history is scaled to the same range, including eliminated players.
+    #[test]
+    fn format_chip_history_scales_every_player_to_the_same_range() {
+        let mut texas_hold_em = TexasHoldEm::new(40, 10, 1, 2).unwrap();
+        let mut player1 = texas_hold_em.new_player_with_chips("Player 1", 100);
+        player1.chip_count_history = vec![100, 150, 200];
+        player1.chips = 200;
+        let mut player2 = texas_hold_em.new_player_with_chips("Player 2", 100);
+        player2.chip_count_history = vec![100, 50, 0];
+        let player2_identifier = player2.identifier;
+        texas_hold_em.add_player(player1).unwrap();
+        texas_hold_em.add_player(player2).unwrap();
+        texas_hold_em
+            .players
+            .get_mut(&player2_identifier)
+            .unwrap()
+            .chips = 0;
+
+        texas_hold_em.remove_losers();
+        assert_eq!(texas_hold_em.eliminated_players.len(), 1);
+
+        assert_eq!(
+            texas_hold_em.format_chip_history(),
+            "****************\n* CHIP HISTORY *\n****************\nPlayer 1 ▄▆█ 200\nPlayer 2 ▄▂▁ 0\n"
+        );
     }
 }
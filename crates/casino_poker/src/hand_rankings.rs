@@ -3,8 +3,56 @@ use std::collections::HashMap;
 use std::fmt;
 
 use casino_cards::card::{Card, Rank, Suit};
+use casino_cards::hand::Hand;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// The approximate probability that a hand of each HandRank wins at showdown.
+///
+/// Rows are indexed by HandRank::rank_class() and columns by the number of opponents from 1 to 9.
+/// The values come from the win_probabilities_match_simulation test, which plays 1,000,000 random hands to
+/// showdown for each number of opponents and counts a tie as a shared win.
+/// Four of a Kind and Straight Flush are rare enough that their simulated rows are noisy, so the table was then
+/// smoothed so that a higher hand rank never has a lower probability and more opponents never raise it.
+const WIN_PROBABILITIES: [[f64; 9]; 9] = [
+    // HighCard
+    [0.17, 0.04, 0.01, 0.00, 0.00, 0.00, 0.00, 0.00, 0.00],
+    // Pair
+    [0.42, 0.23, 0.14, 0.09, 0.06, 0.04, 0.03, 0.02, 0.02],
+    // TwoPair
+    [0.67, 0.48, 0.36, 0.27, 0.22, 0.17, 0.14, 0.12, 0.10],
+    // ThreeOfAKind
+    [0.74, 0.64, 0.57, 0.52, 0.48, 0.44, 0.41, 0.38, 0.35],
+    // Straight
+    [0.87, 0.78, 0.71, 0.65, 0.60, 0.56, 0.51, 0.48, 0.45],
+    // Flush
+    [0.87, 0.78, 0.71, 0.65, 0.60, 0.56, 0.53, 0.49, 0.47],
+    // FullHouse
+    [0.91, 0.85, 0.79, 0.75, 0.71, 0.68, 0.64, 0.62, 0.59],
+    // FourOfAKind
+    [0.93, 0.91, 0.89, 0.89, 0.88, 0.87, 0.87, 0.86, 0.86],
+    // StraightFlush
+    [0.97, 0.96, 0.95, 0.95, 0.92, 0.92, 0.92, 0.92, 0.92],
+];
+
+/// The names of each HandRank category, indexed by HandRank::rank_class().
+///
+/// Each category has a variant name, which matches the enum variant and is used as a key such as in the game
+/// statistics, and a display name for players, which has spaces between the words.
+const HAND_RANK_NAMES: [(&str, &str); 9] = [
+    ("HighCard", "High Card"),
+    ("Pair", "Pair"),
+    ("TwoPair", "Two Pair"),
+    ("ThreeOfAKind", "Three of a Kind"),
+    ("Straight", "Straight"),
+    ("Flush", "Flush"),
+    ("FullHouse", "Full House"),
+    ("FourOfAKind", "Four of a Kind"),
+    ("StraightFlush", "Straight Flush"),
+];
 
 #[derive(Clone, Copy, Debug, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HandRank {
     /// Simple value of the card.
     /// Lowest: 2 – Highest: Ace.
@@ -30,31 +78,152 @@ pub enum HandRank {
 
 impl HandRank {
     pub fn contains(&self, card: &Card) -> bool {
+        self.cards().contains(card)
+    }
+
+    /// Returns the cards that make up the hand rank, such as the two cards of a Pair.
+    pub fn cards(&self) -> &[Card] {
         match self {
-            HandRank::HighCard(cards) => *cards == *card,
-            HandRank::Pair(cards) => cards.iter().any(|c| *c == *card),
-            HandRank::TwoPair(cards) => cards.iter().any(|c| *c == *card),
-            HandRank::ThreeOfAKind(cards) => cards.iter().any(|c| *c == *card),
-            HandRank::Straight(cards) => cards.iter().any(|c| *c == *card),
-            HandRank::Flush(cards) => cards.iter().any(|c| *c == *card),
-            HandRank::FullHouse(cards) => cards.iter().any(|c| *c == *card),
-            HandRank::FourOfAKind(cards) => cards.iter().any(|c| *c == *card),
-            HandRank::StraightFlush(cards) => cards.iter().any(|c| *c == *card),
+            HandRank::HighCard(card) => std::slice::from_ref(card),
+            HandRank::Pair(cards) => cards,
+            HandRank::TwoPair(cards) => cards,
+            HandRank::ThreeOfAKind(cards) => cards,
+            HandRank::Straight(cards) => cards,
+            HandRank::Flush(cards) => cards,
+            HandRank::FullHouse(cards) => cards,
+            HandRank::FourOfAKind(cards) => cards,
+            HandRank::StraightFlush(cards) => cards,
         }
     }
 
-    pub fn len(&self) -> usize {
+    /// Determine the best HandRank by ranking every combination of five cards from the given cards.
+    ///
+    /// This is slower than rank_hand(), but it serves as a simple reference implementation and
+    /// does not restrict the number of cards provided.
+    ///
+    /// Returns None if fewer than five cards are provided.
+    pub fn best_from_all_combinations(cards: &[Card]) -> Option<HandRank> {
+        let mut best_hand_rank: Option<HandRank> = None;
+
+        for_each_combination(cards.len(), 5, |indices| {
+            let combination: Vec<Card> = indices.iter().map(|&index| cards[index]).collect();
+            let hand_rank = rank_hand(combination);
+
+            if best_hand_rank.is_none_or(|best| hand_rank > best) {
+                best_hand_rank = Some(hand_rank);
+            }
+        });
+
+        best_hand_rank
+    }
+
+    /// Compares two hand ranks using short-deck (6+) rules, where a Flush beats a Full House.
+    pub fn cmp_short_deck(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (HandRank::Flush(_), HandRank::FullHouse(_)) => Ordering::Greater,
+            (HandRank::FullHouse(_), HandRank::Flush(_)) => Ordering::Less,
+            _ => self.cmp(other),
+        }
+    }
+
+    /// Returns the class of the hand rank from 0 for a High Card to 8 for a Straight Flush.
+    pub fn rank_class(&self) -> usize {
         match self {
-            HandRank::HighCard(_) => 1,
-            HandRank::Pair(_) => 2,
-            HandRank::TwoPair(_) => 4,
+            HandRank::HighCard(_) => 0,
+            HandRank::Pair(_) => 1,
+            HandRank::TwoPair(_) => 2,
             HandRank::ThreeOfAKind(_) => 3,
-            HandRank::Straight(_) => 5,
+            HandRank::Straight(_) => 4,
             HandRank::Flush(_) => 5,
-            HandRank::FullHouse(_) => 5,
-            HandRank::FourOfAKind(_) => 4,
-            HandRank::StraightFlush(_) => 5,
+            HandRank::FullHouse(_) => 6,
+            HandRank::FourOfAKind(_) => 7,
+            HandRank::StraightFlush(_) => 8,
+        }
+    }
+
+    /// Returns a rough estimate of the probability that this hand rank wins at showdown against the number of opponents.
+    ///
+    /// This is a lookup table rather than a simulation, so it is instant but ignores kickers and the table cards.
+    /// More than 9 opponents are treated as 9, and with no opponents or a Royal Flush the hand always wins.
+    pub fn approximate_win_probability(&self, num_opponents: usize) -> f64 {
+        if num_opponents == 0 || self.is_royal_flush() {
+            return 1.0;
+        }
+
+        WIN_PROBABILITIES[self.rank_class()][num_opponents.min(9) - 1]
+    }
+
+    /// Returns the name of the hand category without any card details, such as "Pair" or "Flush".
+    pub fn variant_name(&self) -> &'static str {
+        HAND_RANK_NAMES[self.rank_class()].0
+    }
+
+    /// Returns whether or not the hand rank is a Royal Flush, which is a Ten through Ace Straight Flush.
+    pub fn is_royal_flush(&self) -> bool {
+        matches!(self, HandRank::StraightFlush(cards) if cards.iter().all(|card| card.is_broadway_card()))
+    }
+
+    /// Returns the display name of the hand rank, such as "Two Pair" or "Royal Flush".
+    pub fn name(&self) -> &'static str {
+        if self.is_royal_flush() {
+            return "Royal Flush";
+        }
+
+        HAND_RANK_NAMES[self.rank_class()].1
+    }
+
+    /// Returns the cards that make up the hand rank itself, without any kickers.
+    ///
+    /// This is the same as cards().
+    pub fn dominant_cards(&self) -> &[Card] {
+        self.cards()
+    }
+
+    /// Returns the ranks of the cards that make up the hand rank without their suits.
+    ///
+    /// Ranks that appear more often come first, so a Full House of Kings over Sevens is
+    /// [King, King, King, Seven, Seven], and otherwise the ranks are sorted from highest to lowest.
+    /// The Ace in an ace-low straight is treated as the lowest card.
+    pub fn component_ranks(&self) -> Vec<Rank> {
+        let mut rank_counts: HashMap<Rank, usize> = HashMap::new();
+        for card in self.dominant_cards() {
+            *rank_counts.entry(card.rank).or_default() += 1;
+        }
+
+        let mut ranks: Vec<Rank> = self.dominant_cards().iter().map(|card| card.rank).collect();
+        ranks.sort_by(|rank1, rank2| {
+            rank_counts[rank2]
+                .cmp(&rank_counts[rank1])
+                .then(rank2.cmp(rank1))
+        });
+
+        if matches!(self, HandRank::Straight(_) | HandRank::StraightFlush(_))
+            && ranks.contains(&Rank::Ace)
+            && ranks.contains(&Rank::Two)
+        {
+            ranks.rotate_left(1);
         }
+
+        ranks
+    }
+
+    /// Returns the cards from all_cards that are not part of the hand rank, sorted from highest to lowest.
+    pub fn kicker_cards_from(&self, all_cards: &[Card]) -> Vec<Card> {
+        let dominant_cards = self.dominant_cards();
+
+        let mut kicker_cards: Vec<Card> = all_cards
+            .iter()
+            .filter(|card| !dominant_cards.contains(card))
+            .copied()
+            .collect();
+        kicker_cards.sort_by(|card1, card2| card2.cmp(card1));
+
+        kicker_cards
+    }
+
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.cards().len()
     }
 }
 
@@ -90,10 +259,8 @@ impl Ord for HandRank {
 
             (HandRank::Straight(cards1), HandRank::Straight(cards2)) => {
                 // Ace-low straight check
-                let is_ace_low_straight1 =
-                    cards1[0].rank == Rank::Ace && cards1[1].rank == Rank::Two;
-                let is_ace_low_straight2 =
-                    cards2[0].rank == Rank::Ace && cards2[1].rank == Rank::Two;
+                let is_ace_low_straight1 = cards1[0].is_ace() && cards1[1].rank == Rank::Two;
+                let is_ace_low_straight2 = cards2[0].is_ace() && cards2[1].rank == Rank::Two;
 
                 if is_ace_low_straight1 && !is_ace_low_straight2 {
                     Ordering::Less
@@ -107,7 +274,8 @@ impl Ord for HandRank {
                 }
             }
 
-            (HandRank::Straight(_), HandRank::Flush(_)) => Ordering::Less,
+            (HandRank::Straight(_), _) => Ordering::Less,
+            (_, HandRank::Straight(_)) => Ordering::Greater,
 
             (HandRank::Flush(cards1), HandRank::Flush(cards2)) => {
                 for i in (0..5).rev() {
@@ -134,25 +302,14 @@ impl Ord for HandRank {
             (_, HandRank::FullHouse(_)) => Ordering::Greater,
 
             (HandRank::FourOfAKind(cards1), HandRank::FourOfAKind(cards2)) => {
+                // Four of a Kind on the table is decided by the kicker, which is compared with compare_kickers().
                 cards1[3].rank.cmp(&cards2[3].rank)
             }
             (HandRank::FourOfAKind(_), _) => Ordering::Less,
             (_, HandRank::FourOfAKind(_)) => Ordering::Greater,
 
             (HandRank::StraightFlush(cards1), HandRank::StraightFlush(cards2)) => {
-                // Check for royal flush
-                let is_royal_flush1 = cards1[0].rank == Rank::Ten
-                    && cards1[1].rank == Rank::Jack
-                    && cards1[2].rank == Rank::Queen
-                    && cards1[3].rank == Rank::King
-                    && cards1[4].rank == Rank::Ace;
-                let is_royal_flush2 = cards2[0].rank == Rank::Ten
-                    && cards2[1].rank == Rank::Jack
-                    && cards2[2].rank == Rank::Queen
-                    && cards2[3].rank == Rank::King
-                    && cards2[4].rank == Rank::Ace;
-
-                if is_royal_flush1 && is_royal_flush2 {
+                if self.is_royal_flush() && other.is_royal_flush() {
                     Ordering::Equal
                 } else {
                     // Compare the ranks of the highest cards
@@ -170,8 +327,6 @@ impl Ord for HandRank {
                     }
                 }
             }
-            (_, HandRank::StraightFlush(_)) => Ordering::Less,
-            (HandRank::StraightFlush(_), _) => Ordering::Greater,
         }
     }
 }
@@ -233,80 +388,45 @@ impl PartialEq for HandRank {
                 // Compare the ranks of the highest card of the straight
                 cards1.last().unwrap().rank == cards2.last().unwrap().rank
             }
-            _ => false,
+            // Hand ranks of different variants are never equal.
+            // Every variant is listed instead of using a wildcard so that adding a variant fails to compile
+            // until it is handled above.
+            (HandRank::HighCard(_), _)
+            | (HandRank::Pair(_), _)
+            | (HandRank::TwoPair(_), _)
+            | (HandRank::ThreeOfAKind(_), _)
+            | (HandRank::Straight(_), _)
+            | (HandRank::Flush(_), _)
+            | (HandRank::FullHouse(_), _)
+            | (HandRank::FourOfAKind(_), _)
+            | (HandRank::StraightFlush(_), _) => false,
         }
     }
 }
 
 impl fmt::Display for HandRank {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let printable = match self {
-            HandRank::HighCard(card) => format!("a High Card: {}", card),
-
-            HandRank::Pair([card1, card2]) => {
-                format!("a Pair: {} {}", card1, card2)
-            }
-
-            HandRank::TwoPair([card1, card2, card3, card4]) => {
-                format!("Two Pairs: {} {} {} {}", card1, card2, card3, card4)
-            }
-
-            HandRank::ThreeOfAKind([card1, card2, card3]) => {
-                format!("Three of a Kind: {} {} {}", card1, card2, card3,)
-            }
-
-            HandRank::Straight([card1, card2, card3, card4, card5]) => {
-                format!(
-                    "a Straight: {} {} {} {} {}",
-                    card1, card2, card3, card4, card5
-                )
-            }
-
-            HandRank::Flush([card1, card2, card3, card4, card5]) => {
-                format!("a Flush: {} {} {} {} {}", card1, card2, card3, card4, card5)
-            }
-
-            HandRank::FullHouse([card1, card2, card3, card4, card5]) => format!(
-                "a Full House: {} {} {} {} {}",
-                card1, card2, card3, card4, card5
-            ),
-            HandRank::FourOfAKind([card1, card2, card3, card4]) => {
-                format!("Four of a Kind: {} {} {} {}", card1, card2, card3, card4)
-            }
-
-            HandRank::StraightFlush(cards) => {
-                let is_royal_flush = cards.iter().all(|card| {
-                    card.rank == Rank::Ten
-                        || card.rank == Rank::Jack
-                        || card.rank == Rank::Queen
-                        || card.rank == Rank::King
-                        || card.rank == Rank::Ace
-                });
-
-                let [card1, card2, card3, card4, card5] = cards;
-
-                if is_royal_flush {
-                    format!(
-                        "a Royal Flush: {} {} {} {} {}",
-                        card1, card2, card3, card4, card5
-                    )
-                } else {
-                    format!(
-                        "a Straight Flush: {} {} {} {} {}",
-                        card1, card2, card3, card4, card5
-                    )
-                }
-            }
+        let article = match self {
+            HandRank::TwoPair(_) | HandRank::ThreeOfAKind(_) | HandRank::FourOfAKind(_) => "",
+            _ => "a ",
         };
+        let cards: Vec<String> = self
+            .dominant_cards()
+            .iter()
+            .map(|card| card.to_string())
+            .collect();
 
-        write!(f, "{}", printable)
+        write!(f, "{}{}: {}", article, self.name(), cards.join(" "))
     }
 }
 
 /// Determine the highest value of a hand from the given cards.
+///
+/// Any count from 2 to 7 cards is accepted, so a partially revealed board can be ranked.
+/// Hand ranks that need more cards than were provided are skipped, down to a High Card at minimum.
 pub fn rank_hand(cards: Vec<Card>) -> HandRank {
-    if cards.len() != 2 && cards.len() != 5 && cards.len() != 6 && cards.len() != 7 {
-        panic!("Expected the cards count to be equal to 2 (pre-flop), 5 (post-flop), 6 (post-turn), or 7 (post-river) to rank the hand.\nThe cards count provided was: {}.", cards.len())
+    if !(2..=7).contains(&cards.len()) {
+        panic!("Expected the cards count to be between 2 (pre-flop) and 7 (post-river) to rank the hand.\nThe cards count provided was: {}.", cards.len())
     }
 
     let mut cards = cards.clone();
@@ -353,6 +473,231 @@ pub fn rank_hand(cards: Vec<Card>) -> HandRank {
     }
 }
 
+/// Determine the highest value of an Omaha hand.
+///
+/// Omaha requires exactly 2 of the 4 hole cards and exactly 3 of the 5 table cards to be used,
+/// so every one of the 60 combinations is ranked and the best one is returned.
+///
+/// Example: Four Aces in the hole can only make a Pair of Aces when there are no Aces on the table.
+pub fn rank_omaha_hand(hole_cards: [Card; 4], table_cards: [Card; 5]) -> HandRank {
+    let mut best_hand_rank: Option<HandRank> = None;
+
+    for_each_combination(hole_cards.len(), 2, |hole_indices| {
+        for_each_combination(table_cards.len(), 3, |table_indices| {
+            let cards: Vec<Card> = hole_indices
+                .iter()
+                .map(|&index| hole_cards[index])
+                .chain(table_indices.iter().map(|&index| table_cards[index]))
+                .collect();
+            let hand_rank = rank_hand(cards);
+
+            if best_hand_rank.is_none_or(|best_hand_rank| hand_rank > best_hand_rank) {
+                best_hand_rank = Some(hand_rank);
+            }
+        });
+    });
+
+    best_hand_rank.expect("Every Omaha hand has at least one combination to rank.")
+}
+
+/// Determine the highest value of a hand from the given cards using short-deck (6+) rules.
+///
+/// In short-deck poker a Flush is harder to make than a Full House, so a Flush is chosen
+/// over a Full House when both are present. Use HandRank::cmp_short_deck() to compare the results.
+pub fn rank_hand_short_deck(cards: Vec<Card>) -> HandRank {
+    if !(2..=7).contains(&cards.len()) {
+        panic!("Expected the cards count to be between 2 (pre-flop) and 7 (post-river) to rank the hand.\nThe cards count provided was: {}.", cards.len())
+    }
+
+    let mut cards = cards.clone();
+    cards.sort();
+
+    if let Some(straight_flush_cards) = check_for_straight_flush(&cards) {
+        return HandRank::StraightFlush(straight_flush_cards);
+    }
+
+    if let Some(four_of_a_kind_cards) = check_for_four_of_a_kind(&cards) {
+        return HandRank::FourOfAKind(four_of_a_kind_cards);
+    }
+
+    if let Some(flush_cards) = check_for_flush(&cards) {
+        return HandRank::Flush(flush_cards);
+    }
+
+    if let Some(full_house_cards) = check_for_full_house(&cards) {
+        return HandRank::FullHouse(full_house_cards);
+    }
+
+    rank_hand(cards)
+}
+
+/// Finds the five cards of a hand that make its highest hand rank.
+///
+/// This is a trait because Hand is defined in casino_cards, which does not rank hands.
+pub trait BestFive {
+    /// Get the five cards that make the highest hand rank, using the kickers to decide between equal hand ranks.
+    ///
+    /// Returns None if there are fewer than five cards.
+    fn best_five(&self) -> Option<[Card; 5]>;
+}
+
+impl BestFive for Hand {
+    fn best_five(&self) -> Option<[Card; 5]> {
+        best_five_cards(self.get_cards())
+    }
+}
+
+/// Rank every combination of five cards and return the highest, sorted from lowest to highest.
+///
+/// Seven cards only have 21 combinations, so every one of them is checked.
+pub fn best_five_cards(cards: &[Card]) -> Option<[Card; 5]> {
+    let descending_ranks = |five_cards: &[Card; 5]| -> Vec<Rank> {
+        let mut ranks: Vec<Rank> = five_cards.iter().map(|card| card.rank).collect();
+        ranks.sort_by(|rank1, rank2| rank2.cmp(rank1));
+        ranks
+    };
+
+    let mut best: Option<(HandRank, [Card; 5])> = None;
+    for_each_combination(cards.len(), 5, |indices| {
+        let mut five_cards: [Card; 5] = std::array::from_fn(|position| cards[indices[position]]);
+        five_cards.sort();
+        let hand_rank = rank_hand(five_cards.to_vec());
+
+        let is_better = best.is_none_or(|(best_hand_rank, best_cards)| {
+            hand_rank
+                .cmp(&best_hand_rank)
+                .then_with(|| descending_ranks(&five_cards).cmp(&descending_ranks(&best_cards)))
+                == Ordering::Greater
+        });
+        if is_better {
+            best = Some((hand_rank, five_cards));
+        }
+    });
+
+    best.map(|(_, five_cards)| five_cards)
+}
+
+/// Determine the probability that the cards still to come improve the hand beyond the current hand rank.
+///
+/// The known cards are the player's hole cards and the table cards dealt so far, and the unseen cards are
+/// the cards that may still be dealt. Every combination of cards to come is ranked together with the known
+/// cards, and the fraction of them that rank higher than the current hand rank is returned.
+///
+/// Use sample_probability_of_improvement() when the number of combinations is too large to check them all.
+///
+/// Returns 0.0 if there are fewer unseen cards than cards to come.
+///
+/// Panics if the known cards and the cards to come are not between 2 and 7 cards in total.
+pub fn probability_of_improvement(
+    current: HandRank,
+    known_cards: &[Card],
+    unseen_cards: &[Card],
+    cards_to_come: usize,
+) -> f64 {
+    if cards_to_come > unseen_cards.len() {
+        return 0.0;
+    }
+
+    let mut outcomes_count: u64 = 0;
+    let mut improved_count: u64 = 0;
+    for_each_combination(unseen_cards.len(), cards_to_come, |indices| {
+        let mut cards = known_cards.to_vec();
+        cards.extend(indices.iter().map(|&index| unseen_cards[index]));
+        if rank_hand(cards) > current {
+            improved_count += 1;
+        }
+        outcomes_count += 1;
+    });
+
+    improved_count as f64 / outcomes_count as f64
+}
+
+/// Call the provided function with every combination of the given number of indices from 0 to count,
+/// in lexicographic order.
+///
+/// Choosing zero indices visits a single empty combination, and choosing more than count visits none.
+fn for_each_combination(count: usize, choose: usize, mut visit: impl FnMut(&[usize])) {
+    if choose > count {
+        return;
+    }
+
+    let mut indices: Vec<usize> = (0..choose).collect();
+    loop {
+        visit(&indices);
+
+        // Move to the next combination of indices in lexicographic order.
+        let Some(position) = (0..choose)
+            .rev()
+            .find(|&position| indices[position] < count - choose + position)
+        else {
+            break;
+        };
+        indices[position] += 1;
+        for next_position in position + 1..choose {
+            indices[next_position] = indices[next_position - 1] + 1;
+        }
+    }
+}
+
+/// Estimate the probability that the cards still to come improve the hand beyond the current hand rank.
+///
+/// This is the same as probability_of_improvement(), except that only the provided number of random
+/// samples of the cards to come are ranked, which is much faster when many cards are unseen.
+///
+/// Returns 0.0 if there are fewer unseen cards than cards to come or no samples are taken.
+///
+/// Panics if the known cards and the cards to come are not between 2 and 7 cards in total.
+pub fn sample_probability_of_improvement<R: Rng + ?Sized>(
+    current: HandRank,
+    known_cards: &[Card],
+    unseen_cards: &[Card],
+    cards_to_come: usize,
+    samples_count: usize,
+    rng: &mut R,
+) -> f64 {
+    if cards_to_come > unseen_cards.len() || samples_count == 0 {
+        return 0.0;
+    }
+
+    let improved_count = (0..samples_count)
+        .filter(|_| {
+            let mut cards = known_cards.to_vec();
+            cards.extend(unseen_cards.choose_multiple(rng, cards_to_come));
+            rank_hand(cards) > current
+        })
+        .count();
+
+    improved_count as f64 / samples_count as f64
+}
+
+/// Compare the kickers of two hands that share an equal hand rank.
+///
+/// The cards whose ranks are not part of the hand rank are sorted from highest to lowest and compared
+/// one position at a time until one differs or the five card hand is complete.
+///
+/// Example: Two hands with a pair of Kings and kickers of Queen, Nine, Four and Queen, Eight, Seven
+/// are decided by the second kicker, so the first hand is greater.
+pub fn compare_kickers(
+    hand_rank: &HandRank,
+    all_cards_a: &[Card],
+    all_cards_b: &[Card],
+) -> Ordering {
+    let kicker_count = 5_usize.saturating_sub(hand_rank.len());
+    let hand_rank_ranks = hand_rank.component_ranks();
+    let kicker_ranks = |all_cards: &[Card]| -> Vec<Rank> {
+        let mut ranks: Vec<Rank> = all_cards
+            .iter()
+            .map(|card| card.rank)
+            .filter(|rank| !hand_rank_ranks.contains(rank))
+            .collect();
+        ranks.sort_by(|rank1, rank2| rank2.cmp(rank1));
+        ranks.truncate(kicker_count);
+        ranks
+    };
+
+    kicker_ranks(all_cards_a).cmp(&kicker_ranks(all_cards_b))
+}
+
 /// Determines the HandRank::HighCard by finding the card with the highest rank value.
 ///
 /// Returns: An Option containing the relevant card if any.
@@ -363,7 +708,7 @@ pub fn rank_hand(cards: Vec<Card>) -> HandRank {
 ///
 /// Example: A table with 10 of Clubs, 4 of Hearts, 7 of Diamonds, King of Clubs,
 /// and 2 of Spades will return the King of Clubs.
-pub fn get_high_card_value(cards: &Vec<Card>) -> Option<Card> {
+pub fn get_high_card_value(cards: &[Card]) -> Option<Card> {
     let mut high_card: Option<Card> = None;
 
     for &card in cards {
@@ -381,10 +726,12 @@ pub fn get_high_card_value(cards: &Vec<Card>) -> Option<Card> {
 
 /// Checks if the provided cards contain a HandRank::Pair.
 ///
+/// The cards must be sorted from lowest to highest, as rank_hand() does before calling this.
+///
 /// Returns: An Option containing the relevant cards if any.
 ///
 /// Example: A pair of Kings.
-fn check_for_pair(cards: &Vec<Card>) -> Option<[Card; 2]> {
+fn check_for_pair(cards: &[Card]) -> Option<[Card; 2]> {
     if cards.len() < 2 {
         return None;
     }
@@ -408,8 +755,10 @@ fn check_for_pair(cards: &Vec<Card>) -> Option<[Card; 2]> {
         }
     }
 
-    if high_pair_cards.is_some() {
-        return high_pair_cards;
+    // Order the pair by suit so the result does not depend on the order of the provided cards.
+    if let Some(mut pair_cards) = high_pair_cards {
+        pair_cards.sort_by_key(|card| card.suit);
+        return Some(pair_cards);
     }
 
     None
@@ -417,10 +766,13 @@ fn check_for_pair(cards: &Vec<Card>) -> Option<[Card; 2]> {
 
 /// Checks if the provided cards contain a HandRank::TwoPair.
 ///
+/// When there are three pairs, only the two highest pairs are used. The higher pair always comes first,
+/// followed by the lower pair, which HandRank::cmp() relies on.
+///
 /// Returns: An Option containing the relevant cards if any.
 ///
 /// Example: A pair of Kings and a pair of 7s.
-fn check_for_two_pair(cards: &Vec<Card>) -> Option<[Card; 4]> {
+fn check_for_two_pair(cards: &[Card]) -> Option<[Card; 4]> {
     if cards.len() < 4 {
         return None;
     }
@@ -436,7 +788,7 @@ fn check_for_two_pair(cards: &Vec<Card>) -> Option<[Card; 4]> {
 
         // Remove the highest pair so that calling check_for_pair again will now return the
         // second highest pair.
-        let mut reduced_cards = cards.clone();
+        let mut reduced_cards = cards.to_vec();
         reduced_cards.retain(|&card| card != first_pair_card1 && card != first_pair_card2);
 
         // Retrieve the second highest pair
@@ -465,10 +817,12 @@ fn check_for_two_pair(cards: &Vec<Card>) -> Option<[Card; 4]> {
 
 /// Checks if the provided cards contain a HandRank::ThreeOfAKind.
 ///
+/// The cards must be sorted from lowest to highest, as rank_hand() does before calling this.
+///
 /// Returns: An Option containing the relevant cards if any.
 ///
 /// Example: Three Kings.
-fn check_for_three_of_a_kind(cards: &Vec<Card>) -> Option<[Card; 3]> {
+fn check_for_three_of_a_kind(cards: &[Card]) -> Option<[Card; 3]> {
     if cards.len() < 3 {
         return None;
     }
@@ -510,7 +864,7 @@ fn check_for_three_of_a_kind(cards: &Vec<Card>) -> Option<[Card; 3]> {
 /// Example: An Ace-low straight of Ace (1), 2, 3, 4, 5.
 ///
 /// Example: An Ace-high straight of 10, J (11), Q (12), K (13), Ace (14).
-fn check_for_straight(cards: &Vec<Card>) -> Option<[Card; 5]> {
+fn check_for_straight(cards: &[Card]) -> Option<[Card; 5]> {
     if cards.len() < 5 {
         return None;
     }
@@ -519,11 +873,11 @@ fn check_for_straight(cards: &Vec<Card>) -> Option<[Card; 5]> {
     let mut longest_straight: Vec<Card> = Vec::new();
     let mut current_straight: Vec<Card> = vec![cards[0]];
 
-    for i in 1..cards.len() {
-        let current_rank = cards[i].rank;
+    for &card in cards.iter().skip(1) {
+        let current_rank = card.rank;
         let previous_rank = current_straight.last().unwrap().rank;
         if current_rank.value() == previous_rank.value() + 1 {
-            current_straight.push(cards[i]);
+            current_straight.push(card);
         } else if current_rank == previous_rank {
             // Skip over duplicate values
             continue;
@@ -533,7 +887,7 @@ fn check_for_straight(cards: &Vec<Card>) -> Option<[Card; 5]> {
                 longest_straight = current_straight.clone();
             }
             current_straight.clear();
-            current_straight.push(cards[i]);
+            current_straight.push(card);
         }
     }
 
@@ -584,7 +938,7 @@ fn check_for_straight(cards: &Vec<Card>) -> Option<[Card; 5]> {
 /// Returns: An Option containing the relevant cards if any.
 ///
 /// Example: A flush of K♣ (13♣), Q♣ (12♣), 9♣, 8♣, 2♣.
-fn check_for_flush(cards: &Vec<Card>) -> Option<[Card; 5]> {
+fn check_for_flush(cards: &[Card]) -> Option<[Card; 5]> {
     if cards.len() < 5 {
         return None;
     }
@@ -596,32 +950,11 @@ fn check_for_flush(cards: &Vec<Card>) -> Option<[Card; 5]> {
         suit_entry.push(card);
     }
 
-    for (_suit, cards) in suits.iter() {
+    for (_suit, cards) in suits.iter_mut() {
         if cards.len() >= 5 {
-            // Check for an Ace-low Flush, which helps when this is also a Straight.
-            // This check comes first in the function since the ranks that make up a Flush don't matter,
-            // but the ranks for a Straight Flush do matter.
-            let ace_pos = cards.iter().position(|&card| card.rank == Rank::Ace);
-            let two_pos = cards.iter().position(|&card| card.rank == Rank::Two);
-            let three_pos = cards.iter().position(|&card| card.rank == Rank::Three);
-            let four_pos = cards.iter().position(|&card| card.rank == Rank::Four);
-            let five_pos = cards.iter().position(|&card| card.rank == Rank::Five);
-
-            // Check if all ranks are present
-            if let (Some(ace_pos), Some(two_pos), Some(three_pos), Some(four_pos), Some(five_pos)) =
-                (ace_pos, two_pos, three_pos, four_pos, five_pos)
-            {
-                let straight_cards = [
-                    cards[ace_pos],
-                    cards[two_pos],
-                    cards[three_pos],
-                    cards[four_pos],
-                    cards[five_pos],
-                ];
-
-                return Some(straight_cards);
-            }
-
+            // Sort the suit so the last five are its highest cards, even if the cards were provided out of order.
+            // With six or seven cards of one suit this keeps the best Flush rather than the lowest cards.
+            cards.sort();
             let flush_cards = [
                 cards[cards.len() - 5],
                 cards[cards.len() - 4],
@@ -642,7 +975,7 @@ fn check_for_flush(cards: &Vec<Card>) -> Option<[Card; 5]> {
 /// Returns: An Option containing the relevant cards if any.
 ///
 /// Example: Three Kings and two 7s.
-fn check_for_full_house(cards: &Vec<Card>) -> Option<[Card; 5]> {
+fn check_for_full_house(cards: &[Card]) -> Option<[Card; 5]> {
     if cards.len() < 5 {
         return None;
     }
@@ -658,7 +991,7 @@ fn check_for_full_house(cards: &Vec<Card>) -> Option<[Card; 5]> {
         let three_of_a_kind_card3 = three_of_a_kind_cards[2];
 
         // Remove the three of a kind so that calling check_for_pair will now return the pair.
-        let mut reduced_cards = cards.clone();
+        let mut reduced_cards = cards.to_vec();
         reduced_cards.retain(|&card| {
             card != three_of_a_kind_card1
                 && card != three_of_a_kind_card2
@@ -695,7 +1028,7 @@ fn check_for_full_house(cards: &Vec<Card>) -> Option<[Card; 5]> {
 /// Returns: An Option containing the relevant cards if any.
 ///
 /// Example: Four 6s.
-fn check_for_four_of_a_kind(cards: &Vec<Card>) -> Option<[Card; 4]> {
+fn check_for_four_of_a_kind(cards: &[Card]) -> Option<[Card; 4]> {
     if cards.len() < 4 {
         return None;
     }
@@ -727,23 +1060,50 @@ fn check_for_four_of_a_kind(cards: &Vec<Card>) -> Option<[Card; 4]> {
 /// Example: An Ace-low flush of A♦ (1♦), 2♦, 3♦, 4♦, 5♦.
 ///
 /// Example: An Ace-high flush (aka Royal Flush) of 10♥, J♥ (11♥), Q♥ (12♥), K♥ (13♥) A♥ (14♥).
-fn check_for_straight_flush(cards: &Vec<Card>) -> Option<[Card; 5]> {
+fn check_for_straight_flush(cards: &[Card]) -> Option<[Card; 5]> {
     if cards.len() < 5 {
         return None;
     }
 
-    let straight_cards = check_for_straight(cards);
-    let flush_cards = check_for_flush(cards);
+    let mut suits: HashMap<Suit, Vec<Card>> = HashMap::new();
 
-    // Check if both a straight and a flush are present
-    if let (Some(straight_cards), Some(flush_cards)) = (straight_cards, flush_cards) {
-        // Check if the same set of cards make up both the straight and the flush
-        if straight_cards == flush_cards {
-            return Some(straight_cards);
-        }
+    for &card in cards {
+        let suit_entry = suits.entry(card.suit).or_default();
+        suit_entry.push(card);
     }
 
-    None
+    // Only one suit can have five or more cards out of seven, so the highest Straight within that suit
+    // is the Straight Flush. This finds it even when the suit's highest five cards are not a Straight.
+    suits
+        .values()
+        .filter(|suited_cards| suited_cards.len() >= 5)
+        .find_map(|suited_cards| check_for_straight(suited_cards))
+}
+
+/// The individual hand rank checks used by rank_hand(), for building hand analysis on top of this crate.
+///
+/// Requires the "hand-analysis" feature.
+#[cfg(feature = "hand-analysis")]
+pub mod analysis {
+    use casino_cards::card::Card;
+
+    /// Checks if the provided cards contain a HandRank::Pair.
+    ///
+    /// The cards must be sorted from lowest to highest, such as by calling sort() on them first.
+    ///
+    /// Returns: An Option containing the relevant cards if any.
+    pub fn check_for_pair(cards: &[Card]) -> Option<[Card; 2]> {
+        super::check_for_pair(cards)
+    }
+
+    /// Checks if the provided cards contain a HandRank::ThreeOfAKind.
+    ///
+    /// The cards must be sorted from lowest to highest, such as by calling sort() on them first.
+    ///
+    /// Returns: An Option containing the relevant cards if any.
+    pub fn check_for_three_of_a_kind(cards: &[Card]) -> Option<[Card; 3]> {
+        super::check_for_three_of_a_kind(cards)
+    }
 }
 
 #[cfg(test)]
@@ -755,6 +1115,191 @@ mod tests {
     use casino_cards::card;
     use casino_cards::card::{Card, Rank, Suit};
 
+    /// Tests cards().
+    ///
+    /// Tests that every hand rank returns the cards it is made up of.
+    #[test]
+    fn cards_works() {
+        let high_card = HandRank::HighCard(card!(Ace, Diamond));
+        assert_eq!(high_card.cards(), &[card!(Ace, Diamond)]);
+
+        let pair = HandRank::Pair([card!(King, Club), card!(King, Heart)]);
+        assert_eq!(pair.cards(), &[card!(King, Club), card!(King, Heart)]);
+
+        let three_of_a_kind =
+            HandRank::ThreeOfAKind([card!(Nine, Club), card!(Nine, Heart), card!(Nine, Spade)]);
+        assert_eq!(three_of_a_kind.cards().len(), 3);
+
+        let two_pair = HandRank::TwoPair([
+            card!(Seven, Club),
+            card!(Seven, Heart),
+            card!(King, Club),
+            card!(King, Heart),
+        ]);
+        let four_of_a_kind = HandRank::FourOfAKind([
+            card!(Four, Club),
+            card!(Four, Diamond),
+            card!(Four, Heart),
+            card!(Four, Spade),
+        ]);
+        assert_eq!(two_pair.cards().len(), 4);
+        assert_eq!(four_of_a_kind.cards().len(), 4);
+
+        let five_cards = [
+            card!(Two, Heart),
+            card!(Three, Heart),
+            card!(Four, Heart),
+            card!(Five, Heart),
+            card!(Six, Heart),
+        ];
+        for hand_rank in [
+            HandRank::Straight(five_cards),
+            HandRank::Flush(five_cards),
+            HandRank::FullHouse(five_cards),
+            HandRank::StraightFlush(five_cards),
+        ] {
+            assert_eq!(hand_rank.cards(), &five_cards);
+            assert_eq!(hand_rank.cards().len(), hand_rank.len());
+        }
+    }
+
+    /// Tests probability_of_improvement() and sample_probability_of_improvement().
+    ///
+    /// Tests that the probability of improving a flush draw on the turn counts every heart and every card that pairs.
+    #[test]
+    fn probability_of_improvement_works() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let known_cards = vec![
+            card!(Ace, Heart),
+            card!(King, Heart),
+            card!(Seven, Heart),
+            card!(Two, Heart),
+            card!(Nine, Club),
+        ];
+        let unseen_cards: Vec<Card> = Suit::iter()
+            .flat_map(|suit| Rank::iter().map(move |rank| Card::new(rank, suit)))
+            .filter(|card| !known_cards.contains(card))
+            .collect();
+        let current = rank_hand(known_cards.clone());
+        assert_eq!(current, HandRank::HighCard(card!(Ace, Heart)));
+
+        // 9 Hearts and 14 other cards that pair one of the known cards.
+        let probability = probability_of_improvement(current, &known_cards, &unseen_cards, 1);
+        assert!((probability - 23.0 / 47.0).abs() < f64::EPSILON);
+
+        let exact_probability = probability_of_improvement(current, &known_cards, &unseen_cards, 2);
+        let mut rng = StdRng::seed_from_u64(7);
+        let sampled_probability = sample_probability_of_improvement(
+            current,
+            &known_cards,
+            &unseen_cards,
+            2,
+            5000,
+            &mut rng,
+        );
+        assert!(exact_probability > probability);
+        assert!((sampled_probability - exact_probability).abs() < 0.05);
+
+        assert_eq!(
+            probability_of_improvement(current, &known_cards, &unseen_cards[..1], 2),
+            0.0
+        );
+        assert_eq!(
+            sample_probability_of_improvement(current, &known_cards, &unseen_cards, 1, 0, &mut rng),
+            0.0
+        );
+    }
+
+    /// Tests dominant_cards() and kicker_cards_from().
+    ///
+    /// Tests that the cards making up the hand rank are separated from the kickers.
+    #[test]
+    fn dominant_cards_and_kicker_cards_from_work() {
+        let two_of_diamonds = card!(Two, Diamond);
+        let seven_of_clubs = card!(Seven, Club);
+        let nine_of_hearts = card!(Nine, Heart);
+        let jack_of_spades = card!(Jack, Spade);
+        let king_of_clubs = card!(King, Club);
+        let king_of_hearts = card!(King, Heart);
+        let ace_of_diamonds = card!(Ace, Diamond);
+
+        let pair = HandRank::Pair([king_of_clubs, king_of_hearts]);
+        assert_eq!(pair.dominant_cards(), &[king_of_clubs, king_of_hearts]);
+
+        let high_card = HandRank::HighCard(ace_of_diamonds);
+        assert_eq!(high_card.dominant_cards(), &[ace_of_diamonds]);
+
+        let cards: Vec<Card> = vec![
+            nine_of_hearts,
+            king_of_clubs,
+            two_of_diamonds,
+            ace_of_diamonds,
+            seven_of_clubs,
+            king_of_hearts,
+            jack_of_spades,
+        ];
+
+        assert_eq!(
+            pair.kicker_cards_from(&cards),
+            vec![
+                ace_of_diamonds,
+                jack_of_spades,
+                nine_of_hearts,
+                seven_of_clubs,
+                two_of_diamonds,
+            ]
+        );
+    }
+
+    /// Tests best_from_all_combinations().
+    ///
+    /// Tests that the best HandRank matches rank_hand() and that fewer than five cards returns None.
+    #[test]
+    fn best_from_all_combinations_works() {
+        let two_of_diamonds = card!(Two, Diamond);
+        let three_of_clubs = card!(Three, Club);
+        let eight_of_spades = card!(Eight, Spade);
+        let nine_of_clubs = card!(Nine, Club);
+        let jack_of_clubs = card!(Jack, Club);
+        let king_of_clubs = card!(King, Club);
+        let king_of_hearts = card!(King, Heart);
+        let ace_of_clubs = card!(Ace, Club);
+
+        let flush = HandRank::Flush([
+            three_of_clubs,
+            nine_of_clubs,
+            jack_of_clubs,
+            king_of_clubs,
+            ace_of_clubs,
+        ]);
+
+        let cards: Vec<Card> = vec![
+            two_of_diamonds,
+            three_of_clubs,
+            eight_of_spades,
+            nine_of_clubs,
+            jack_of_clubs,
+            king_of_clubs,
+            ace_of_clubs,
+        ];
+
+        assert_eq!(HandRank::best_from_all_combinations(&cards), Some(flush));
+        assert_eq!(
+            HandRank::best_from_all_combinations(&cards),
+            Some(rank_hand(cards.clone()))
+        );
+
+        // Tests that more than seven cards can be ranked.
+        let mut cards2 = cards.clone();
+        cards2.push(king_of_hearts);
+
+        assert_eq!(HandRank::best_from_all_combinations(&cards2), Some(flush));
+
+        assert_eq!(HandRank::best_from_all_combinations(&cards[..4]), None);
+    }
+
     /// Tests that High Cards of the same Rank are equal, regardless of Suit.
     #[test]
     fn high_card_ranks_are_compared_correctly() {
@@ -896,47 +1441,207 @@ mod tests {
             card!(Queen, Heart),
             card!(King, Heart),
             card!(Ace, Heart),
-        ]);
+        ]);
+
+        assert!(high_card < pair);
+        assert!(pair < two_pair);
+        assert!(two_pair < three_of_a_kind);
+        assert!(three_of_a_kind < straight);
+        assert!(straight < flush);
+        assert!(flush < full_house);
+        assert!(full_house < four_of_a_kind);
+        assert!(four_of_a_kind < straight_flush);
+        assert!(straight_flush < royal_flush);
+    }
+
+    #[test]
+    fn straight_ace_low_straight_is_valued_lower_than_higher_straights() {
+        let ace_low_straight = HandRank::Straight([
+            card!(Ace, Club),
+            card!(Two, Spade),
+            card!(Three, Club),
+            card!(Four, Heart),
+            card!(Five, Diamond),
+        ]);
+
+        let two_six_straight = HandRank::Straight([
+            card!(Two, Spade),
+            card!(Three, Club),
+            card!(Four, Heart),
+            card!(Five, Diamond),
+            card!(Six, Club),
+        ]);
+
+        let ace_high_straight = HandRank::Straight([
+            card!(Ten, Club),
+            card!(Jack, Heart),
+            card!(Queen, Diamond),
+            card!(King, Spade),
+            card!(Ace, Club),
+        ]);
+
+        assert!(ace_low_straight < two_six_straight);
+        assert!(two_six_straight < ace_high_straight);
+    }
+
+    /// Tests is_royal_flush().
+    ///
+    /// Tests that only a Ten through Ace Straight Flush is a Royal Flush.
+    #[test]
+    fn is_royal_flush_works() {
+        let nine_high_straight_flush = HandRank::StraightFlush([
+            card!(Five, Club),
+            card!(Six, Club),
+            card!(Seven, Club),
+            card!(Eight, Club),
+            card!(Nine, Club),
+        ]);
+        let royal_flush = HandRank::StraightFlush([
+            card!(Ten, Heart),
+            card!(Jack, Heart),
+            card!(Queen, Heart),
+            card!(King, Heart),
+            card!(Ace, Heart),
+        ]);
+        let ace_high_straight = HandRank::Straight([
+            card!(Ten, Heart),
+            card!(Jack, Spade),
+            card!(Queen, Heart),
+            card!(King, Heart),
+            card!(Ace, Heart),
+        ]);
+
+        assert!(!nine_high_straight_flush.is_royal_flush());
+        assert!(royal_flush.is_royal_flush());
+        assert!(!ace_high_straight.is_royal_flush());
+    }
+
+    /// Tests rank_omaha_hand().
+    ///
+    /// Tests that only two of the four Aces in the hole can be used, so they make a Pair instead of Four of a Kind.
+    #[test]
+    fn rank_omaha_hand_uses_exactly_two_hole_cards() {
+        let hole_cards = [
+            card!(Ace, Club),
+            card!(Ace, Diamond),
+            card!(Ace, Heart),
+            card!(Ace, Spade),
+        ];
+        let table_cards = [
+            card!(Two, Club),
+            card!(Seven, Diamond),
+            card!(Nine, Heart),
+            card!(Jack, Spade),
+            card!(Four, Club),
+        ];
+
+        let hand_rank = rank_omaha_hand(hole_cards, table_cards);
+
+        assert!(matches!(hand_rank, HandRank::Pair(_)));
+        assert_eq!(hand_rank.component_ranks(), vec![Rank::Ace, Rank::Ace]);
+    }
+
+    /// Tests rank_omaha_hand().
+    ///
+    /// Tests that four suited table cards do not make a Flush with only one suited hole card.
+    #[test]
+    fn rank_omaha_hand_uses_exactly_three_table_cards() {
+        let hole_cards = [
+            card!(Ace, Heart),
+            card!(King, Club),
+            card!(Eight, Spade),
+            card!(Three, Diamond),
+        ];
+        let table_cards = [
+            card!(Two, Heart),
+            card!(Six, Heart),
+            card!(Nine, Heart),
+            card!(Jack, Heart),
+            card!(Queen, Spade),
+        ];
 
-        assert!(high_card < pair);
-        assert!(pair < two_pair);
-        assert!(two_pair < three_of_a_kind);
-        assert!(three_of_a_kind < straight);
-        assert!(straight < flush);
-        assert!(flush < full_house);
-        assert!(full_house < four_of_a_kind);
-        assert!(four_of_a_kind < straight_flush);
-        assert!(straight_flush < royal_flush);
+        let hand_rank = rank_omaha_hand(hole_cards, table_cards);
+
+        assert!(matches!(hand_rank, HandRank::HighCard(_)));
+        assert_eq!(hand_rank.component_ranks(), vec![Rank::Ace]);
     }
 
+    /// Tests rank_hand().
+    ///
+    /// Tests that 3 and 4 card hands are ranked without needing a full board.
     #[test]
-    fn straight_ace_low_straight_is_valued_lower_than_higher_straights() {
-        let ace_low_straight = HandRank::Straight([
-            card!(Ace, Club),
-            card!(Two, Spade),
-            card!(Three, Club),
-            card!(Four, Heart),
-            card!(Five, Diamond),
-        ]);
+    fn rank_hand_accepts_partial_board() {
+        let three_cards = vec![card!(Ace, Heart), card!(Ace, Spade), card!(Nine, Club)];
+        assert_eq!(
+            rank_hand(three_cards),
+            HandRank::Pair([card!(Ace, Heart), card!(Ace, Spade)])
+        );
 
-        let two_six_straight = HandRank::Straight([
-            card!(Two, Spade),
-            card!(Three, Club),
-            card!(Four, Heart),
-            card!(Five, Diamond),
-            card!(Six, Club),
-        ]);
+        let four_cards = vec![
+            card!(Two, Heart),
+            card!(Five, Spade),
+            card!(Nine, Club),
+            card!(Jack, Diamond),
+        ];
+        assert_eq!(
+            rank_hand(four_cards),
+            HandRank::HighCard(card!(Jack, Diamond))
+        );
+    }
 
-        let ace_high_straight = HandRank::Straight([
-            card!(Ten, Club),
-            card!(Jack, Heart),
+    /// Tests compare_kickers().
+    ///
+    /// Tests that the second kicker decides between equal pairs with the same first kicker.
+    #[test]
+    fn compare_kickers_uses_second_kicker() {
+        let table_cards = [
+            card!(King, Club),
             card!(Queen, Diamond),
-            card!(King, Spade),
-            card!(Ace, Club),
-        ]);
+            card!(Two, Heart),
+            card!(Three, Spade),
+            card!(Five, Club),
+        ];
+        let mut cards_a = table_cards.to_vec();
+        cards_a.extend([card!(King, Heart), card!(Nine, Spade)]);
+        let mut cards_b = table_cards.to_vec();
+        cards_b.extend([card!(King, Spade), card!(Eight, Heart)]);
 
-        assert!(ace_low_straight < two_six_straight);
-        assert!(two_six_straight < ace_high_straight);
+        let hand_rank = rank_hand(cards_a.clone());
+        assert_eq!(hand_rank, rank_hand(cards_b.clone()));
+
+        assert_eq!(
+            compare_kickers(&hand_rank, &cards_a, &cards_b),
+            Ordering::Greater
+        );
+        assert_eq!(
+            compare_kickers(&hand_rank, &cards_b, &cards_a),
+            Ordering::Less
+        );
+    }
+
+    /// Tests compare_kickers().
+    ///
+    /// Tests that kickers beyond the best five cards do not break a tie.
+    #[test]
+    fn compare_kickers_ignores_cards_outside_best_five() {
+        let table_cards = [
+            card!(King, Club),
+            card!(Queen, Diamond),
+            card!(Jack, Heart),
+            card!(Ten, Spade),
+            card!(Two, Club),
+        ];
+        let mut cards_a = table_cards.to_vec();
+        cards_a.extend([card!(King, Heart), card!(Four, Spade)]);
+        let mut cards_b = table_cards.to_vec();
+        cards_b.extend([card!(King, Spade), card!(Three, Heart)]);
+
+        let hand_rank = rank_hand(cards_a.clone());
+
+        assert_eq!(
+            compare_kickers(&hand_rank, &cards_a, &cards_b),
+            Ordering::Equal
+        );
     }
 
     /// Tests get_high_card_value().
@@ -1041,6 +1746,21 @@ mod tests {
         };
     }
 
+    #[test]
+    fn check_for_pair_orders_cards_by_suit() {
+        let cards: Vec<Card> = vec![
+            card!(King, Spade),
+            card!(Two, Club),
+            card!(King, Diamond),
+            card!(Seven, Heart),
+        ];
+
+        assert_eq!(
+            check_for_pair(&cards),
+            Some([card!(King, Diamond), card!(King, Spade)])
+        );
+    }
+
     /// Tests rank_hand().
     ///
     /// Tests if a hand containing a Pair is ranked correctly.
@@ -1132,6 +1852,55 @@ mod tests {
         };
     }
 
+    /// Tests check_for_two_pair().
+    ///
+    /// Tests that the two highest of three pairs are used with the higher pair first.
+    #[test]
+    fn check_for_two_pair_with_three_pairs_orders_higher_pair_first() {
+        let king_of_clubs = card!(King, Club);
+        let king_of_hearts = card!(King, Heart);
+        let queen_of_diamonds = card!(Queen, Diamond);
+        let queen_of_spades = card!(Queen, Spade);
+        let jack_of_clubs = card!(Jack, Club);
+        let jack_of_hearts = card!(Jack, Heart);
+        let two_of_spades = card!(Two, Spade);
+
+        let mut cards: Vec<Card> = vec![
+            jack_of_hearts,
+            queen_of_spades,
+            two_of_spades,
+            king_of_hearts,
+            jack_of_clubs,
+            queen_of_diamonds,
+            king_of_clubs,
+        ];
+        cards.sort();
+
+        if let Some(result) = check_for_two_pair(&cards) {
+            assert_eq!(
+                result,
+                [
+                    king_of_clubs,
+                    king_of_hearts,
+                    queen_of_diamonds,
+                    queen_of_spades
+                ]
+            );
+        } else {
+            panic!("Expected a Two Pair, but none was found.");
+        };
+
+        let kings_and_queens = rank_hand(cards);
+        let kings_and_jacks = rank_hand(vec![
+            king_of_clubs,
+            king_of_hearts,
+            jack_of_clubs,
+            jack_of_hearts,
+            two_of_spades,
+        ]);
+        assert!(kings_and_queens > kings_and_jacks);
+    }
+
     /// Tests rank_hand().
     ///
     /// Tests if a hand containing a Pair is ranked correctly.
@@ -1865,6 +2634,111 @@ mod tests {
         }
     }
 
+    /// Tests check_for_straight().
+    ///
+    /// Tests that an Ace that is not part of the Straight does not prevent a Straight from being identified.
+    #[test]
+    fn check_for_straight_with_unused_ace_works() {
+        let ace_of_spades = card!(Ace, Spade);
+        let three_of_hearts = card!(Three, Heart);
+        let four_of_diamonds = card!(Four, Diamond);
+        let five_of_clubs = card!(Five, Club);
+        let six_of_diamonds = card!(Six, Diamond);
+        let seven_of_spades = card!(Seven, Spade);
+        let king_of_hearts = card!(King, Heart);
+
+        let straight = [
+            three_of_hearts,
+            four_of_diamonds,
+            five_of_clubs,
+            six_of_diamonds,
+            seven_of_spades,
+        ];
+
+        // The hole cards are the A♠ and 3♥ and the table cards are 4♦, 5♣, 6♦, 7♠, and K♥.
+        let mut cards: Vec<Card> = vec![
+            ace_of_spades,
+            three_of_hearts,
+            four_of_diamonds,
+            five_of_clubs,
+            six_of_diamonds,
+            seven_of_spades,
+            king_of_hearts,
+        ];
+        cards.sort();
+
+        if let Some(result) = check_for_straight(&cards) {
+            assert_eq!(result, straight);
+        } else {
+            panic!("Expected a Straight, but none was found.");
+        }
+
+        assert_eq!(rank_hand(cards), HandRank::Straight(straight));
+    }
+
+    /// Tests check_for_straight().
+    ///
+    /// Tests that an Ace only bridges to a Two or a King and never wraps around to form a Straight.
+    #[test]
+    fn check_for_straight_ace_does_not_wrap_around() {
+        let two_of_clubs = card!(Two, Club);
+        let three_of_hearts = card!(Three, Heart);
+        let four_of_diamonds = card!(Four, Diamond);
+        let five_of_spades = card!(Five, Spade);
+        let queen_of_hearts = card!(Queen, Heart);
+        let king_of_clubs = card!(King, Club);
+        let ace_of_spades = card!(Ace, Spade);
+
+        let ace_low_straight = [
+            ace_of_spades,
+            two_of_clubs,
+            three_of_hearts,
+            four_of_diamonds,
+            five_of_spades,
+        ];
+
+        // Tests that the K♣ is ignored, and the Ace-low Straight is identified instead of K, A, 2, 3, 4.
+        let mut cards: Vec<Card> = vec![
+            ace_of_spades,
+            two_of_clubs,
+            three_of_hearts,
+            four_of_diamonds,
+            five_of_spades,
+            king_of_clubs,
+        ];
+        cards.sort();
+
+        if let Some(result) = check_for_straight(&cards) {
+            assert_eq!(result, ace_low_straight);
+        } else {
+            panic!("Expected a Straight, but none was found.");
+        }
+
+        // Tests that Q, K, A, 2, 3 is not identified as a Straight.
+        let mut cards2: Vec<Card> = vec![
+            queen_of_hearts,
+            king_of_clubs,
+            ace_of_spades,
+            two_of_clubs,
+            three_of_hearts,
+        ];
+        cards2.sort();
+
+        assert_eq!(check_for_straight(&cards2), None);
+
+        // Tests that K, A, 2, 3, 4 is not identified as a Straight.
+        let mut cards3: Vec<Card> = vec![
+            king_of_clubs,
+            ace_of_spades,
+            two_of_clubs,
+            three_of_hearts,
+            four_of_diamonds,
+        ];
+        cards3.sort();
+
+        assert_eq!(check_for_straight(&cards3), None);
+    }
+
     /// Tests rank_hand().
     ///
     /// Tests if a hand containing an Ace-low Straight is ranked correctly.
@@ -2534,18 +3408,68 @@ mod tests {
         }
     }
 
-    /// Tests rank_hand().
+    /// Tests rank_hand().
+    ///
+    /// Tests if a hand containing a Full House is ranked correctly.
+    #[test]
+    fn rank_hand_full_house_works() {
+        let three_of_clubs = card!(Three, Club);
+        let three_of_spades = card!(Three, Spade);
+        let seven_of_clubs = card!(Seven, Club);
+        let seven_of_spades = card!(Seven, Spade);
+        let king_of_clubs = card!(King, Club);
+        let king_of_diamonds = card!(King, Diamond);
+        let king_of_hearts = card!(King, Heart);
+
+        let full_house = HandRank::FullHouse([
+            king_of_clubs,
+            king_of_diamonds,
+            king_of_hearts,
+            seven_of_clubs,
+            seven_of_spades,
+        ]);
+
+        // Base case
+        let cards: Vec<Card> = vec![
+            king_of_clubs,
+            king_of_hearts,
+            king_of_diamonds,
+            seven_of_clubs,
+            seven_of_spades,
+        ];
+
+        let hand_rank = rank_hand(cards);
+        assert_eq!(hand_rank, full_house);
+
+        // Tests that the higher Full House consisting of Ks & 7s is identified over the lower Full House containing 3s.
+        let cards2: Vec<Card> = vec![
+            three_of_clubs,
+            three_of_spades,
+            king_of_clubs,
+            king_of_hearts,
+            king_of_diamonds,
+            seven_of_clubs,
+            seven_of_spades,
+        ];
+
+        let hand_rank2 = rank_hand(cards2);
+        assert_eq!(hand_rank2, full_house);
+    }
+
+    /// Tests rank_hand_short_deck().
     ///
-    /// Tests if a hand containing a Full House is ranked correctly.
+    /// Tests that hands are ranked the same as rank_hand() but that a Flush beats a Full House when compared.
     #[test]
-    fn rank_hand_full_house_works() {
-        let three_of_clubs = card!(Three, Club);
-        let three_of_spades = card!(Three, Spade);
+    fn rank_hand_short_deck_flush_beats_full_house() {
+        let six_of_clubs = card!(Six, Club);
         let seven_of_clubs = card!(Seven, Club);
         let seven_of_spades = card!(Seven, Spade);
+        let nine_of_clubs = card!(Nine, Club);
+        let ten_of_hearts = card!(Ten, Heart);
         let king_of_clubs = card!(King, Club);
         let king_of_diamonds = card!(King, Diamond);
         let king_of_hearts = card!(King, Heart);
+        let ace_of_clubs = card!(Ace, Club);
 
         let full_house = HandRank::FullHouse([
             king_of_clubs,
@@ -2555,31 +3479,43 @@ mod tests {
             seven_of_spades,
         ]);
 
-        // Base case
+        let flush = HandRank::Flush([
+            six_of_clubs,
+            seven_of_clubs,
+            nine_of_clubs,
+            king_of_clubs,
+            ace_of_clubs,
+        ]);
+
         let cards: Vec<Card> = vec![
             king_of_clubs,
             king_of_hearts,
             king_of_diamonds,
             seven_of_clubs,
             seven_of_spades,
+            ten_of_hearts,
+            ace_of_clubs,
         ];
 
-        let hand_rank = rank_hand(cards);
+        let hand_rank = rank_hand_short_deck(cards);
         assert_eq!(hand_rank, full_house);
 
-        // Tests that the higher Full House consisting of Ks & 7s is identified over the lower Full House containing 3s.
         let cards2: Vec<Card> = vec![
-            three_of_clubs,
-            three_of_spades,
-            king_of_clubs,
-            king_of_hearts,
-            king_of_diamonds,
+            six_of_clubs,
             seven_of_clubs,
+            nine_of_clubs,
+            king_of_clubs,
+            ace_of_clubs,
             seven_of_spades,
+            ten_of_hearts,
         ];
 
-        let hand_rank2 = rank_hand(cards2);
-        assert_eq!(hand_rank2, full_house);
+        let hand_rank2 = rank_hand_short_deck(cards2);
+        assert_eq!(hand_rank2, flush);
+
+        assert!(flush < full_house);
+        assert_eq!(flush.cmp_short_deck(&full_house), Ordering::Greater);
+        assert_eq!(full_house.cmp_short_deck(&flush), Ordering::Less);
     }
 
     /// Tests check_for_four_of_a_kind().
@@ -3218,4 +4154,498 @@ mod tests {
             assert!(hand_rank1.cmp(&hand_rank2) == Ordering::Equal);
         }
     }
+
+    #[test]
+    fn variant_name_excludes_card_details() {
+        let hand_rank = HandRank::Pair([card!(King, Club), card!(King, Heart)]);
+        assert_eq!(hand_rank.variant_name(), "Pair");
+
+        let hand_rank = HandRank::TwoPair([
+            card!(King, Club),
+            card!(King, Heart),
+            card!(Two, Club),
+            card!(Two, Spade),
+        ]);
+        assert_eq!(hand_rank.variant_name(), "TwoPair");
+
+        let hand_rank = HandRank::HighCard(card!(Ace, Spade));
+        assert_eq!(hand_rank.variant_name(), "HighCard");
+    }
+
+    #[test]
+    fn name_distinguishes_royal_flush() {
+        let hand_rank = HandRank::TwoPair([
+            card!(King, Club),
+            card!(King, Heart),
+            card!(Two, Club),
+            card!(Two, Spade),
+        ]);
+        assert_eq!(hand_rank.name(), "Two Pair");
+        assert_eq!(
+            hand_rank.to_string(),
+            format!(
+                "Two Pair: {} {} {} {}",
+                card!(King, Club),
+                card!(King, Heart),
+                card!(Two, Club),
+                card!(Two, Spade)
+            )
+        );
+
+        let straight_flush = HandRank::StraightFlush([
+            card!(Nine, Heart),
+            card!(Ten, Heart),
+            card!(Jack, Heart),
+            card!(Queen, Heart),
+            card!(King, Heart),
+        ]);
+        assert_eq!(straight_flush.name(), "Straight Flush");
+        assert!(straight_flush.to_string().starts_with("a Straight Flush: "));
+
+        let royal_flush = HandRank::StraightFlush([
+            card!(Ten, Heart),
+            card!(Jack, Heart),
+            card!(Queen, Heart),
+            card!(King, Heart),
+            card!(Ace, Heart),
+        ]);
+        assert_eq!(royal_flush.name(), "Royal Flush");
+        assert!(royal_flush.to_string().starts_with("a Royal Flush: "));
+        assert_eq!(royal_flush.variant_name(), "StraightFlush");
+    }
+
+    #[test]
+    fn approximate_win_probability_works() {
+        let high_card = HandRank::HighCard(card!(Ace, Spade));
+        let pair = HandRank::Pair([card!(King, Club), card!(King, Heart)]);
+        let royal_flush = HandRank::StraightFlush([
+            card!(Ten, Spade),
+            card!(Jack, Spade),
+            card!(Queen, Spade),
+            card!(King, Spade),
+            card!(Ace, Spade),
+        ]);
+
+        assert_eq!(high_card.rank_class(), 0);
+        assert_eq!(royal_flush.rank_class(), 8);
+
+        assert_eq!(pair.approximate_win_probability(0), 1.0);
+        assert!(pair.approximate_win_probability(1) > high_card.approximate_win_probability(1));
+        assert!(pair.approximate_win_probability(1) > pair.approximate_win_probability(5));
+        assert_eq!(
+            pair.approximate_win_probability(20),
+            pair.approximate_win_probability(9)
+        );
+        assert!(royal_flush.approximate_win_probability(9) > pair.approximate_win_probability(9));
+        assert_eq!(royal_flush.approximate_win_probability(9), 1.0);
+    }
+
+    /// Tests cmp().
+    ///
+    /// Tests that a Straight ranks below every higher hand rank.
+    #[test]
+    fn cmp_ranks_straight_below_higher_hand_ranks() {
+        let straight = HandRank::Straight([
+            card!(Six, Club),
+            card!(Seven, Heart),
+            card!(Eight, Spade),
+            card!(Nine, Diamond),
+            card!(Ten, Club),
+        ]);
+        let three_of_a_kind =
+            HandRank::ThreeOfAKind([card!(Ace, Club), card!(Ace, Heart), card!(Ace, Spade)]);
+        let flush = HandRank::Flush([
+            card!(Two, Heart),
+            card!(Four, Heart),
+            card!(Seven, Heart),
+            card!(Nine, Heart),
+            card!(Jack, Heart),
+        ]);
+        let full_house = HandRank::FullHouse([
+            card!(Two, Club),
+            card!(Two, Heart),
+            card!(Two, Spade),
+            card!(Three, Club),
+            card!(Three, Heart),
+        ]);
+        let four_of_a_kind = HandRank::FourOfAKind([
+            card!(Two, Club),
+            card!(Two, Diamond),
+            card!(Two, Heart),
+            card!(Two, Spade),
+        ]);
+
+        assert!(straight > three_of_a_kind);
+        for higher_hand_rank in [flush, full_house, four_of_a_kind] {
+            assert!(straight < higher_hand_rank);
+            assert!(higher_hand_rank > straight);
+        }
+    }
+
+    /// Tests approximate_win_probability().
+    ///
+    /// Tests that a higher hand rank never has a lower win probability and more opponents never raise it.
+    #[test]
+    fn approximate_win_probability_is_monotonic() {
+        for rank_class in 0..WIN_PROBABILITIES.len() {
+            for opponents_index in 0..WIN_PROBABILITIES[rank_class].len() {
+                let probability = WIN_PROBABILITIES[rank_class][opponents_index];
+                assert!((0.0..=1.0).contains(&probability));
+
+                if rank_class > 0 {
+                    assert!(probability >= WIN_PROBABILITIES[rank_class - 1][opponents_index]);
+                }
+
+                if opponents_index > 0 {
+                    assert!(probability <= WIN_PROBABILITIES[rank_class][opponents_index - 1]);
+                }
+            }
+        }
+    }
+
+    /// Tests the WIN_PROBABILITIES table used by approximate_win_probability().
+    ///
+    /// Tests that the table matches a simulation of random hands played to showdown, allowing for the sampling
+    /// error of rare hand ranks and the smoothing of the table.
+    /// For every number of opponents, the hands are dealt from a shuffled deck with five shared table cards.
+    /// A hand wins when it beats every opponent, including kickers, and a tie is counted as a shared win.
+    /// The simulation takes a while, so run it with `cargo test --release -- --ignored`.
+    #[test]
+    #[ignore]
+    fn win_probabilities_match_simulation() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        const DEALS_PER_OPPONENTS_COUNT: usize = 1_000_000;
+
+        let mut rng = StdRng::seed_from_u64(2130);
+        let mut deck: Vec<Card> = Suit::iter()
+            .flat_map(|suit| Rank::iter().map(move |rank| Card::new(rank, suit)))
+            .collect();
+
+        let mut simulated = [[0.0; 9]; 9];
+        let mut hands_counts = [[0_u32; 9]; 9];
+        for opponents_count in 1..=9 {
+            let mut wins = [0.0; 9];
+
+            for _ in 0..DEALS_PER_OPPONENTS_COUNT {
+                deck.shuffle(&mut rng);
+                let (table_cards, hole_cards) = deck.split_at(5);
+                let all_cards: Vec<Vec<Card>> = (0..=opponents_count)
+                    .map(|player_index| {
+                        let mut cards = table_cards.to_vec();
+                        cards
+                            .extend_from_slice(&hole_cards[player_index * 2..player_index * 2 + 2]);
+                        cards
+                    })
+                    .collect();
+                let hand_ranks: Vec<HandRank> = all_cards
+                    .iter()
+                    .map(|cards| rank_hand(cards.clone()))
+                    .collect();
+
+                let mut tied_count = 0;
+                let mut lost = false;
+                for opponent_index in 1..=opponents_count {
+                    let ordering = hand_ranks[0]
+                        .cmp(&hand_ranks[opponent_index])
+                        .then_with(|| {
+                            compare_kickers(
+                                &hand_ranks[0],
+                                &all_cards[0],
+                                &all_cards[opponent_index],
+                            )
+                        });
+                    match ordering {
+                        Ordering::Less => lost = true,
+                        Ordering::Equal => tied_count += 1,
+                        Ordering::Greater => {}
+                    }
+                }
+
+                let rank_class = hand_ranks[0].rank_class();
+                hands_counts[rank_class][opponents_count - 1] += 1;
+                if !lost {
+                    wins[rank_class] += 1.0 / (tied_count + 1) as f64;
+                }
+            }
+
+            for rank_class in 0..9 {
+                let hands_count = hands_counts[rank_class][opponents_count - 1];
+                if hands_count > 0 {
+                    simulated[rank_class][opponents_count - 1] =
+                        wins[rank_class] / hands_count as f64;
+                }
+            }
+        }
+
+        for (rank_class, row) in simulated.iter().enumerate() {
+            let row: Vec<String> = row
+                .iter()
+                .map(|probability| format!("{:.2}", probability))
+                .collect();
+            println!("{}: [{}],", rank_class, row.join(", "));
+        }
+
+        for rank_class in 0..9 {
+            for opponents_index in 0..9 {
+                let difference = (simulated[rank_class][opponents_index]
+                    - WIN_PROBABILITIES[rank_class][opponents_index])
+                    .abs();
+                // Allow three standard errors of the simulated probability, plus rounding and smoothing.
+                let hands_count = hands_counts[rank_class][opponents_index];
+                let tolerance = 0.01 + 3.0 * (0.25 / hands_count as f64).sqrt();
+                assert!(
+                    difference <= tolerance,
+                    "The simulated probability for rank class {} against {} opponents was {:.3}.",
+                    rank_class,
+                    opponents_index + 1,
+                    simulated[rank_class][opponents_index]
+                );
+            }
+        }
+    }
+
+    /// Tests component_ranks().
+    ///
+    /// Tests that the ranks are grouped by how often they appear and then sorted from highest to lowest.
+    #[test]
+    fn component_ranks_works() {
+        let pair = HandRank::Pair([card!(King, Club), card!(King, Heart)]);
+        assert_eq!(pair.component_ranks(), vec![Rank::King, Rank::King]);
+
+        let two_pair = HandRank::TwoPair([
+            card!(Seven, Club),
+            card!(Seven, Heart),
+            card!(King, Club),
+            card!(King, Heart),
+        ]);
+        assert_eq!(
+            two_pair.component_ranks(),
+            vec![Rank::King, Rank::King, Rank::Seven, Rank::Seven]
+        );
+
+        let full_house = HandRank::FullHouse([
+            card!(King, Club),
+            card!(King, Heart),
+            card!(King, Spade),
+            card!(Seven, Club),
+            card!(Seven, Heart),
+        ]);
+        assert_eq!(
+            full_house.component_ranks(),
+            vec![Rank::King, Rank::King, Rank::King, Rank::Seven, Rank::Seven]
+        );
+
+        let ace_low_straight = HandRank::Straight([
+            card!(Ace, Club),
+            card!(Two, Heart),
+            card!(Three, Spade),
+            card!(Four, Club),
+            card!(Five, Diamond),
+        ]);
+        assert_eq!(
+            ace_low_straight.component_ranks(),
+            vec![Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]
+        );
+    }
+
+    /// Tests analysis::check_for_pair() and analysis::check_for_three_of_a_kind().
+    ///
+    /// Tests that the public checks match the ones used by rank_hand().
+    #[cfg(feature = "hand-analysis")]
+    #[test]
+    fn analysis_checks_work() {
+        let mut cards = vec![
+            card!(King, Heart),
+            card!(Two, Club),
+            card!(King, Club),
+            card!(Seven, Spade),
+            card!(Seven, Diamond),
+            card!(Seven, Heart),
+        ];
+        cards.sort();
+
+        assert_eq!(
+            analysis::check_for_pair(&cards),
+            Some([card!(King, Club), card!(King, Heart)])
+        );
+        assert_eq!(
+            analysis::check_for_three_of_a_kind(&cards).map(|cards| cards[0].rank),
+            Some(Rank::Seven)
+        );
+    }
+
+    /// Tests eq().
+    ///
+    /// Tests that hand ranks of different variants are never equal, even when they share cards.
+    #[test]
+    fn hand_ranks_of_different_variants_are_not_equal() {
+        let pair = HandRank::Pair([card!(King, Club), card!(King, Heart)]);
+        let three_of_a_kind =
+            HandRank::ThreeOfAKind([card!(King, Club), card!(King, Heart), card!(King, Spade)]);
+        let high_card = HandRank::HighCard(card!(King, Club));
+
+        assert_ne!(pair, three_of_a_kind);
+        assert_ne!(three_of_a_kind, pair);
+        assert_ne!(high_card, pair);
+        assert_eq!(pair, pair);
+    }
+
+    /// Tests rank_hand().
+    ///
+    /// Tests that a Straight Flush is found when the highest cards of the suit are not part of it.
+    #[test]
+    fn rank_hand_finds_straight_flush_below_highest_suited_card() {
+        let hand_rank = rank_hand(vec![
+            card!(Four, Heart),
+            card!(Five, Heart),
+            card!(Six, Heart),
+            card!(Seven, Heart),
+            card!(Eight, Heart),
+            card!(King, Heart),
+            card!(Nine, Club),
+        ]);
+        assert_eq!(
+            hand_rank,
+            HandRank::StraightFlush([
+                card!(Four, Heart),
+                card!(Five, Heart),
+                card!(Six, Heart),
+                card!(Seven, Heart),
+                card!(Eight, Heart),
+            ])
+        );
+
+        // A Six-high Straight Flush beats the Ace-low Straight Flush made from the same suit.
+        let hand_rank = rank_hand(vec![
+            card!(Ace, Spade),
+            card!(Two, Spade),
+            card!(Three, Spade),
+            card!(Four, Spade),
+            card!(Five, Spade),
+            card!(Six, Spade),
+            card!(Nine, Club),
+        ]);
+        assert_eq!(
+            hand_rank,
+            HandRank::StraightFlush([
+                card!(Two, Spade),
+                card!(Three, Spade),
+                card!(Four, Spade),
+                card!(Five, Spade),
+                card!(Six, Spade),
+            ])
+        );
+    }
+
+    /// Tests rank_hand().
+    ///
+    /// Tests that a hole card of the suit improves a Flush that is entirely on the table.
+    #[test]
+    fn rank_hand_uses_hole_card_to_improve_flush_on_table() {
+        let table_cards = vec![
+            card!(Two, Diamond),
+            card!(Five, Diamond),
+            card!(Seven, Diamond),
+            card!(Nine, Diamond),
+            card!(Jack, Diamond),
+        ];
+
+        let mut player1_cards = table_cards.clone();
+        player1_cards.extend([card!(Ace, Diamond), card!(Three, Club)]);
+        let mut player2_cards = table_cards.clone();
+        player2_cards.extend([card!(King, Club), card!(King, Spade)]);
+
+        let player1_hand_rank = rank_hand(player1_cards);
+        let player2_hand_rank = rank_hand(player2_cards);
+
+        assert_eq!(
+            player1_hand_rank,
+            HandRank::Flush([
+                card!(Five, Diamond),
+                card!(Seven, Diamond),
+                card!(Nine, Diamond),
+                card!(Jack, Diamond),
+                card!(Ace, Diamond),
+            ])
+        );
+        assert!(player1_hand_rank > player2_hand_rank);
+    }
+
+    /// Tests best_five().
+    ///
+    /// Tests that the highest five cards are found and that the kickers decide between equal hand ranks.
+    #[test]
+    fn best_five_finds_highest_five_cards() {
+        let hand = Hand::new_from_cards(vec![
+            card!(King, Club),
+            card!(Two, Diamond),
+            card!(King, Heart),
+            card!(Nine, Spade),
+            card!(Four, Club),
+            card!(Queen, Diamond),
+            card!(Seven, Heart),
+        ]);
+        assert_eq!(
+            hand.best_five(),
+            Some([
+                card!(Seven, Heart),
+                card!(Nine, Spade),
+                card!(Queen, Diamond),
+                card!(King, Club),
+                card!(King, Heart),
+            ])
+        );
+
+        let hand = Hand::new_from_cards(vec![
+            card!(Four, Heart),
+            card!(Five, Heart),
+            card!(Six, Heart),
+            card!(Seven, Heart),
+            card!(Eight, Heart),
+            card!(King, Heart),
+            card!(Nine, Club),
+        ]);
+        assert_eq!(
+            hand.best_five(),
+            Some([
+                card!(Four, Heart),
+                card!(Five, Heart),
+                card!(Six, Heart),
+                card!(Seven, Heart),
+                card!(Eight, Heart),
+            ])
+        );
+
+        let hand = Hand::new_from_cards(vec![card!(Ace, Club), card!(Ace, Heart)]);
+        assert_eq!(hand.best_five(), None);
+    }
+
+    /// Tests check_for_flush().
+    ///
+    /// Tests that the five highest cards are returned when six unsorted cards of the suit are present.
+    #[test]
+    fn check_for_flush_returns_highest_five_of_six_unsorted_cards() {
+        let cards = vec![
+            card!(Nine, Club),
+            card!(Two, Club),
+            card!(King, Club),
+            card!(Four, Diamond),
+            card!(Six, Club),
+            card!(Jack, Club),
+            card!(Three, Club),
+        ];
+
+        assert_eq!(
+            check_for_flush(&cards),
+            Some([
+                card!(Three, Club),
+                card!(Six, Club),
+                card!(Nine, Club),
+                card!(Jack, Club),
+                card!(King, Club),
+            ])
+        );
+    }
 }
@@ -1,4 +1,7 @@
 pub mod games {
+    pub mod betting_round;
+    pub mod cpu_strategies;
+    pub mod observers;
     pub mod texas_hold_em;
 }
 pub mod hand_rankings;
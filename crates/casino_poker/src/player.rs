@@ -1,11 +1,18 @@
 use uuid::Uuid;
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Player {
     pub identifier: Uuid,
     pub name: String,
     pub chips: u32,
     pub active: bool,
+    pub folded: bool,
+    pub all_in: bool,
+    pub chips_at_start_of_round: u32,
+    pub bounty: u32,
+    /// The player's chips after each round in which any player's chips changed.
+    pub chip_count_history: Vec<u32>,
 }
 
 impl Player {
@@ -18,10 +25,16 @@ impl Player {
             name: name.to_string(),
             chips,
             active: true,
+            folded: false,
+            all_in: false,
+            chips_at_start_of_round: chips,
+            bounty: 0,
+            chip_count_history: Vec::new(),
         }
     }
 
-    pub fn new_with_chips(name: &str, chips: u32) -> Self {
+    /// Create a new player with the provided chips and the bounty awarded to whoever eliminates them.
+    pub fn new_with_chips(name: &str, chips: u32, bounty: u32) -> Self {
         let identifier = Uuid::new_v4();
 
         Self {
@@ -29,6 +42,11 @@ impl Player {
             name: name.to_string(),
             chips,
             active: true,
+            folded: false,
+            all_in: false,
+            chips_at_start_of_round: chips,
+            bounty,
+            chip_count_history: Vec::new(),
         }
     }
 
@@ -39,4 +57,26 @@ impl Player {
     pub fn subtract_chips(&mut self, amount: u32) {
         self.chips -= amount;
     }
+
+    /// Fold the player's hand so they are unable to act or win for the rest of the current hand.
+    pub fn fold(&mut self) {
+        self.folded = true;
+    }
+
+    /// Returns whether or not the player has folded in the current hand.
+    pub fn is_folded(&self) -> bool {
+        self.folded
+    }
+
+    /// Reset the state the player keeps during a hand so they are able to play the next one.
+    pub fn reset_for_new_round(&mut self) {
+        self.folded = false;
+        self.all_in = false;
+        self.chips_at_start_of_round = self.chips;
+    }
+
+    /// Returns whether or not the player is playing the current hand with all of their chips.
+    pub fn is_all_in(&self) -> bool {
+        self.all_in
+    }
 }